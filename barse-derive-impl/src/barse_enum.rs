@@ -1,16 +1,18 @@
 //! Implementation of derive for enums.
 
 use ::proc_macro2::{Span, TokenStream};
-use ::quote::{quote, ToTokens};
+use ::quote::{format_ident, quote, ToTokens};
 use ::syn::{
     parse::{Parse as _, Parser},
     parse_quote,
     punctuated::Punctuated,
+    spanned::Spanned as _,
     GenericParam, Generics, ItemEnum, Token, WhereClause,
 };
 
 use crate::{
-    barse_field::{FieldDeps, ProcessedFields},
+    barse_field::{field_names, FieldBodies, FieldDeps, ProcessedFields},
+    barse_trait::BarseTrait,
     impl_idents::ImplIdents,
     opt, path_expr,
     result_aggregate::ResAggr,
@@ -41,8 +43,32 @@ opt::opt_parser! {
         /// Set a fixed endian in use by struct (fields may overwrite to another fixed endian).
         endian: opt::Endian,
 
+        /// Lifetime borrowed from source.
+        borrow: opt::Borrow,
+
+        /// Also implement AsyncBarse.
+        async_impl: opt::Async,
+
+        /// Implement BarseSeek instead of Barse.
+        seek: opt::Seek,
+
         /// Enum is read/written as discriminant then variant.
         discriminant: opt::EnumDiscriminant,
+
+        /// Discriminant is read/written using given impl.
+        discriminant_as: opt::DiscriminantAs,
+
+        /// Discriminant is read/written using given with value.
+        discriminant_with: opt::DiscriminantWith,
+
+        /// Variant is selected by peeked bytes, which are still read by variant.
+        peek: opt::EnumPeek,
+
+        /// Order of bits in bit fields.
+        bit_order: opt::BitOrder,
+
+        /// What alignment and padding of variants is relative to.
+        align_base: opt::AlignBase,
     },
 
     /// Enum variant configuration.
@@ -61,15 +87,135 @@ opt::opt_parser! {
 
         /// Field is ignored.
         ignore: opt::IgnoreField,
+
+        /// Variant is read when no other variant matches, its first field holding the
+        /// discriminant of discriminated enums.
+        catch_all: opt::CatchAll,
+
+        /// Order of bits in bit fields.
+        bit_order: opt::BitOrder,
+
+        /// Magic bytes preceding fields of variant.
+        magic: opt::Magic,
+
+        /// Assertions checked after all fields of variant are read.
+        assert: opt::Asserts,
+
+        /// Variant is padded to a multiple of given size.
+        pad_to_multiple: opt::PadToMultiple,
+
+        /// What alignment and padding is relative to.
+        align_base: opt::AlignBase,
     },
 }
 
+/// Get tokens of a discriminant value, byte string literals being dereferenced such that they
+/// may be compared to and written as arrays.
+fn byte_str_deref(value: &::syn::Expr) -> TokenStream {
+    match value {
+        ::syn::Expr::Lit(::syn::ExprLit {
+            lit: ::syn::Lit::ByteStr(lit),
+            ..
+        }) => quote! { (*#lit) },
+        value => value.to_token_stream(),
+    }
+}
+
+/// Get integer type of a '#[repr(..)]' attribute, if it is one implementing Barse.
+fn repr_int(attrs: &[::syn::Attribute]) -> Option<::syn::Type> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<::syn::Meta, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .find_map(|meta| {
+            let ident = meta.require_path_only().ok()?.get_ident()?;
+            matches!(
+                ident.to_string().as_str(),
+                "u8" | "u16" | "u32" | "u64" | "u128" | "i8" | "i16" | "i32" | "i64" | "i128"
+            )
+            .then(|| parse_quote!(#ident))
+        })
+}
+
+/// Check if a discriminant type is a primitive integer.
+fn is_int(ty: &::syn::Type) -> bool {
+    match ty {
+        ::syn::Type::Path(ty) => {
+            ty.qself.is_none() && {
+                ty.path.get_ident().is_some_and(|ident| {
+                    matches!(
+                        ident.to_string().as_str(),
+                        "u8" | "u16"
+                            | "u32"
+                            | "u64"
+                            | "u128"
+                            | "i8"
+                            | "i16"
+                            | "i32"
+                            | "i64"
+                            | "i128"
+                    )
+                })
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Check if values of a discriminant type may be matched against constants.
+fn is_matchable(ty: &::syn::Type) -> bool {
+    match ty {
+        ::syn::Type::Array(ty) => is_matchable(&ty.elem),
+        ty => is_int(ty),
+    }
+}
+
+/// Check if a discriminant value may be evaluated as a constant, being built from literals and
+/// paths not bound by the with pattern.
+fn is_const(value: &::syn::Expr, bound: &[::proc_macro2::Ident]) -> bool {
+    match value {
+        ::syn::Expr::Lit(_) => true,
+        ::syn::Expr::Path(path) => {
+            path.qself.is_none()
+                && path
+                    .path
+                    .get_ident()
+                    .is_none_or(|ident| !bound.contains(ident))
+        }
+        ::syn::Expr::Unary(unary) => is_const(&unary.expr, bound),
+        ::syn::Expr::Paren(paren) => is_const(&paren.expr, bound),
+        ::syn::Expr::Group(group) => is_const(&group.expr, bound),
+        ::syn::Expr::Cast(cast) => is_const(&cast.expr, bound),
+        ::syn::Expr::Binary(binary) => {
+            is_const(&binary.left, bound) && is_const(&binary.right, bound)
+        }
+        ::syn::Expr::Array(array) => array.elems.iter().all(|elem| is_const(elem, bound)),
+        ::syn::Expr::Tuple(tuple) => tuple.elems.iter().all(|elem| is_const(elem, bound)),
+        _ => false,
+    }
+}
+
+/// Collect all identifiers of a token stream.
+fn idents(tokens: TokenStream, out: &mut Vec<::proc_macro2::Ident>) {
+    for token in tokens {
+        match token {
+            ::proc_macro2::TokenTree::Ident(ident) => out.push(ident),
+            ::proc_macro2::TokenTree::Group(group) => idents(group.stream(), out),
+            _ => {}
+        }
+    }
+}
+
 /// Derive barse for an enum.
 ///
 /// # Errors
 /// Should derive not be possible.
 #[expect(clippy::missing_panics_doc)]
-pub fn derive_barse_enum(mut item: ItemEnum) -> Result<TokenStream, ::syn::Error> {
+pub fn derive_barse_enum(item: ItemEnum) -> Result<TokenStream, ::syn::Error> {
     let EnumConfig {
         where_clause,
         barse_path,
@@ -79,13 +225,59 @@ pub fn derive_barse_enum(mut item: ItemEnum) -> Result<TokenStream, ::syn::Error
         field_prefix,
         endian,
         discriminant,
+        discriminant_as,
+        discriminant_with,
+        peek,
+        borrow,
+        async_impl,
+        seek,
+        bit_order,
+        align_base,
     } = EnumConfig::default().parse_attrs(&item.attrs)?;
 
     let name = &item.ident;
+    let type_name = name.to_string();
     let mut aggr = ResAggr::<()>::new();
 
     aggr.conflict(&read_with, &with)
-        .conflict(&write_with, &with);
+        .conflict(&write_with, &with)
+        .conflict(&discriminant, &peek);
+
+    // Discriminant type is inferred from an integer repr, unless variants are selected by
+    // peeking or conditions.
+    let discriminant_ty = discriminant.as_deref().cloned().or_else(|| {
+        let variant_if = item.variants.iter().any(|variant| {
+            VariantConfig::default()
+                .parse_attrs(&variant.attrs)
+                .is_ok_and(|cfg| cfg.variant_if.is_some())
+        });
+        if peek.is_some() || variant_if {
+            None
+        } else {
+            repr_int(&item.attrs)
+        }
+    });
+
+    for span in [
+        discriminant_as.as_ref().map(|opt| opt.kw.span),
+        discriminant_with.as_ref().map(|opt| opt.kw.span),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|_| discriminant_ty.is_none())
+    {
+        aggr.push_err(::syn::Error::new(
+            span,
+            "'discriminant_as' and 'discriminant_with' require 'discriminant' to be set",
+        ));
+    }
+    if cfg!(not(feature = "barse_as")) {
+        aggr.requires_feature("barse_as", &discriminant_as);
+    }
+    let discriminant_as = discriminant_as.as_deref();
+    let discriminant_with = discriminant_with
+        .as_deref()
+        .map_or_else(|| quote! { () }, ToTokens::to_token_stream);
 
     let impl_idents @ ImplIdents {
         _r,
@@ -95,6 +287,7 @@ pub fn derive_barse_enum(mut item: ItemEnum) -> Result<TokenStream, ::syn::Error
         to_ident,
         from_ident,
         discriminant_ident,
+        len_ident,
     } = &ImplIdents::new();
 
     let underscore = ::syn::Ident::new("_", Span::call_site());
@@ -107,12 +300,31 @@ pub fn derive_barse_enum(mut item: ItemEnum) -> Result<TokenStream, ::syn::Error
         |p| p.path,
     );
 
+    let barse_traits = BarseTrait::with_async(
+        &item.generics,
+        item.variants.iter().flat_map(|variant| &variant.fields),
+        borrow,
+        (async_impl, seek),
+        &mut aggr,
+    )?;
+    if let (Some(peek), BarseTrait::Borrowed(_)) = (&peek, &barse_traits[0]) {
+        aggr.push_err(::syn::Error::new(
+            peek.kw.span,
+            "'peek' is not supported for types borrowing from source",
+        ));
+    }
+
     let default_with = with.map_or_else(|| impl_idents.default_with(), |w| w.with_pat);
     let false_expr = ::syn::Expr::from(
         ::syn::ExprLit::parse
             .parse2(quote! {false})
             .expect("'false' should parse as a literal"),
     );
+    let true_expr = ::syn::Expr::from(
+        ::syn::ExprLit::parse
+            .parse2(quote! {true})
+            .expect("'true' should parse as a literal"),
+    );
 
     let read_with = read_with.as_deref().unwrap_or(&default_with);
     let read_with_pat = read_with.pat.as_deref().unwrap_or(with_ident);
@@ -122,19 +334,111 @@ pub fn derive_barse_enum(mut item: ItemEnum) -> Result<TokenStream, ::syn::Error
     let write_with_pat = write_with.pat.as_deref().unwrap_or(with_ident);
     let write_with_expr = path_expr(write_with_pat.clone());
 
-    let mut read_body = TokenStream::default();
-    let mut write_body = TokenStream::default();
+    let mut bodies = vec![FieldBodies::default(); barse_traits.len()];
+    let mut discriminant_reads = vec![TokenStream::default(); barse_traits.len()];
+    let mut len_body = TokenStream::default();
+
+    // Catch-all variant is read only after all other variants have been tried.
+    let mut catch_all_reads = vec![TokenStream::default(); barse_traits.len()];
+    let mut catch_all_span = None;
+
+    // Peeked bytes still to be read by variant.
+    let replay_ident = format_ident!("__replay_{_r:x}");
 
     let discr_endian = endian
         .as_deref()
         .map_or_else(|| Either::A(endian_ident), Either::B);
-    if let Some(ty) = discriminant.as_deref() {
-        quote! {
-            let #discriminant_ident = <#ty as #barse_path::Barse>::read_with::<#discr_endian, _>(#from_ident, ())?;
+    for (barse_trait, discriminant_read) in barse_traits.iter().zip(&mut discriminant_reads) {
+        let source_trait = barse_trait.source_trait(&barse_path);
+        let await_suffix = barse_trait.await_suffix();
+        let read_ctx = quote! {
+            |err: #barse_path::WrappedErr<#byte_ident::Err>| err
+                .at_offset(<#byte_ident as #source_trait>::offset(#from_ident))
+                .in_type(#type_name)
+        };
+        if let Some(ty) = &discriminant_ty {
+            let discriminant_trait = barse_trait.discriminant_trait();
+            let discriminant_path = discriminant_trait.path(&barse_path);
+            let read_fn = discriminant_trait.read_fn();
+            let call_expr = match discriminant_as {
+                Some(using) => {
+                    if let BarseTrait::Async = barse_trait {
+                        aggr.push_err(::syn::Error::new(
+                            using.span(),
+                            "'discriminant_as' is not supported with 'async'",
+                        ));
+                    }
+                    quote! {
+                        #barse_path::ReadAs::<#ty, _>::read_with::<#discr_endian, _>(
+                            { #using },
+                            #from_ident,
+                            #discriminant_with,
+                        )
+                    }
+                }
+                None => quote! {
+                    <#ty as #discriminant_path>::#read_fn::<#discr_endian, _>(
+                        #from_ident,
+                        #discriminant_with,
+                    )
+                    #await_suffix
+                },
+            };
+            quote! {
+                let #discriminant_ident = #call_expr.map_err(#read_ctx)?;
+            }
+            .to_tokens(discriminant_read);
+        } else if let Some(count) = peek.as_deref() {
+            // Bytes are peeked when source supports it, otherwise they are read and replayed.
+            let peek_expr = match barse_trait {
+                BarseTrait::Async => quote! { false },
+                BarseTrait::Owned | BarseTrait::Borrowed(_) | BarseTrait::Seek => quote! {
+                    <#byte_ident as #source_trait>::try_peek_slice(
+                        #from_ident,
+                        &mut #discriminant_ident,
+                    )
+                    .map_err(#barse_path::WrappedErr::from)
+                    .map_err(#read_ctx)?
+                },
+            };
+            quote! {
+                let mut #discriminant_ident = [0u8; { #count }];
+                let #replay_ident: &[u8] = if #peek_expr {
+                    &[]
+                } else {
+                    <#byte_ident as #source_trait>::read_slice(#from_ident, &mut #discriminant_ident)
+                        #await_suffix
+                        .map_err(#barse_path::WrappedErr::from)
+                        .map_err(#read_ctx)?;
+                    &#discriminant_ident
+                };
+            }
+            .to_tokens(discriminant_read);
         }
-        .to_tokens(&mut read_body);
     }
 
+    // Variants of peeking enums read from a source replaying the peeked bytes if they were read.
+    let prefixed_ty = quote! { #barse_path::adapter::Prefixed<'_, &mut #byte_ident> };
+    let source_ty = peek.as_ref().map(|_| &prefixed_ty);
+
+    // Discriminants are numbered like rustc does for integer reprs.
+    let implicit_numbering =
+        repr_int(&item.attrs).is_some() && discriminant_ty.as_ref().is_some_and(is_int);
+
+    // Identifiers bound by the with pattern, which discriminants may not be matched against.
+    let mut bound_idents = Vec::new();
+    idents(read_with_pat.to_token_stream(), &mut bound_idents);
+
+    // Fieldless enums are read by matching the discriminant against constants.
+    let mut matched_variants = discriminant_ty
+        .as_ref()
+        .filter(|ty| is_matchable(ty))
+        .map(|_| Vec::new());
+
+    // Discriminant of previous variant as assigned by rustc, the last explicit one and how many
+    // variants follow it.
+    let mut implicit_discriminant = None::<(Option<&::syn::Expr>, u128)>;
+
     for variant in &item.variants {
         let cfg = match VariantConfig::default().parse_attrs(&variant.attrs) {
             Ok(cfg) => cfg,
@@ -145,7 +449,26 @@ pub fn derive_barse_enum(mut item: ItemEnum) -> Result<TokenStream, ::syn::Error
         };
 
         aggr.conflict(&discriminant, &cfg.variant_if)
-            .conflict(&cfg.discriminant, &cfg.variant_if);
+            .conflict(&peek, &cfg.variant_if)
+            .conflict(&cfg.discriminant, &cfg.variant_if)
+            .conflict(&cfg.catch_all, &cfg.discriminant)
+            .conflict(&cfg.catch_all, &cfg.variant_if)
+            .conflict(&cfg.catch_all, &cfg.ignore);
+
+        if let Some(catch_all) = &cfg.catch_all {
+            if catch_all_span.replace(catch_all.kw.span).is_some() {
+                aggr.push_err(::syn::Error::new(
+                    catch_all.kw.span,
+                    "only one variant may be 'catch_all'",
+                ));
+            }
+            if let Some((eq_token, _)) = &variant.discriminant {
+                aggr.push_err(::syn::Error::new(
+                    eq_token.span,
+                    "'catch_all' variants should not have a discriminant",
+                ));
+            }
+        }
 
         let field_prefix =
             cfg.field_prefix
@@ -157,11 +480,111 @@ pub fn derive_barse_enum(mut item: ItemEnum) -> Result<TokenStream, ::syn::Error
                 });
 
         let variant_endian = cfg.endian.as_deref().or(endian.as_deref());
+        let variant_bit_order = cfg.bit_order.as_deref().or(bit_order.as_deref());
+        let variant_align_base = cfg.align_base.as_deref().or(align_base.as_deref());
+
+        let (base, count) = match (&variant.discriminant, implicit_discriminant) {
+            (Some((_, expr)), _) => (Some(expr), 0),
+            (None, None) => (None, 0),
+            (None, Some((base, count))) => (base, count.saturating_add(1)),
+        };
+        implicit_discriminant = Some((base, count));
+        let implicit_value = match (base, count) {
+            (Some(base), 0) => base.to_token_stream(),
+            (Some(base), count) => {
+                let count = ::proc_macro2::Literal::u128_unsuffixed(count);
+                quote! { (#base) + #count }
+            }
+            (None, count) => ::proc_macro2::Literal::u128_unsuffixed(count).to_token_stream(),
+        };
+
+        // Values given through '= $expr' or numbered by rustc are constants.
+        let is_const_value = cfg
+            .discriminant
+            .as_deref()
+            .is_none_or(|value| is_const(value, &bound_idents));
+        let discriminant_value = cfg
+            .discriminant
+            .as_deref()
+            .or_else(|| variant.discriminant.as_ref().map(|d| &d.1))
+            .map(byte_str_deref)
+            .or_else(|| Some(implicit_value).filter(|_| implicit_numbering && cfg.ignore.is_none()))
+            .filter(|_| cfg.catch_all.is_none());
+
+        // Catch-all variants of discriminated enums hold the read discriminant in their first
+        // field, which is written back as the discriminant.
+        let catch_all_discriminant = cfg
+            .catch_all
+            .as_ref()
+            .filter(|_| discriminant_ty.is_some())
+            .and_then(|catch_all| {
+                let first = field_names(&variant.fields, field_prefix, &mut aggr)
+                    .into_iter()
+                    .next();
+                if first.is_none() {
+                    aggr.push_err(::syn::Error::new(
+                        catch_all.kw.span,
+                        "'catch_all' variants of discriminated enums should have a field \
+                            holding the discriminant",
+                    ));
+                }
+                first
+            })
+            .map(|(first, _)| first);
+        let discriminant_binding = catch_all_discriminant.as_ref().map(|first| {
+            quote! {
+                let #discriminant_ident = ::core::clone::Clone::clone(#first);
+            }
+        });
+        let discriminant_value = discriminant_value.map_or_else(
+            || {
+                catch_all_discriminant
+                    .as_ref()
+                    .map(|_| discriminant_ident.to_token_stream())
+            },
+            Some,
+        );
+
+        // Encoded length of discriminant, as a result with an infallible error.
+        let discriminant_encoded_len = discriminant_ty.as_ref().and_then(|ty| {
+            let discr = discriminant_value.as_ref()?;
+            Some(discriminant_as.map_or_else(
+                || {
+                    quote! {
+                        <#ty as #barse_path::Barse>::encoded_len(&(#discr), #discriminant_with)
+                    }
+                },
+                |using| {
+                    quote! {{
+                        let #to_ident = &mut #barse_path::CountingSink::new();
+                        #barse_path::WriteAs::<#ty, _>::write_with::<
+                            #barse_path::endian::Native,
+                            #barse_path::CountingSink,
+                        >(
+                            { #using },
+                            &(#discr),
+                            #to_ident,
+                            #discriminant_with,
+                        )
+                        .map(|()| usize::try_from(#to_ident.count()).unwrap_or(usize::MAX))
+                    }}
+                },
+            ))
+        });
+
+        // Tracked positions of fields include the discriminant.
+        let position = discriminant_encoded_len.as_ref().map(|encoded_len| {
+            quote! {
+                #encoded_len
+                    .map_err(|err| err.rewrap::<#byte_ident::Err>().in_type(#type_name))?
+            }
+        });
 
         let ProcessedFields {
             name_expansion,
-            read_body: variant_read_body,
-            write_body: variant_write_body,
+            bodies: variant_bodies,
+            len_body: variant_len_body,
+            fixed_size: _,
         } = ProcessedFields::new(
             &variant.fields,
             FieldDeps {
@@ -170,27 +593,39 @@ pub fn derive_barse_enum(mut item: ItemEnum) -> Result<TokenStream, ::syn::Error
                 read_with_expr: &read_with_expr,
                 write_with_expr: &write_with_expr,
                 endian: variant_endian,
+                bit_order: variant_bit_order,
+                magic: cfg.magic.as_deref(),
+                asserts: cfg.assert.as_ref(),
                 impl_idents,
+                barse_traits: &barse_traits,
+                source_ty,
+                position: position.as_ref(),
+                discriminant_field: catch_all_discriminant.is_some(),
+                pad_to_multiple: cfg.pad_to_multiple.as_ref().map(|pad| &pad.multiple),
+                align_base: variant_align_base,
+                type_name: &format!("{name}::{}", variant.ident),
             },
             &mut aggr,
         );
 
-        let discriminant_value = cfg
-            .discriminant
-            .as_deref()
-            .or_else(|| variant.discriminant.as_ref().map(|d| &d.1));
-
-        let discriminant_expr = discriminant_value.map(|expr| {
-            ::syn::Expr::from(
-                ::syn::ExprBinary::parse
-                    .parse2(quote! {#discriminant_ident == #expr})
-                    .expect("'#discriminant_ident == #expr' should be a binary expression"),
-            )
-        });
+        let discriminant_expr = discriminant_value
+            .as_ref()
+            .filter(|_| cfg.catch_all.is_none())
+            .map(|expr| {
+                ::syn::Expr::from(
+                    ::syn::ExprBinary::parse
+                        .parse2(quote! {#discriminant_ident == (#expr)})
+                        .expect("'#discriminant_ident == #expr' should be a binary expression"),
+                )
+            });
 
         let variant_name = &variant.ident;
 
-        if discriminant.is_some() && discriminant_expr.is_none() && cfg.ignore.is_none() {
+        if (discriminant_ty.is_some() || peek.is_some())
+            && discriminant_expr.is_none()
+            && cfg.ignore.is_none()
+            && cfg.catch_all.is_none()
+        {
             aggr.push_err(::syn::Error::new(
                 variant.ident.span(),
                 "discriminated barse enums should have \
@@ -199,84 +634,159 @@ pub fn derive_barse_enum(mut item: ItemEnum) -> Result<TokenStream, ::syn::Error
             ));
         }
 
-        let read_if_expr = discriminant_expr
-            .as_ref()
-            .or(cfg.variant_if.as_deref())
-            .unwrap_or(&false_expr);
+        let read_if_expr = if cfg.catch_all.is_some() {
+            &true_expr
+        } else {
+            discriminant_expr
+                .as_ref()
+                .or(cfg.variant_if.as_deref())
+                .unwrap_or(&false_expr)
+        };
         let read_return = match variant.fields {
             ::syn::Fields::Named(_) => quote! { Self::#variant_name { #name_expansion } },
             ::syn::Fields::Unnamed(_) => quote! { Self::#variant_name ( #name_expansion ) },
             ::syn::Fields::Unit => quote! { Self::#variant_name },
         };
-        quote! {
-            if #read_if_expr {
-                #variant_read_body
-                return Ok( #read_return );
-            }
-        }
-        .to_tokens(&mut read_body);
-
         let write_expansion = match &variant.fields {
             ::syn::Fields::Named(_) => quote! { Self::#variant_name { #name_expansion } },
             ::syn::Fields::Unnamed(_) => quote! { Self::#variant_name ( #name_expansion ) },
             ::syn::Fields::Unit => quote! { Self::#variant_name },
         };
-        let write_discriminant = discriminant.as_deref().and_then(|ty| {
-            let discr = discriminant_value?;
-            Some(quote! {
-                <#ty as #barse_path::Barse>::write_with::<#discr_endian, _>(&#discr, #to_ident, ())?;
-            })
+
+        // Variants reading anything besides the discriminant need a body of their own.
+        let matchable = matches!(variant.fields, ::syn::Fields::Unit)
+            && cfg.magic.is_none()
+            && cfg.assert.is_none()
+            && cfg.pad_to_multiple.is_none()
+            && cfg.catch_all.is_none();
+        let matched = match (&discriminant_value, &mut matched_variants) {
+            (Some(value), Some(matched)) if matchable && is_const_value => {
+                matched.push((value.clone(), variant_name));
+                true
+            }
+            (None, _) => cfg.ignore.is_some(),
+            _ => false,
+        };
+        if !matched {
+            matched_variants = None;
+        }
+
+        let discriminant_len = discriminant_encoded_len.as_ref().map(|encoded_len| {
+            quote! {
+                let #len_ident = #encoded_len.map_err(|err| err.in_type(#type_name))?;
+            }
         });
         quote! {
             if let #write_expansion = self {
-                #write_discriminant
-                #variant_write_body
-                return Ok(());
+                let #len_ident = 0usize;
+                #discriminant_binding
+                #discriminant_len
+                #variant_len_body
+                return Ok(#len_ident);
             }
         }
-        .to_tokens(&mut write_body);
-    }
-
-    quote! {
-        Err(#barse_path::WrappedErr::Other(#barse_path::Error::Msg("no read variant matched")))
-    }
-    .to_tokens(&mut read_body);
-    quote! {
-        Err(#barse_path::WrappedErr::Other(#barse_path::Error::Msg("no write variant matched")))
-    }
-    .to_tokens(&mut write_body);
-
-    if where_clause.is_none() {
-        let Generics {
-            params,
-            where_clause,
-            ..
-        } = &mut item.generics;
-        for param in params {
-            let GenericParam::Type(param) = param else {
-                continue;
-            };
-            let where_clause = where_clause.get_or_insert_with(|| WhereClause {
-                where_token: Default::default(),
-                predicates: Punctuated::new(),
+        .to_tokens(&mut len_body);
+
+        for (
+            (
+                barse_trait,
+                (
+                    FieldBodies {
+                        read_body,
+                        write_body,
+                    },
+                    FieldBodies {
+                        read_body: variant_read_body,
+                        write_body: variant_write_body,
+                    },
+                ),
+            ),
+            catch_all_read,
+        ) in barse_traits
+            .iter()
+            .zip(bodies.iter_mut().zip(variant_bodies))
+            .zip(&mut catch_all_reads)
+        {
+            quote! {
+                if #read_if_expr {
+                    #variant_read_body
+                    return Ok( #read_return );
+                }
+            }
+            .to_tokens(if cfg.catch_all.is_some() {
+                catch_all_read
+            } else {
+                read_body
             });
 
-            let ident = &param.ident;
-
-            if !where_clause.predicates.empty_or_trailing() {
-                where_clause.predicates.push_punct(<Token![,]>::default());
+            let write_discriminant = discriminant_ty.as_ref().and_then(|ty| {
+                let discr = discriminant_value.as_ref()?;
+                let discriminant_trait = barse_trait.discriminant_trait();
+                let discriminant_path = discriminant_trait.path(&barse_path);
+                let write_fn = discriminant_trait.write_fn();
+                let sink_trait = barse_trait.sink_trait(&barse_path);
+                let await_suffix = barse_trait.await_suffix();
+                let call_expr = discriminant_as.map_or_else(
+                    || {
+                        quote! {
+                            <#ty as #discriminant_path>::#write_fn::<#discr_endian, _>(
+                                &(#discr),
+                                #to_ident,
+                                #discriminant_with,
+                            )
+                            #await_suffix
+                        }
+                    },
+                    |using| {
+                        quote! {
+                            #barse_path::WriteAs::<#ty, _>::write_with::<#discr_endian, _>(
+                                { #using },
+                                &(#discr),
+                                #to_ident,
+                                #discriminant_with,
+                            )
+                        }
+                    },
+                );
+                Some(quote! {
+                    #call_expr
+                        .map_err(|err| err
+                            .at_offset(<#byte_ident as #sink_trait>::offset(#to_ident))
+                            .in_type(#type_name))?;
+                })
+            });
+            quote! {
+                if let #write_expansion = self {
+                    #discriminant_binding
+                    #write_discriminant
+                    #variant_write_body
+                    return Ok(());
+                }
             }
+            .to_tokens(write_body);
+        }
+    }
 
-            where_clause
-                .predicates
-                .push(parse_quote!(#ident: #barse_path::Barse));
+    if let (Some(matched), Some(ty)) = (matched_variants, &discriminant_ty) {
+        let (values, variants): (Vec<_>, Vec<_>) = matched.into_iter().unzip();
+        let consts = (0..values.len())
+            .map(|i| format_ident!("__DISCRIMINANT_{i}"))
+            .collect::<Vec<_>>();
+        let read_match = quote! {
+            #(const #consts: #ty = #values;)*
+            match #discriminant_ident {
+                #(#consts => return Ok(Self::#variants),)*
+                _ => {}
+            }
+        };
+        for FieldBodies { read_body, .. } in &mut bodies {
+            read_body.clone_from(&read_match);
         }
     }
 
-    let (impl_generics, ty_generics, split_where_clause) = item.generics.split_for_impl();
-    let where_clause = where_clause
-        .as_ref()
-        .map_or_else(|| Either::A(split_where_clause), Either::B);
+    for (FieldBodies { read_body, .. }, catch_all_read) in bodies.iter_mut().zip(catch_all_reads) {
+        catch_all_read.to_tokens(read_body);
+    }
 
     let read_with_ty = &read_with.ty;
     let write_with_ty = &write_with.ty;
@@ -284,34 +794,140 @@ pub fn derive_barse_enum(mut item: ItemEnum) -> Result<TokenStream, ::syn::Error
     // Error if any minor errors encountered.
     aggr.into_inner()?;
 
-    Ok(quote! {
-        #[automatically_derived]
-        impl #impl_generics #barse_path::Barse for #name #ty_generics #where_clause {
-            type ReadWith = #read_with_ty;
-            type WriteWith = #write_with_ty;
-
-            fn read_with<#endian_ident, #byte_ident>(
-                #from_ident: &mut #byte_ident,
-                #read_with_pat: #read_with_ty
-            ) -> ::core::result::Result<Self, #barse_path::WrappedErr::<#byte_ident::Err>>
-            where
-                #endian_ident: #barse_path::Endian,
-                #byte_ident: #barse_path::ByteSource,
-            {
+    let mut impls = TokenStream::default();
+    for (
+        barse_trait,
+        (
+            discriminant_read,
+            FieldBodies {
+                read_body,
+                write_body,
+            },
+        ),
+    ) in barse_traits
+        .iter()
+        .zip(discriminant_reads.into_iter().zip(bodies))
+    {
+        let read_body = if peek.is_some() {
+            quote! {{
+                let #from_ident = &mut #barse_path::adapter::Prefixed::new(
+                    #replay_ident,
+                    &mut *#from_ident,
+                );
                 #read_body
+            }}
+        } else {
+            read_body
+        };
+        let trait_path = barse_trait.path(&barse_path);
+        let source_trait = barse_trait.source_trait(&barse_path);
+        let sink_trait = barse_trait.sink_trait(&barse_path);
+        let mut generics = item.generics.clone();
+
+        if where_clause.is_none() {
+            let Generics {
+                params,
+                where_clause,
+                ..
+            } = &mut generics;
+            for param in params {
+                let GenericParam::Type(param) = param else {
+                    continue;
+                };
+                let where_clause = where_clause.get_or_insert_with(|| WhereClause {
+                    where_token: Default::default(),
+                    predicates: Punctuated::new(),
+                });
+
+                let ident = &param.ident;
+
+                if !where_clause.predicates.empty_or_trailing() {
+                    where_clause.predicates.push_punct(<Token![,]>::default());
+                }
+
+                where_clause
+                    .predicates
+                    .push(parse_quote!(#ident: #trait_path));
             }
+        }
 
-            fn write_with<#endian_ident, #byte_ident>(
-                &self,
-                #to_ident: &mut #byte_ident,
-                #write_with_pat: #write_with_ty
-            ) -> ::core::result::Result<(), #barse_path::WrappedErr::<#byte_ident::Err>>
-            where
-                #endian_ident: #barse_path::Endian,
-                #byte_ident: #barse_path::ByteSink,
-            {
-                #write_body
+        let (impl_generics, ty_generics, split_where_clause) = generics.split_for_impl();
+        let where_clause = where_clause
+            .as_ref()
+            .map_or_else(|| Either::A(split_where_clause), Either::B);
+
+        let with_types = barse_trait.with_types(read_with_ty, write_with_ty);
+        let source_bound = barse_trait.source_bound(&barse_path);
+        let sink_bound = barse_trait.sink_bound(&barse_path);
+        let read_fn = barse_trait.read_fn();
+        let write_fn = barse_trait.write_fn();
+        let asyncness = barse_trait.asyncness();
+
+        // Only Barse has encoded_len.
+        let encoded_len = matches!(barse_trait, BarseTrait::Owned).then(|| {
+            quote! {
+                fn encoded_len(
+                    &self,
+                    #write_with_pat: #write_with_ty
+                ) -> ::core::result::Result<
+                    usize,
+                    #barse_path::WrappedErr::<::core::convert::Infallible>
+                > {
+                    #len_body
+                    Err(#barse_path::WrappedErr::from_err(#barse_path::Error::Msg("no write variant matched"))
+                        .in_type(#type_name))
+                }
+            }
+        });
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #trait_path for #name #ty_generics #where_clause {
+                #with_types
+
+                #asyncness fn #read_fn<#endian_ident, #byte_ident>(
+                    #from_ident: &mut #byte_ident,
+                    #read_with_pat: #read_with_ty
+                ) -> ::core::result::Result<Self, #barse_path::WrappedErr::<#byte_ident::Err>>
+                where
+                    #endian_ident: #barse_path::Endian,
+                    #byte_ident: #source_bound,
+                {
+                    #discriminant_read
+                    #read_body
+                    Err(#barse_path::WrappedErr::from_err(#barse_path::Error::Msg("no read variant matched"))
+                        .at_offset(<#byte_ident as #source_trait>::offset(#from_ident))
+                        .in_type(#type_name))
+                }
+
+                #asyncness fn #write_fn<#endian_ident, #byte_ident>(
+                    &self,
+                    #to_ident: &mut #byte_ident,
+                    #write_with_pat: #write_with_ty
+                ) -> ::core::result::Result<(), #barse_path::WrappedErr::<#byte_ident::Err>>
+                where
+                    #endian_ident: #barse_path::Endian,
+                    #byte_ident: #sink_bound,
+                {
+                    #write_body
+                    Err(#barse_path::WrappedErr::from_err(#barse_path::Error::Msg("no write variant matched"))
+                        .at_offset(<#byte_ident as #sink_trait>::offset(#to_ident))
+                        .in_type(#type_name))
+                }
+
+                #encoded_len
             }
         }
-    })
+        .to_tokens(&mut impls);
+
+        barse_trait
+            .seek_forward(
+                &barse_path,
+                impl_idents,
+                (&impl_generics, quote! { #name #ty_generics }, &where_clause),
+            )
+            .to_tokens(&mut impls);
+    }
+
+    Ok(impls)
 }
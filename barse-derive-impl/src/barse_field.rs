@@ -1,10 +1,16 @@
 //! Shared field processing.
 
+use ::core::ops::Range;
+
 use ::proc_macro2::{Span, TokenStream};
 use ::quote::{format_ident, ToTokens};
+use ::syn::{ext::IdentExt, Token};
 use quote::quote;
 
-use crate::{impl_idents::ImplIdents, opt, result_aggregate::ResAggr, unit_expr, Either};
+use crate::{
+    barse_trait::BarseTrait, impl_idents::ImplIdents, kw, opt, result_aggregate::ResAggr,
+    unit_expr, Either,
+};
 
 opt::opt_parser! {
     /// Struct field configuration.
@@ -41,6 +47,48 @@ opt::opt_parser! {
 
         /// Read/Write using provided impl.
         barse_as: opt::BarseAs,
+
+        /// Amount of bytes borrowed, or items read.
+        count: opt::Count,
+
+        /// Items are read until closure returns true.
+        until: opt::Until,
+
+        /// Items are read until source is exhausted.
+        until_eof: opt::UntilEof,
+
+        /// Amount of bytes field may use.
+        size: opt::Size,
+
+        /// What to do with bytes left within size.
+        leftover: opt::Leftover,
+
+        /// Field is aligned relative to start of record, or offset given align_base.
+        align: opt::Align,
+
+        /// Field is a checksum of other fields.
+        checksum: opt::Checksum,
+
+        /// Field is stored in a number of bits, packed with neighbouring bit fields.
+        bits: opt::Bits,
+
+        /// Magic bytes preceding field.
+        magic: opt::Magic,
+
+        /// Assertions checked after field is read.
+        assert: opt::Asserts,
+
+        /// Value written is calculated from other fields.
+        calc: opt::Calc,
+
+        /// Calculated value is verified on read.
+        verify: opt::Verify,
+
+        /// Optional field is present only if condition holds.
+        field_if: opt::FieldIf,
+
+        /// Field is read at/written to a position, after which the previous one is restored.
+        offset: opt::Offset,
     },
 }
 
@@ -50,6 +98,53 @@ pub struct ProcessedFields {
     /// 'ident: name,' expansion for fields.
     pub name_expansion: TokenStream,
 
+    /// Bodies for fields, one for each trait being implemented.
+    pub bodies: Vec<FieldBodies>,
+
+    /// Body summing encoded length of fields.
+    pub len_body: TokenStream,
+
+    /// Size of fields, if all of them are read and written as fixed size types.
+    pub fixed_size: Option<TokenStream>,
+}
+
+/// Get expression evaluating to size of a type implementing FixedBarse.
+///
+/// Arrays are sized by their element type, such that they need not implement FixedBarse.
+fn type_fixed_size(ty: &::syn::Type, barse_path: &::syn::Path) -> TokenStream {
+    match ty {
+        ::syn::Type::Array(array) => {
+            let size = type_fixed_size(&array.elem, barse_path);
+            let len = &array.len;
+            quote! { (#size) * (#len) }
+        }
+        ::syn::Type::Paren(::syn::TypeParen { elem, .. })
+        | ::syn::Type::Group(::syn::TypeGroup { elem, .. }) => type_fixed_size(elem, barse_path),
+        _ => quote! { <#ty as #barse_path::FixedBarse>::SIZE },
+    }
+}
+
+/// Get type wrapped by an [Option] type.
+fn option_inner(ty: &::syn::Type) -> Option<&::syn::Type> {
+    let ::syn::Type::Path(::syn::TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+    let segment = path
+        .segments
+        .last()
+        .filter(|segment| segment.ident == "Option")?;
+    let ::syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first() {
+        Some(::syn::GenericArgument::Type(inner)) if args.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
+
+/// Read and write bodies of fields for a trait.
+#[derive(Debug, Default, Clone)]
+pub struct FieldBodies {
     /// read body for fields.
     pub read_body: TokenStream,
 
@@ -57,6 +152,187 @@ pub struct ProcessedFields {
     pub write_body: TokenStream,
 }
 
+/// Checksum of a range of fields.
+#[derive(Debug)]
+struct FieldChecksum {
+    /// Ident of running checksum.
+    state: ::syn::Ident,
+
+    /// Type of checksum.
+    ty: TokenStream,
+
+    /// Indices of fields covered by checksum.
+    covers: Range<usize>,
+}
+
+/// How many items of a collection field are read.
+#[derive(Debug, Clone, Copy)]
+enum Repeat<'a> {
+    /// A counted amount of items are read.
+    Count(&'a ::syn::Expr),
+
+    /// Items are read until closure returns true for the last read item.
+    Until(&'a ::syn::Expr),
+
+    /// Items are read until source is exhausted.
+    UntilEof,
+}
+
+/// Consecutive bit fields, read/written as a unit at the first field.
+#[derive(Debug)]
+struct BitGroup {
+    /// Indices of fields in group.
+    members: Range<usize>,
+
+    /// Amount of bits used by each field in group.
+    counts: Vec<u32>,
+
+    /// Amount of bytes used by group.
+    bytes: usize,
+}
+
+impl BitGroup {
+    /// Get bit groups of fields, and the index of group of each field.
+    fn collect(
+        cfgs: &[Option<FieldConfig>],
+        aggr: &mut ResAggr,
+    ) -> (Vec<Self>, Vec<Option<usize>>) {
+        let mut groups = Vec::<Self>::new();
+        let mut group_of = Vec::with_capacity(cfgs.len());
+        let mut last_bits = None;
+
+        for (i, cfg) in cfgs.iter().enumerate() {
+            let Some(bits) = cfg.as_ref().and_then(|cfg| cfg.bits.as_ref()) else {
+                group_of.push(None);
+                continue;
+            };
+            let count = match bits.bits.base10_parse::<u32>() {
+                Ok(count @ 1..=64) => count,
+                Ok(_) => {
+                    aggr.push_err(::syn::Error::new(
+                        bits.bits.span(),
+                        "bit fields should use between 1 and 64 bits",
+                    ));
+                    1
+                }
+                Err(err) => {
+                    aggr.push_err(err);
+                    1
+                }
+            };
+
+            match groups.last_mut() {
+                Some(group) if group.members.end == i => {
+                    group.members.end = i + 1;
+                    group.counts.push(count);
+                }
+                _ => groups.push(Self {
+                    members: i..i + 1,
+                    counts: vec![count],
+                    bytes: 0,
+                }),
+            }
+            group_of.push(Some(groups.len() - 1));
+            last_bits.replace(bits.bits.clone());
+
+            // Check group alignment when it ends.
+            let ends = cfgs
+                .get(i + 1)
+                .and_then(Option::as_ref)
+                .is_none_or(|cfg| cfg.bits.is_none());
+            if let (true, Some(group), Some(last_bits)) = (ends, groups.last_mut(), &last_bits) {
+                let total = group.counts.iter().sum::<u32>();
+                if total % 8 != 0 {
+                    aggr.push_err(::syn::Error::new(
+                        last_bits.span(),
+                        format!(
+                            "bit group does not end on a byte boundary, \
+                                it uses {total} bits which is not a multiple of 8"
+                        ),
+                    ));
+                }
+                group.bytes = total.div_ceil(8) as usize;
+            }
+        }
+
+        (groups, group_of)
+    }
+}
+
+impl FieldChecksum {
+    /// Get checksums of fields, if any, by index of field.
+    fn collect(
+        fields: &::syn::Fields,
+        cfgs: &[Option<FieldConfig>],
+        barse_path: &::syn::Path,
+        r: u32,
+        aggr: &mut ResAggr,
+    ) -> Vec<Option<Self>> {
+        let index_of = |ident: &::syn::Ident, here: usize, aggr: &mut ResAggr| {
+            if ident == "start" {
+                return Some(0);
+            }
+            if ident == "here" {
+                return Some(here);
+            }
+            let index = fields.iter().position(|field| {
+                field
+                    .ident
+                    .as_ref()
+                    .is_some_and(|field| field.unraw() == ident.unraw())
+            });
+            if index.is_none() {
+                aggr.push_err(::syn::Error::new(
+                    ident.span(),
+                    "expected 'start', 'here' or name of a field",
+                ));
+            }
+            index
+        };
+
+        cfgs.iter()
+            .enumerate()
+            .map(|(i, cfg)| {
+                let checksum = cfg.as_ref()?.checksum.as_ref()?;
+                let covers = match &checksum.args.over {
+                    Some(over) => {
+                        let start = index_of(&over.start, i, aggr)?;
+                        let end = index_of(&over.end, i, aggr)?;
+                        if start > end || end > i {
+                            aggr.push_err(::syn::Error::new(
+                                over.kw.span,
+                                "checksum may only cover fields preceding it, in order",
+                            ));
+                            return None;
+                        }
+                        start..end
+                    }
+                    None => 0..i,
+                };
+                let algorithm = &checksum.args.algorithm;
+                let ty = match algorithm
+                    .get_ident()
+                    .map(|ident| ident.to_string())
+                    .as_deref()
+                {
+                    Some("crc32") => quote! { #barse_path::adapter::Crc32 },
+                    Some("adler32") => quote! { #barse_path::adapter::Adler32 },
+                    Some("sum8") => quote! { #barse_path::adapter::Additive<u8> },
+                    Some("sum16") => quote! { #barse_path::adapter::Additive<u16> },
+                    Some("sum32") => quote! { #barse_path::adapter::Additive<u32> },
+                    Some("sum64") => quote! { #barse_path::adapter::Additive<u64> },
+                    _ => algorithm.to_token_stream(),
+                };
+                Some(Self {
+                    state: format_ident!("__checksum_{i}_{r:x}"),
+                    ty,
+                    covers,
+                })
+            })
+            .collect()
+    }
+}
+
 /// Values that need to be supplied to fields.
 #[derive(Debug)]
 pub struct FieldDeps<'a> {
@@ -75,24 +351,297 @@ pub struct FieldDeps<'a> {
     /// Endian path.
     pub endian: Option<&'a ::syn::Path>,
 
+    /// Order of bits in bit fields.
+    pub bit_order: Option<&'a ::syn::Ident>,
+
+    /// Magic bytes preceding fields.
+    pub magic: Option<&'a ::syn::LitByteStr>,
+
+    /// Assertions checked after all fields are read.
+    pub asserts: Option<&'a opt::Asserts>,
+
     /// Impl idents.
     pub impl_idents: &'a ImplIdents,
+
+    /// Name of type used as root of error context.
+    pub type_name: &'a str,
+
+    /// Traits being implemented.
+    pub barse_traits: &'a [BarseTrait],
+
+    /// Type of source fields are read from, if not the source generic param.
+    pub source_ty: Option<&'a TokenStream>,
+
+    /// Position of first field within record, zero if not set.
+    pub position: Option<&'a TokenStream>,
+
+    /// First field holds the discriminant, which is read/written by the enum.
+    pub discriminant_field: bool,
+
+    /// Record is padded to a multiple of given size after all fields.
+    pub pad_to_multiple: Option<&'a ::syn::Expr>,
+
+    /// What alignment and padding is relative to, the start of record if not set.
+    pub align_base: Option<&'a ::syn::Ident>,
+}
+
+/// Get read and write code of magic bytes for a trait, read bytes are verified.
+///
+/// Context is applied to errors after their offset.
+fn magic_bodies(
+    magic: &::syn::LitByteStr,
+    barse_trait: &BarseTrait,
+    barse_path: &::syn::Path,
+    byte_ident: &::syn::Ident,
+    (from_ident, source_ty): (&::syn::Ident, &dyn ToTokens),
+    (to_ident, sink_ty): (&::syn::Ident, &dyn ToTokens),
+    context: &TokenStream,
+) -> FieldBodies {
+    let source_trait = barse_trait.source_trait(barse_path);
+    let sink_trait = barse_trait.sink_trait(barse_path);
+    let await_suffix = barse_trait.await_suffix();
+    let len = magic.value().len();
+
+    let read_ctx = quote! {
+        |err: #barse_path::WrappedErr<#byte_ident::Err>| err
+            .at_offset(<#source_ty as #source_trait>::offset(#from_ident))
+            #context
+    };
+    let write_ctx = quote! {
+        |err: #barse_path::WrappedErr<#byte_ident::Err>| err
+            .at_offset(<#sink_ty as #sink_trait>::offset(#to_ident))
+            #context
+    };
+
+    FieldBodies {
+        read_body: quote! {{
+            const MAGIC: &[u8] = #magic;
+            let actual = <#source_ty as #source_trait>::read_array::<#len>(#from_ident)
+                #await_suffix
+                .map_err(#barse_path::WrappedErr::from)
+                .map_err(#read_ctx)?;
+            if actual != *MAGIC {
+                return ::core::result::Result::Err(#barse_path::WrappedErr::from_err(
+                    #barse_path::Error::Magic(#barse_path::error::MagicMismatch::new(
+                        &MAGIC,
+                        &actual,
+                    )),
+                ))
+                .map_err(#read_ctx);
+            }
+        }},
+        write_body: quote! {
+            <#sink_ty as #sink_trait>::write_slice(#to_ident, #magic)
+                #await_suffix
+                .map_err(#barse_path::WrappedErr::from)
+                .map_err(#write_ctx)?;
+        },
+    }
+}
+
+/// Get checks of assertions for a trait, write checks only cover assertions marked 'write'.
+///
+/// Conditions see fields as references, on read the given read fields are borrowed to match
+/// write. Context is applied to errors after their offset.
+fn assert_bodies(
+    (asserts, read): (&opt::Asserts, &[(::syn::Ident, String)]),
+    barse_trait: &BarseTrait,
+    barse_path: &::syn::Path,
+    byte_ident: &::syn::Ident,
+    (from_ident, source_ty): (&::syn::Ident, &dyn ToTokens),
+    (to_ident, sink_ty): (&::syn::Ident, &dyn ToTokens),
+    context: &TokenStream,
+) -> FieldBodies {
+    let source_trait = barse_trait.source_trait(barse_path);
+    let sink_trait = barse_trait.sink_trait(barse_path);
+
+    let check = |opt::AssertArgs {
+                     condition, message, ..
+                 }: &opt::AssertArgs,
+                 ctx: &TokenStream,
+                 borrowed: &[&::syn::Ident]| {
+        let borrow = (!borrowed.is_empty()).then(|| {
+            quote! { let (#(#borrowed,)*) = (#(&#borrowed,)*); }
+        });
+        quote! {{
+            #borrow
+            if !(#condition) {
+                return ::core::result::Result::Err(#barse_path::WrappedErr::from_err(
+                    #barse_path::Error::Assertion(#barse_path::error::AssertionFailed {
+                        message: #message,
+                    }),
+                ))
+                .map_err(#ctx);
+            }
+        }}
+    };
+    let read_ctx = quote! {
+        |err: #barse_path::WrappedErr<#byte_ident::Err>| err
+            .at_offset(<#source_ty as #source_trait>::offset(#from_ident))
+            #context
+    };
+    let write_ctx = quote! {
+        |err: #barse_path::WrappedErr<#byte_ident::Err>| err
+            .at_offset(<#sink_ty as #sink_trait>::offset(#to_ident))
+            #context
+    };
+
+    FieldBodies {
+        read_body: asserts
+            .0
+            .iter()
+            .map(|a| {
+                // Only fields mentioned are borrowed, as to not leave unused bindings.
+                let borrowed = read
+                    .iter()
+                    .map(|(name, _)| name)
+                    .filter(|name| mentions(a.args.condition.to_token_stream(), name))
+                    .collect::<Vec<_>>();
+                check(&a.args, &read_ctx, &borrowed)
+            })
+            .collect(),
+        write_body: asserts
+            .0
+            .iter()
+            .filter(|a| a.args.write.is_some())
+            .map(|a| check(&a.args, &write_ctx, &[]))
+            .collect(),
+    }
+}
+
+/// Replace 'self.field' in tokens by a deref of the borrowed field, such that they may be used
+/// without self.
+fn self_to_names(tokens: TokenStream, names: &[(::syn::Ident, String)]) -> TokenStream {
+    let mut trees = tokens.into_iter().peekable();
+    let mut replaced = TokenStream::default();
+    while let Some(tree) = trees.next() {
+        let tree = match tree {
+            ::proc_macro2::TokenTree::Ident(ident) if ident == "self" => {
+                let mut lookahead = trees.clone();
+                let name = match (lookahead.next(), lookahead.next()) {
+                    (Some(::proc_macro2::TokenTree::Punct(dot)), Some(field))
+                        if dot.as_char() == '.' =>
+                    {
+                        let field = match field {
+                            ::proc_macro2::TokenTree::Ident(field) => field.unraw().to_string(),
+                            other => other.to_string(),
+                        };
+                        names
+                            .iter()
+                            .find(|(_, field_name)| *field_name == field)
+                            .map(|(name, _)| name)
+                    }
+                    _ => None,
+                };
+                if let Some(name) = name {
+                    trees = lookahead;
+                    quote! { (*#name) }.to_tokens(&mut replaced);
+                    continue;
+                }
+                ::proc_macro2::TokenTree::Ident(ident)
+            }
+            ::proc_macro2::TokenTree::Group(group) => {
+                let mut replaced = ::proc_macro2::Group::new(
+                    group.delimiter(),
+                    self_to_names(group.stream(), names),
+                );
+                replaced.set_span(group.span());
+                ::proc_macro2::TokenTree::Group(replaced)
+            }
+            other => other,
+        };
+        tree.to_tokens(&mut replaced);
+    }
+    replaced
+}
+
+/// Check if tokens mention ident.
+fn mentions(tokens: TokenStream, ident: &::syn::Ident) -> bool {
+    tokens.into_iter().any(|tree| match tree {
+        ::proc_macro2::TokenTree::Ident(found) => found == *ident,
+        ::proc_macro2::TokenTree::Group(group) => mentions(group.stream(), ident),
+        ::proc_macro2::TokenTree::Punct(_) | ::proc_macro2::TokenTree::Literal(_) => false,
+    })
+}
+
+/// Get names of fields in expressions, and as shown in errors.
+pub fn field_names(
+    fields: &::syn::Fields,
+    field_prefix: Option<&::syn::Ident>,
+    aggr: &mut ResAggr,
+) -> Vec<(::syn::Ident, String)> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let name = match (&field.ident, field_prefix) {
+                // No prefix, normal fields.
+                (Some(ident), None) => ident.clone(),
+
+                // Prefix, normal fields.
+                (Some(ident), Some(field_prefix)) => format_ident!("{field_prefix}{ident}"),
+
+                // No Prefix, tuple fields.
+                (None, None) => {
+                    aggr.push_err(::syn::Error::new(
+                        Span::call_site(),
+                        "field prefix needs to exist for tuple structs",
+                    ));
+                    format_ident!("_{i}")
+                }
+
+                // Prefix, tuple fields.
+                (None, Some(field_prefix)) => format_ident!("{field_prefix}{i}"),
+            };
+            let field_name = field
+                .ident
+                .as_ref()
+                .map_or_else(|| i.to_string(), |ident| ident.unraw().to_string());
+            (name, field_name)
+        })
+        .collect()
+}
+
+/// Wrap expression such that earlier fields it mentions are borrowed, as they are on write.
+fn borrowing_earlier(expr: &::syn::Expr, earlier: &[(::syn::Ident, String)]) -> TokenStream {
+    let borrowed = earlier
+        .iter()
+        .map(|(name, _)| name)
+        .filter(|name| mentions(expr.to_token_stream(), name))
+        .collect::<Vec<_>>();
+    if borrowed.is_empty() {
+        expr.to_token_stream()
+    } else {
+        quote! {{
+            let (#(#borrowed,)*) = (#(&#borrowed,)*);
+            #expr
+        }}
+    }
 }
 
 impl ProcessedFields {
     /// Generate code from fields.
     pub fn new(fields: &::syn::Fields, deps: FieldDeps, aggr: &mut ResAggr) -> ProcessedFields {
-        let mut f = ProcessedFields::default();
+        let mut f = ProcessedFields {
+            name_expansion: TokenStream::default(),
+            bodies: vec![FieldBodies::default(); deps.barse_traits.len()],
+            len_body: TokenStream::default(),
+            fixed_size: Some(quote! { 0 }),
+        };
         let ProcessedFields {
             name_expansion,
-            read_body,
-            write_body,
+            bodies,
+            len_body,
+            fixed_size,
         } = &mut f;
         let FieldDeps {
             field_prefix,
             barse_path,
             read_with_expr,
             endian,
+            bit_order,
+            magic,
+            asserts,
             write_with_expr,
             impl_idents:
                 ImplIdents {
@@ -102,24 +651,256 @@ impl ProcessedFields {
                     with_ident: _,
                     to_ident,
                     from_ident,
-                    discriminant_ident: _,
+                    discriminant_ident,
+                    len_ident,
                 },
+            type_name,
+            barse_traits,
+            source_ty,
+            position,
+            discriminant_field,
+            pad_to_multiple,
+            align_base,
         } = deps;
         let default_expr = unit_expr();
 
-        for (i, field) in fields.iter().enumerate() {
-            let cfg = match FieldConfig::default().parse_attrs(&field.attrs) {
-                Ok(cfg) => cfg,
-                Err(err) => {
-                    aggr.push_err(err);
-                    continue;
+        let cfgs = fields
+            .iter()
+            .map(|field| {
+                FieldConfig::default()
+                    .parse_attrs(&field.attrs)
+                    .map_err(|err| aggr.push_err(err))
+                    .ok()
+            })
+            .collect::<Vec<_>>();
+
+        // Position within record is tracked by adapters when fields are aligned.
+        let tracked =
+            pad_to_multiple.is_some() || cfgs.iter().flatten().any(|cfg| cfg.align.is_some());
+        let align_offset = match align_base.map(|base| (base, base.to_string())) {
+            None => false,
+            Some((_, base)) if base == "record" => false,
+            Some((_, base)) if base == "offset" => true,
+            Some((base, _)) => {
+                aggr.push_err(::syn::Error::new(
+                    base.span(),
+                    "expected one of 'record' or 'offset'",
+                ));
+                false
+            }
+        };
+        let source_ty = source_ty.map_or_else(|| byte_ident.to_token_stream(), Clone::clone);
+        let (source_ty, sink_ty) = if tracked {
+            let position = position.map_or_else(|| quote! { 0usize }, Clone::clone);
+            let position_ident = format_ident!("__position_{_r:x}");
+            for (
+                barse_trait,
+                FieldBodies {
+                    read_body,
+                    write_body,
+                },
+            ) in barse_traits.iter().zip(bodies.iter_mut())
+            {
+                // Alignment relative to offset starts tracking at offset of source/sink.
+                let (read_position, write_position) = if align_offset {
+                    let source_trait = barse_trait.source_trait(barse_path);
+                    let sink_trait = barse_trait.sink_trait(barse_path);
+                    let offset_position = |offset: TokenStream| {
+                        quote! {
+                            #barse_path::adapter::offset_position(#offset).map_err(|err| {
+                                #barse_path::WrappedErr::<#byte_ident::Err>::from_err(err)
+                                    .in_type(#type_name)
+                            })?
+                        }
+                    };
+                    (
+                        offset_position(
+                            quote! { <#source_ty as #source_trait>::offset(#from_ident) },
+                        ),
+                        offset_position(quote! { <#byte_ident as #sink_trait>::offset(#to_ident) }),
+                    )
+                } else {
+                    (position.clone(), position.clone())
+                };
+                quote! {
+                    let #position_ident = #read_position;
+                    let #from_ident = &mut #barse_path::adapter::Tracked::with_position(
+                        &mut *#from_ident,
+                        #position_ident,
+                    );
+                }
+                .to_tokens(read_body);
+                quote! {
+                    let #position_ident = #write_position;
+                    let #to_ident = &mut #barse_path::adapter::TrackedSink::with_position(
+                        &mut *#to_ident,
+                        #position_ident,
+                    );
                 }
+                .to_tokens(write_body);
+            }
+            (
+                quote! { #barse_path::adapter::Tracked<&mut #source_ty> },
+                quote! { #barse_path::adapter::TrackedSink<&mut #byte_ident> },
+            )
+        } else {
+            (source_ty, byte_ident.to_token_stream())
+        };
+        let checksums = FieldChecksum::collect(fields, &cfgs, barse_path, *_r, aggr);
+        let (bit_groups, bit_group_of) = BitGroup::collect(&cfgs, aggr);
+        let bit_order = match bit_order.map(|order| (order, order.to_string())) {
+            None => format_ident!("Msb0"),
+            Some((_, order)) if order == "msb0" => format_ident!("Msb0"),
+            Some((_, order)) if order == "lsb0" => format_ident!("Lsb0"),
+            Some((order, _)) => {
+                aggr.push_err(::syn::Error::new(
+                    order.span(),
+                    "expected one of 'msb0' or 'lsb0'",
+                ));
+                format_ident!("Msb0")
+            }
+        };
+
+        let names = field_names(fields, field_prefix, aggr);
+
+        // Declare running checksums before any field is read/written.
+        for FieldChecksum { state, ty, covers } in checksums.iter().flatten() {
+            let mutability = (!covers.is_empty()).then(<Token![mut]>::default);
+            for FieldBodies {
+                read_body,
+                write_body,
+            } in bodies.iter_mut()
+            {
+                let declaration = quote! {
+                    let #mutability #state = <#ty as ::core::default::Default>::default();
+                };
+                declaration.to_tokens(read_body);
+                declaration.to_tokens(write_body);
+            }
+        }
+
+        if let Some(asserts) = asserts {
+            for (barse_trait, FieldBodies { write_body, .. }) in
+                barse_traits.iter().zip(bodies.iter_mut())
+            {
+                assert_bodies(
+                    (asserts, &names),
+                    barse_trait,
+                    barse_path,
+                    byte_ident,
+                    (from_ident, &source_ty),
+                    (to_ident, &sink_ty),
+                    &quote! { .in_type(#type_name) },
+                )
+                .write_body
+                .to_tokens(write_body);
+            }
+        }
+
+        // Magic bytes precede all fields.
+        if let Some(magic) = magic {
+            for (
+                barse_trait,
+                FieldBodies {
+                    read_body,
+                    write_body,
+                },
+            ) in barse_traits.iter().zip(bodies.iter_mut())
+            {
+                let FieldBodies {
+                    read_body: magic_read,
+                    write_body: magic_write,
+                } = magic_bodies(
+                    magic,
+                    barse_trait,
+                    barse_path,
+                    byte_ident,
+                    (from_ident, &source_ty),
+                    (to_ident, &sink_ty),
+                    &quote! { .in_type(#type_name) },
+                );
+                magic_read.to_tokens(read_body);
+                magic_write.to_tokens(write_body);
+            }
+
+            let len = magic.value().len();
+            quote! {
+                let #len_ident = usize::saturating_add(#len_ident, #len);
+            }
+            .to_tokens(len_body);
+            if let Some(size) = fixed_size {
+                *size = quote! { #len };
+            }
+        }
+
+        for (i, (field, cfg)) in fields.iter().zip(&cfgs).enumerate() {
+            let Some(cfg) = cfg else {
+                continue;
             };
 
             aggr.conflict(&cfg.read_with, &cfg.with)
                 .conflict(&cfg.write_with, &cfg.with)
                 .conflict(&cfg.read_as, &cfg.barse_as)
-                .conflict(&cfg.write_as, &cfg.barse_as);
+                .conflict(&cfg.write_as, &cfg.barse_as)
+                .conflict(&cfg.until, &cfg.count)
+                .conflict(&cfg.until_eof, &cfg.count)
+                .conflict(&cfg.until_eof, &cfg.until)
+                .conflict(&cfg.size, &cfg.ignore)
+                .conflict(&cfg.checksum, &cfg.ignore)
+                .conflict(&cfg.bits, &cfg.ignore)
+                .conflict(&cfg.bits, &cfg.bytes)
+                .conflict(&cfg.bits, &cfg.read_bytes)
+                .conflict(&cfg.bits, &cfg.write_bytes)
+                .conflict(&cfg.bits, &cfg.barse_as)
+                .conflict(&cfg.bits, &cfg.read_as)
+                .conflict(&cfg.bits, &cfg.write_as)
+                .conflict(&cfg.bits, &cfg.with)
+                .conflict(&cfg.bits, &cfg.read_with)
+                .conflict(&cfg.bits, &cfg.write_with)
+                .conflict(&cfg.bits, &cfg.endian)
+                .conflict(&cfg.bits, &cfg.count)
+                .conflict(&cfg.bits, &cfg.until_eof)
+                .conflict(&cfg.bits, &cfg.until)
+                .conflict(&cfg.bits, &cfg.size)
+                .conflict(&cfg.bits, &cfg.checksum)
+                .conflict(&cfg.magic, &cfg.ignore)
+                .conflict(&cfg.magic, &cfg.bits)
+                .conflict(&cfg.assert, &cfg.ignore)
+                .conflict(&cfg.calc, &cfg.ignore)
+                .conflict(&cfg.calc, &cfg.bits)
+                .conflict(&cfg.calc, &cfg.checksum)
+                .conflict(&cfg.field_if, &cfg.ignore)
+                .conflict(&cfg.field_if, &cfg.bits)
+                .conflict(&cfg.field_if, &cfg.checksum)
+                .conflict(&cfg.field_if, &cfg.calc)
+                .conflict(&cfg.align, &cfg.ignore)
+                .conflict(&cfg.align, &cfg.bits)
+                .conflict(&cfg.offset, &cfg.ignore)
+                .conflict(&cfg.offset, &cfg.bits)
+                .conflict(&cfg.offset, &cfg.align);
+
+            if let Some(offset) = &cfg.offset {
+                if !barse_traits.iter().any(|t| matches!(t, BarseTrait::Seek)) {
+                    aggr.push_err(::syn::Error::new(
+                        offset.kw.span,
+                        "'offset' requires 'seek' to be set",
+                    ));
+                }
+            }
+
+            if let (Some(verify), None) = (&cfg.verify, &cfg.calc) {
+                aggr.push_err(::syn::Error::new(
+                    verify.kw.span,
+                    "'verify' requires 'calc' to be set",
+                ));
+            }
+
+            if let (Some(leftover), None) = (&cfg.leftover, &cfg.size) {
+                aggr.push_err(::syn::Error::new(
+                    leftover.kw.span,
+                    "'leftover' requires 'size' to be set",
+                ));
+            }
 
             if cfg!(not(feature = "barse_as")) {
                 const BARSE_AS: &str = "barse_as";
@@ -128,30 +909,12 @@ impl ProcessedFields {
                     .requires_feature(BARSE_AS, &cfg.write_as);
             }
 
-            let name = match (&field.ident, field_prefix) {
-                // No prefix, normal fields.
-                (Some(ident), None) => ident.clone(),
-
-                // Prefix, normal fields.
-                (Some(ident), Some(field_prefix)) => format_ident!("{field_prefix}{ident}"),
-
-                // No Prefix, tuple fields.
-                (None, None) => {
-                    aggr.push_err(::syn::Error::new(
-                        Span::call_site(),
-                        "field prefix needs to exist for tuple structs",
-                    ));
-                    format_ident!("_{i}")
-                }
-
-                // Prefix, tuple fields.
-                (None, Some(field_prefix)) => format_ident!("{field_prefix}{i}"),
-            };
+            let (name, field_name) = &names[i];
 
             field
                 .ident
                 .as_ref()
-                .filter(|&ident| ident != &name)
+                .filter(|&ident| ident != name)
                 .map_or_else(
                     || {
                         quote! { #name, }
@@ -162,23 +925,361 @@ impl ProcessedFields {
                 )
                 .to_tokens(name_expansion);
 
-            let ty = &field.ty;
+            // Conditional fields are read/written as the type wrapped by their Option.
+            let ty = match &cfg.field_if {
+                Some(field_if) => option_inner(&field.ty).unwrap_or_else(|| {
+                    aggr.push_err(::syn::Error::new(
+                        field_if.kw.span,
+                        "'if' is only supported for Option fields",
+                    ));
+                    &field.ty
+                }),
+                None => &field.ty,
+            };
 
-            if let Some(ignore) = &cfg.ignore {
-                // Field should be ignored.
-                let expr = ignore.value.as_ref().map_or_else(
+            // Calculated fields are rebound before being written, such that following fields see
+            // the calculated value.
+            let calc = cfg.calc.as_ref().map(|calc| {
+                let calc = self_to_names(calc.expr.to_token_stream(), &names);
+                quote! {
+                    let #name: &#ty = &(#calc);
+                }
+            });
+
+            // Count of borrowed fields is their with value, other fields are collections.
+            let borrowed = matches!(ty, ::syn::Type::Reference(_));
+            let count_expr = cfg.count.as_ref().filter(|_| borrowed).map(|count| {
+                let count = &count.count;
+                quote! { (#count) as usize }
+            });
+            if borrowed {
+                aggr.conflict(&cfg.count, &cfg.read_with)
+                    .conflict(&cfg.count, &cfg.with);
+                if let Some(span) = cfg
+                    .until
+                    .as_ref()
+                    .map(|o| o.kw.span)
+                    .or(cfg.until_eof.as_ref().map(|o| o.kw.span))
+                {
+                    aggr.push_err(::syn::Error::new(
+                        span,
+                        "'until' and 'until_eof' are not supported for borrowed fields",
+                    ));
+                }
+            }
+            let repeat = if let Some(until) = &cfg.until {
+                Some(Repeat::Until(&until.until))
+            } else if cfg.until_eof.is_some() {
+                Some(Repeat::UntilEof)
+            } else {
+                cfg.count
+                    .as_ref()
+                    .filter(|_| !borrowed)
+                    .map(|count| Repeat::Count(&count.count))
+            };
+            if repeat.is_some() {
+                aggr.conflict(&cfg.count, &cfg.bytes)
+                    .conflict(&cfg.count, &cfg.read_bytes)
+                    .conflict(&cfg.count, &cfg.write_bytes)
+                    .conflict(&cfg.until, &cfg.bytes)
+                    .conflict(&cfg.until, &cfg.read_bytes)
+                    .conflict(&cfg.until, &cfg.write_bytes)
+                    .conflict(&cfg.until_eof, &cfg.bytes)
+                    .conflict(&cfg.until_eof, &cfg.read_bytes)
+                    .conflict(&cfg.until_eof, &cfg.write_bytes);
+            }
+
+            // Collection fields are read/written one item at a time.
+            let item_ty = repeat.map(|_| quote! { <#ty as #barse_path::Collection>::Item });
+            let items_ident = format_ident!("__items_{_r:x}");
+            let item_ident = format_ident!("__item_{_r:x}");
+            let index_ident = format_ident!("__index_{_r:x}");
+            let value_ty = item_ty.as_ref().map_or_else(|| Either::A(ty), Either::B);
+            let value = if repeat.is_some() { &item_ident } else { name };
+
+            // Sizes of fields read/written in ways other than Barse are not known.
+            let bit_group = bit_group_of[i].map(|group| &bit_groups[group]);
+            let field_size = if cfg.ignore.is_some() {
+                Some(quote! { 0 })
+            } else if let Some(group) = bit_group {
+                let bytes = if group.members.start == i {
+                    group.bytes
+                } else {
+                    0
+                };
+                Some(quote! { #bytes })
+            } else if let Some(count) = cfg.bytes.as_deref() {
+                Some(quote! { #count })
+            } else if cfg.read_bytes.is_some()
+                || cfg.write_bytes.is_some()
+                || cfg.read_as.is_some()
+                || cfg.write_as.is_some()
+                || cfg.barse_as.is_some()
+                || cfg.count.is_some()
+                || cfg.until.is_some()
+                || cfg.until_eof.is_some()
+                || cfg.size.is_some()
+                || cfg.field_if.is_some()
+                || cfg.align.is_some()
+                || cfg.offset.is_some()
+            {
+                None
+            } else {
+                Some(type_fixed_size(ty, barse_path))
+            };
+            let magic_len = cfg.magic.as_ref().map(|magic| magic.magic.value().len());
+            let field_size = field_size.map(|size| match magic_len {
+                Some(len) => quote! { #len + #size },
+                None => size,
+            });
+            *fixed_size = fixed_size
+                .take()
+                .zip(field_size)
+                .map(|(fields_size, size)| quote! { #fields_size + #size });
+
+            let ignored = cfg.ignore.as_ref().map(|ignore| {
+                ignore.value.as_ref().map_or_else(
                     || Either::A(quote! { <#ty as ::core::default::Default>::default() }),
-                    |value| Either::B(&value.value),
-                );
+                    |value| Either::B(value.value.to_token_stream()),
+                )
+            });
+            // Discriminant field is neither read nor written, as the enum does so.
+            let ignored = if discriminant_field && i == 0 {
+                Some(Either::B(discriminant_ident.to_token_stream()))
+            } else {
+                ignored
+            };
+            if let Some(expr) = ignored {
+                // Field should be ignored.
 
+                for FieldBodies {
+                    read_body,
+                    write_body,
+                } in bodies.iter_mut()
+                {
+                    quote! {
+                        let #name = #expr;
+                    }
+                    .to_tokens(read_body);
+                    quote! {
+                        _ = #name;
+                    }
+                    .to_tokens(write_body);
+                }
                 quote! {
-                    let #name = #expr;
+                    _ = #name;
                 }
-                .to_tokens(read_body);
+                .to_tokens(len_body);
+                continue;
+            }
+
+            let limit = cfg.size.as_ref().map(|size| {
+                let policy = cfg.leftover.as_ref().map_or_else(
+                    || format_ident!("Error"),
+                    |leftover| match leftover.policy.to_string().as_str() {
+                        "error" => format_ident!("Error"),
+                        "skip" => format_ident!("Skip"),
+                        "keep" => format_ident!("Keep"),
+                        _ => {
+                            aggr.push_err(::syn::Error::new(
+                                leftover.policy.span(),
+                                "expected one of 'error', 'skip' or 'keep'",
+                            ));
+                            format_ident!("Error")
+                        }
+                    },
+                );
+                (&size.size, policy)
+            });
+
+            // Checksums covering field, read from/written to adapters.
+            let covering = checksums
+                .iter()
+                .flatten()
+                .filter(|checksum| checksum.covers.contains(&i))
+                .collect::<Vec<_>>();
+            let (checked_source_ty, checked_sink_ty) = covering.iter().fold(
+                (source_ty.clone(), sink_ty.clone()),
+                |(source_ty, sink_ty), FieldChecksum { ty, .. }| {
+                    (
+                        quote! { #barse_path::adapter::Checksummed<&mut #source_ty, &mut #ty> },
+                        quote! { #barse_path::adapter::ChecksummedSink<&mut #sink_ty, &mut #ty> },
+                    )
+                },
+            );
+            let checksum_state = checksums[i].as_ref().map(|checksum| &checksum.state);
+
+            if let Some(BitGroup {
+                members,
+                counts,
+                bytes,
+            }) = bit_group
+            {
+                // Bit groups are read/written at their first field, other fields are bound then.
                 quote! {
                     _ = #name;
                 }
-                .to_tokens(write_body);
+                .to_tokens(len_body);
+                if members.start != i {
+                    continue;
+                }
+                quote! {
+                    let #len_ident = usize::saturating_add(#len_ident, #bytes);
+                }
+                .to_tokens(len_body);
+
+                let member_names = names[members.clone()]
+                    .iter()
+                    .map(|(name, _)| name)
+                    .collect::<Vec<_>>();
+                let member_tys = fields
+                    .iter()
+                    .skip(members.start)
+                    .take(members.len())
+                    .map(|field| &field.ty)
+                    .collect::<Vec<_>>();
+                let member_ctx = names[members.clone()]
+                    .iter()
+                    .map(|(_, field_name)| {
+                        quote! {
+                            |err: #barse_path::WrappedErr<#byte_ident::Err>| err
+                                .in_field(#field_name)
+                                .in_type(#type_name)
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                let member_asserts = cfgs[members.clone()]
+                    .iter()
+                    .zip(&names[members.clone()])
+                    .filter_map(|(cfg, (_, field_name))| {
+                        let asserts = cfg.as_ref()?.assert.as_ref()?;
+                        Some((
+                            asserts,
+                            quote! { .in_field(#field_name).in_type(#type_name) },
+                        ))
+                    })
+                    .collect::<Vec<_>>();
+                let base_source_ty = &source_ty;
+                let base_sink_ty = &sink_ty;
+                let bits_ident = format_ident!("__bits_{_r:x}");
+                let writer_ident = format_ident!("__bit_writer_{_r:x}");
+                let bound = quote! { (#(#member_names,)*) };
+
+                for (
+                    barse_trait,
+                    FieldBodies {
+                        read_body,
+                        write_body,
+                    },
+                ) in barse_traits.iter().zip(bodies.iter_mut())
+                {
+                    let source_trait = barse_trait.source_trait(barse_path);
+                    let sink_trait = barse_trait.sink_trait(barse_path);
+                    let await_suffix = barse_trait.await_suffix();
+
+                    let source_ty = &checked_source_ty;
+                    let sink_ty = &checked_sink_ty;
+                    let read_ctx = quote! {
+                        |err: #barse_path::WrappedErr<#byte_ident::Err>| err
+                            .at_offset(<#source_ty as #source_trait>::offset(#from_ident))
+                            .in_field(#field_name)
+                            .in_type(#type_name)
+                    };
+                    let write_ctx = quote! {
+                        |err: #barse_path::WrappedErr<#byte_ident::Err>| err
+                            .at_offset(<#sink_ty as #sink_trait>::offset(#to_ident))
+                            .in_field(#field_name)
+                            .in_type(#type_name)
+                    };
+
+                    let mut field_read = quote! {
+                        let #bound = {
+                            let mut #bits_ident = [0u8; #bytes];
+                            <#source_ty as #source_trait>::read_slice(#from_ident, &mut #bits_ident)
+                                #await_suffix
+                                .map_err(#barse_path::WrappedErr::from)
+                                .map_err(#read_ctx)?;
+                            let mut #bits_ident = #barse_path::adapter::BitReader::<
+                                _,
+                                #barse_path::adapter::#bit_order,
+                            >::new(#barse_path::SliceSrc::new(&#bits_ident));
+                            (#(
+                                #barse_path::adapter::BitReader::read_field::<#member_tys>(
+                                    &mut #bits_ident,
+                                    #counts,
+                                )
+                                .map_err(#barse_path::WrappedErr::from_err)
+                                .map_err(#member_ctx)?,
+                            )*)
+                        };
+                    };
+                    for FieldChecksum { state, .. } in covering.iter().rev() {
+                        field_read = quote! {
+                            let #bound = {
+                                let #from_ident = &mut #barse_path::adapter::Checksummed::new(
+                                    &mut *#from_ident,
+                                    &mut #state,
+                                );
+                                #field_read
+                                #bound
+                            };
+                        };
+                    }
+                    field_read.to_tokens(read_body);
+
+                    let mut field_write = TokenStream::default();
+                    for (asserts, ctx) in &member_asserts {
+                        let FieldBodies {
+                            read_body: assert_read,
+                            write_body: assert_write,
+                        } = assert_bodies(
+                            (asserts, &names[..members.end]),
+                            barse_trait,
+                            barse_path,
+                            byte_ident,
+                            (from_ident, base_source_ty),
+                            (to_ident, base_sink_ty),
+                            ctx,
+                        );
+                        assert_read.to_tokens(read_body);
+                        assert_write.to_tokens(&mut field_write);
+                    }
+
+                    quote! {{
+                        let #bits_ident = {
+                            let mut #bits_ident = [0u8; #bytes];
+                            let mut #writer_ident = #barse_path::adapter::BitWriter::<
+                                _,
+                                #barse_path::adapter::#bit_order,
+                            >::new(#barse_path::SliceSink::new(&mut #bits_ident));
+                            #(
+                                #barse_path::adapter::BitWriter::write_field::<#member_tys>(
+                                    &mut #writer_ident,
+                                    #member_names,
+                                    #counts,
+                                )
+                                .map_err(#barse_path::WrappedErr::from_err)
+                                .map_err(#member_ctx)?;
+                            )*
+                            #bits_ident
+                        };
+                        <#sink_ty as #sink_trait>::write_slice(#to_ident, &#bits_ident)
+                            #await_suffix
+                            .map_err(#barse_path::WrappedErr::from)
+                            .map_err(#write_ctx)?;
+                    }}
+                    .to_tokens(&mut field_write);
+                    for FieldChecksum { state, .. } in covering.iter().rev() {
+                        field_write = quote! {{
+                            let #to_ident = &mut #barse_path::adapter::ChecksummedSink::new(
+                                &mut *#to_ident,
+                                &mut #state,
+                            );
+                            #field_write
+                        }};
+                    }
+                    field_write.to_tokens(write_body);
+                }
                 continue;
             }
 
@@ -188,95 +1289,774 @@ impl ProcessedFields {
                 .or(endian)
                 .map_or_else(|| Either::A(&endian_ident), Either::B);
 
-            if let Some(count) = cfg.read_bytes.as_deref().or(cfg.bytes.as_deref()) {
-                // Field should be read as bytes.
-                quote! {
-                    let mut #name = [0u8; #count];
-                    <#byte_ident as #barse_path::ByteSource>::read_slice(#from_ident, &mut #name)?;
-                    let #name = <#ty as ::core::convert::From<[u8; #count]>>::from(#name);
-                }
-                .to_tokens(read_body);
-            } else {
-                // Field is read as either barse or AsRead
-                let with_expr = cfg
+            let write_with = cfg
+                .write_with
+                .as_ref()
+                .map(|w| w.expr.as_deref().unwrap_or(write_with_expr))
+                .or(cfg
                     .with
                     .as_ref()
-                    .map(|w| w.expr.as_deref().unwrap_or(read_with_expr));
-
-                let read_with = cfg
-                    .read_with
-                    .as_ref()
-                    .map(|w| w.expr.as_deref().unwrap_or(read_with_expr))
-                    .or(with_expr)
-                    .unwrap_or(&default_expr);
+                    .map(|w| w.expr.as_deref().unwrap_or(write_with_expr)))
+                .unwrap_or(&default_expr);
 
-                let call_expr = cfg
-                    .read_as
-                    .as_deref()
-                    .or(cfg.barse_as.as_deref())
-                    .map_or_else(
-                        || {
-                            quote! {
-                                <#ty as #barse_path::Barse>::read_with::<#e, #byte_ident>(
-                                    #from_ident,
-                                    #read_with
-                                )
-                            }
-                        },
-                        |using| {
-                            quote! {
-                                #barse_path::ReadAs::<#ty, _>::read_with::<#e, #byte_ident>(
+            let at_index = repeat.map(|_| quote! { .at_index(#index_ident) });
+            let len_ctx = quote! {
+                |err: #barse_path::WrappedErr<::core::convert::Infallible>| err
+                    #at_index
+                    .in_field(#field_name)
+                    .in_type(#type_name)
+            };
+            let field_len = match &limit {
+                Some((size, policy)) if policy != "Keep" => {
+                    quote! { #barse_path::adapter::IntoLimit::into_limit(#size) }
+                }
+                _ if cfg.bytes.is_some() || cfg.write_bytes.is_some() => quote! {
+                    <#ty as ::core::convert::AsRef<[u8]>>::as_ref(#name).len()
+                },
+                _ => {
+                    let value_len =
+                        if let Some(using) = cfg.write_as.as_deref().or(cfg.barse_as.as_deref()) {
+                            quote! {{
+                                let #to_ident = &mut #barse_path::CountingSink::new();
+                                #barse_path::WriteAs::<#value_ty, _>::write_with::<
+                                    #barse_path::endian::Native,
+                                    #barse_path::CountingSink,
+                                >(
                                     { #using },
-                                    #from_ident,
-                                    #read_with
+                                    #value,
+                                    #to_ident,
+                                    #write_with
                                 )
+                                .map_err(#len_ctx)?;
+                                usize::try_from(#to_ident.count()).unwrap_or(usize::MAX)
+                            }}
+                        } else {
+                            quote! {
+                                <#value_ty as #barse_path::Barse>::encoded_len(#value, #write_with)
+                                    .map_err(#len_ctx)?
+                            }
+                        };
+                    if repeat.is_some() {
+                        quote! {{
+                            let mut #len_ident = 0usize;
+                            for (#index_ident, #item_ident) in ::core::iter::Iterator::enumerate(
+                                ::core::iter::IntoIterator::into_iter(#name),
+                            ) {
+                                #len_ident = usize::saturating_add(#len_ident, #value_len);
                             }
-                        },
+                            #len_ident
+                        }}
+                    } else {
+                        value_len
+                    }
+                }
+            };
+            calc.to_tokens(len_body);
+            let align = cfg.align.as_ref().map(|align| &align.align);
+            if let Some(align) = align {
+                quote! {
+                    let #len_ident = usize::saturating_add(
+                        #len_ident,
+                        #barse_path::adapter::padding_to(#len_ident, #align),
                     );
-
-                quote! { let #name = #call_expr?; }.to_tokens(read_body);
+                }
+                .to_tokens(len_body);
             }
-
-            if cfg.bytes.is_some() || cfg.write_bytes.is_some() {
-                // Field should be written as bytes.
-                quote! {{
-                    let #name = <#ty as ::core::convert::AsRef<[u8]>>::as_ref(#name);
-                    <#byte_ident as #barse_path::ByteSink>::write_slice(#to_ident, #name)?;
-                }}
-                .to_tokens(write_body);
+            let mut field_len_body = TokenStream::default();
+            if let Some(len) = magic_len {
+                quote! {
+                    let #len_ident = usize::saturating_add(#len_ident, #len);
+                }
+                .to_tokens(&mut field_len_body);
+            }
+            quote! {
+                let #len_ident = usize::saturating_add(#len_ident, #field_len);
+            }
+            .to_tokens(&mut field_len_body);
+            if cfg.field_if.is_some() {
+                quote! {
+                    let #len_ident = match #name {
+                        ::core::option::Option::Some(#name) => {
+                            #field_len_body
+                            #len_ident
+                        }
+                        ::core::option::Option::None => #len_ident,
+                    };
+                }
+                .to_tokens(len_body);
             } else {
-                let with_expr = cfg
-                    .with
-                    .as_ref()
-                    .map(|w| w.expr.as_deref().unwrap_or(write_with_expr));
+                field_len_body.to_tokens(len_body);
+            }
 
-                let write_with = cfg
-                    .write_with
-                    .as_ref()
-                    .map(|w| w.expr.as_deref().unwrap_or(write_with_expr))
-                    .or(with_expr)
-                    .unwrap_or(&default_expr);
+            // Earlier fields are borrowed in conditions and offsets, as they are on write.
+            let field_if = cfg.field_if.as_ref().map(|field_if| {
+                (
+                    borrowing_earlier(&field_if.cond, &names[..i]),
+                    &field_if.cond,
+                )
+            });
+            let offset = cfg.offset.as_ref().map(|offset| {
+                (
+                    borrowing_earlier(&offset.offset, &names[..i]),
+                    &offset.offset,
+                )
+            });
+            let pos_ident = format_ident!("__pos_{_r:x}");
+            let back_ident = format_ident!("__back_{_r:x}");
+            let restored_ident = format_ident!("__restored_{_r:x}");
+
+            for (
+                barse_trait,
+                FieldBodies {
+                    read_body,
+                    write_body,
+                },
+            ) in barse_traits.iter().zip(bodies.iter_mut())
+            {
+                // Adapters of limited and checksummed fields cannot seek.
+                let value_trait = if limit.is_some() || !covering.is_empty() {
+                    barse_trait.unseekable()
+                } else {
+                    barse_trait.clone()
+                };
+                let trait_path = value_trait.path(barse_path);
+                let read_fn = value_trait.read_fn();
+                let write_fn = value_trait.write_fn();
+                let source_trait = barse_trait.source_trait(barse_path);
+                let sink_trait = barse_trait.sink_trait(barse_path);
+                let seek_source = quote! { #barse_path::SeekSource };
+                let guarded_source = quote! { #barse_path::GuardedSource };
+                let seek_sink = quote! { #barse_path::SeekSink };
+                let await_suffix = barse_trait.await_suffix();
+                let finish_fn = barse_trait.finish_fn();
+                let align_fn = barse_trait.align_fn();
+
+                let checked_read_ctx = quote! {
+                    |err: #barse_path::WrappedErr<#byte_ident::Err>| err
+                        .at_offset(<#source_ty as #source_trait>::offset(#from_ident))
+                        .in_field(#field_name)
+                        .in_type(#type_name)
+                };
+
+                let checked_write_ctx = quote! {
+                    |err: #barse_path::WrappedErr<#byte_ident::Err>| err
+                        .at_offset(<#sink_ty as #sink_trait>::offset(#to_ident))
+                        .in_field(#field_name)
+                        .in_type(#type_name)
+                };
 
-                if let Some(using) = cfg.write_as.as_deref().or(cfg.barse_as.as_deref()) {
+                let assert = cfg.assert.as_ref().map(|asserts| {
+                    assert_bodies(
+                        (asserts, &names[..=i]),
+                        barse_trait,
+                        barse_path,
+                        byte_ident,
+                        (from_ident, &source_ty),
+                        (to_ident, &sink_ty),
+                        &quote! { .in_field(#field_name).in_type(#type_name) },
+                    )
+                });
+
+                // Limited fields are read from/written to adapters.
+                let (source_ty, sink_ty, limit_map) = if limit.is_some() {
+                    (
+                        Either::A(
+                            quote! { #barse_path::adapter::Limited<&mut #checked_source_ty> },
+                        ),
+                        Either::A(quote! {
+                            #barse_path::adapter::LimitedSink<&mut #checked_sink_ty>
+                        }),
+                        Some(quote! {
+                            .map_err(|err: #barse_path::WrappedErr<_>| {
+                                err.try_map_wrapped(#barse_path::error::LimitErr::into_inner)
+                            })
+                        }),
+                    )
+                } else {
+                    (
+                        Either::B(&checked_source_ty),
+                        Either::B(&checked_sink_ty),
+                        None,
+                    )
+                };
+
+                if let (BarseTrait::Async, Some(opt)) = (
+                    barse_trait,
+                    cfg.read_as
+                        .as_ref()
+                        .map(|o| o.kw.span)
+                        .or(cfg.write_as.as_ref().map(|o| o.kw.span))
+                        .or(cfg.barse_as.as_ref().map(|o| o.kw.span)),
+                ) {
+                    aggr.push_err(::syn::Error::new(
+                        opt,
+                        "'read_as', 'write_as' and 'as' are not supported with 'async'",
+                    ));
+                    continue;
+                }
+
+                let read_ctx = quote! {
+                    |err: #barse_path::WrappedErr<#byte_ident::Err>| err
+                        .at_offset(<#source_ty as #source_trait>::offset(#from_ident))
+                        .in_field(#field_name)
+                        .in_type(#type_name)
+                };
+                let write_ctx = quote! {
+                    |err: #barse_path::WrappedErr<#byte_ident::Err>| err
+                        .at_offset(<#sink_ty as #sink_trait>::offset(#to_ident))
+                        .in_field(#field_name)
+                        .in_type(#type_name)
+                };
+
+                let mut field_read = TokenStream::default();
+                if let Some(count) = cfg.read_bytes.as_deref().or(cfg.bytes.as_deref()) {
+                    // Field should be read as bytes.
                     quote! {
-                        #barse_path::WriteAs::<#ty, _>::write_with::<#e, #byte_ident>(
-                            { #using },
-                            #name,
-                            #to_ident,
-                            #write_with
-                        )?;
+                        let mut #name = [0u8; #count];
+                        <#source_ty as #source_trait>::read_slice(#from_ident, &mut #name)
+                            #await_suffix
+                            .map_err(#barse_path::WrappedErr::from)
+                            #limit_map
+                            .map_err(#read_ctx)?;
+                        let #name = <#ty as ::core::convert::From<[u8; #count]>>::from(#name);
                     }
+                    .to_tokens(&mut field_read);
                 } else {
+                    // Field is read as either barse or AsRead
+                    let with_expr = cfg
+                        .with
+                        .as_ref()
+                        .map(|w| w.expr.as_deref().unwrap_or(read_with_expr));
+
+                    let read_with = cfg
+                        .read_with
+                        .as_ref()
+                        .map(|w| w.expr.as_deref().unwrap_or(read_with_expr))
+                        .or(with_expr)
+                        .unwrap_or(&default_expr);
+                    let read_with = count_expr
+                        .as_ref()
+                        .map_or_else(|| Either::A(read_with), Either::B);
+
+                    // Items read until end of source are read from a source replaying the byte
+                    // the end was checked with.
+                    let item_source_ty = if matches!(repeat, Some(Repeat::UntilEof)) {
+                        Either::A(quote! { #barse_path::adapter::Prefixed<'_, &mut #source_ty> })
+                    } else {
+                        Either::B(&source_ty)
+                    };
+                    let call_expr = cfg
+                        .read_as
+                        .as_deref()
+                        .or(cfg.barse_as.as_deref())
+                        .map_or_else(
+                            || {
+                                quote! {
+                                    <#value_ty as #trait_path>::#read_fn::<#e, #item_source_ty>(
+                                        #from_ident,
+                                        #read_with
+                                    )
+                                    #await_suffix
+                                }
+                            },
+                            |using| {
+                                quote! {
+                                    #barse_path::ReadAs::<#value_ty, _>::read_with::<
+                                        #e,
+                                        #item_source_ty,
+                                    >(
+                                        { #using },
+                                        #from_ident,
+                                        #read_with
+                                    )
+                                }
+                            },
+                        );
+
+                    if let Some(repeat) = repeat {
+                        let collection = quote! { <#ty as #barse_path::Collection> };
+                        let item_ctx = quote! {
+                            |err: #barse_path::WrappedErr<#byte_ident::Err>| err
+                                .at_index(#index_ident)
+                        };
+                        let read_item = quote! {
+                            let #item_ident = #call_expr
+                                #limit_map
+                                .map_err(#item_ctx)
+                                .map_err(#read_ctx)?;
+                        };
+                        let push_item = quote! {
+                            #collection::push(&mut #items_ident, #item_ident)
+                                .map_err(#barse_path::WrappedErr::from_err)
+                                .map_err(#item_ctx)
+                                .map_err(#read_ctx)?;
+                        };
+                        let count_ident = format_ident!("__count_{_r:x}");
+                        let first_ident = format_ident!("__first_{_r:x}");
+                        let (len_hint, range) = match repeat {
+                            Repeat::Count(count) => (
+                                quote! { let #count_ident = (#count) as usize; },
+                                quote! { 0..#count_ident },
+                            ),
+                            Repeat::Until(_) | Repeat::UntilEof => {
+                                (quote! { let #count_ident = 0usize; }, quote! { 0usize.. })
+                            }
+                        };
+                        let read_items = match repeat {
+                            Repeat::Count(_) => quote! {
+                                #read_item
+                                #push_item
+                            },
+                            Repeat::Until(until) => quote! {
+                                #read_item
+                                let last = (#until)(&#item_ident);
+                                #push_item
+                                if last {
+                                    break;
+                                }
+                            },
+                            Repeat::UntilEof => quote! {
+                                let #item_ident = match <#source_ty as #source_trait>::read_byte_or_eof(
+                                    #from_ident,
+                                )
+                                #await_suffix
+                                .map_err(#barse_path::WrappedErr::from)
+                                #limit_map
+                                .map_err(#read_ctx)?
+                                {
+                                    ::core::option::Option::Some(#first_ident) => {
+                                        let #first_ident = [#first_ident];
+                                        let #from_ident = &mut #barse_path::adapter::Prefixed::new(
+                                            &#first_ident,
+                                            &mut *#from_ident,
+                                        );
+                                        #call_expr
+                                    }
+                                    ::core::option::Option::None => break,
+                                }
+                                #limit_map
+                                .map_err(#item_ctx)
+                                .map_err(#read_ctx)?;
+                                #push_item
+                            },
+                        };
+                        quote! {
+                            let #name = {
+                                #len_hint
+                                let mut #items_ident = #collection::builder(#count_ident);
+                                for #index_ident in #range {
+                                    #read_items
+                                }
+                                #collection::finish(#items_ident)
+                                    .map_err(#barse_path::WrappedErr::from_err)
+                                    .map_err(#read_ctx)?
+                            };
+                        }
+                        .to_tokens(&mut field_read);
+                    } else {
+                        quote! { let #name = #call_expr #limit_map .map_err(#read_ctx)?; }
+                            .to_tokens(&mut field_read);
+                    }
+                }
+
+                if let Some((size, policy)) = &limit {
+                    field_read = quote! {
+                        let #name = {
+                            let #from_ident = &mut #barse_path::adapter::Limited::new(
+                                &mut *#from_ident,
+                                #size,
+                            );
+                            #field_read
+                            #barse_path::adapter::Limited::#finish_fn(
+                                #from_ident,
+                                #barse_path::adapter::Leftover::#policy,
+                            )
+                            #await_suffix
+                            .map_err(#barse_path::WrappedErr::from)
+                            #limit_map
+                            .map_err(#read_ctx)?;
+                            #name
+                        };
+                    };
+                }
+
+                let magic = cfg.magic.as_deref().map(|magic| {
+                    magic_bodies(
+                        magic,
+                        barse_trait,
+                        barse_path,
+                        byte_ident,
+                        (from_ident, &checked_source_ty),
+                        (to_ident, &checked_sink_ty),
+                        &quote! { .in_field(#field_name).in_type(#type_name) },
+                    )
+                });
+                if let Some(FieldBodies { read_body, .. }) = &magic {
+                    field_read = quote! { #read_body #field_read };
+                }
+
+                for FieldChecksum { state, .. } in covering.iter().rev() {
+                    field_read = quote! {
+                        let #name = {
+                            let #from_ident = &mut #barse_path::adapter::Checksummed::new(
+                                &mut *#from_ident,
+                                &mut #state,
+                            );
+                            #field_read
+                            #name
+                        };
+                    };
+                }
+
+                if let Some(state) = checksum_state {
                     quote! {
-                        <#ty as #barse_path::Barse>::write_with::<#e, #byte_ident>(
-                            #name,
-                            #to_ident,
-                            #write_with
-                        )?;
+                        let #state = #barse_path::adapter::Checksum::value(&#state);
+                        if #name != #state {
+                            return ::core::result::Result::Err(
+                                #barse_path::WrappedErr::from_err(
+                                    #barse_path::Error::Checksum(
+                                        #barse_path::error::ChecksumMismatch {
+                                            expected: ::core::convert::Into::into(#state),
+                                            actual: ::core::convert::Into::into(#name),
+                                        },
+                                    ),
+                                ),
+                            )
+                            .map_err(#checked_read_ctx);
+                        }
+                    }
+                    .to_tokens(&mut field_read);
+                }
+
+                // Errors of field read are returned after position is restored.
+                if let Some((read_pos, _)) = &offset {
+                    field_read = quote! {
+                        let #name = {
+                            let #pos_ident: u64 = #read_pos;
+                            let #back_ident = <#source_ty as #seek_source>::position(#from_ident)
+                                .map_err(#barse_path::WrappedErr::from)
+                                .map_err(#checked_read_ctx)?;
+                            <#source_ty as #guarded_source>::enter_ptr(#from_ident, #pos_ident)
+                                .map_err(#barse_path::WrappedErr::from_err)
+                                .map_err(#checked_read_ctx)?;
+                            let #name = <#source_ty as #seek_source>::seek_to(
+                                #from_ident,
+                                #pos_ident,
+                            )
+                            .map_err(#barse_path::WrappedErr::from)
+                            .map_err(#checked_read_ctx)
+                            .and_then(|()| {
+                                #field_read
+                                ::core::result::Result::Ok(#name)
+                            });
+                            let #restored_ident = <#source_ty as #seek_source>::seek_to(
+                                #from_ident,
+                                #back_ident,
+                            )
+                            .map_err(#barse_path::WrappedErr::from)
+                            .map_err(#checked_read_ctx);
+                            <#source_ty as #guarded_source>::leave_ptr(#from_ident, #pos_ident);
+                            let #name = #name?;
+                            #restored_ident?;
+                            #name
+                        };
+                    };
+                }
+
+                if let Some((read_cond, _)) = &field_if {
+                    field_read = quote! {
+                        let #name = if #read_cond {
+                            #field_read
+                            ::core::option::Option::Some(#name)
+                        } else {
+                            ::core::option::Option::None
+                        };
+                    };
+                }
+
+                if let Some(FieldBodies { read_body, .. }) = &assert {
+                    read_body.to_tokens(&mut field_read);
+                }
+
+                // Alignment padding precedes field, whether it is present or not.
+                if let Some(align) = align {
+                    quote! {
+                        #barse_path::adapter::Tracked::#align_fn(#from_ident, #align)
+                            #await_suffix
+                            .map_err(#barse_path::WrappedErr::from)
+                            .map_err(#checked_read_ctx)?;
+                    }
+                    .to_tokens(read_body);
+                }
+
+                field_read.to_tokens(read_body);
+
+                let mut field_write = TokenStream::default();
+                if let Some(state) = checksum_state {
+                    quote! {
+                        let #name = &#barse_path::adapter::Checksum::value(&#state);
+                    }
+                    .to_tokens(&mut field_write);
+                }
+
+                if cfg.bytes.is_some() || cfg.write_bytes.is_some() {
+                    // Field should be written as bytes.
+                    quote! {{
+                        let #name = <#ty as ::core::convert::AsRef<[u8]>>::as_ref(#name);
+                        <#sink_ty as #sink_trait>::write_slice(#to_ident, #name)
+                            #await_suffix
+                            .map_err(#barse_path::WrappedErr::from)
+                            #limit_map
+                            .map_err(#write_ctx)?;
+                    }}
+                    .to_tokens(&mut field_write);
+                } else {
+                    let item_map = repeat.map(|_| {
+                        quote! {
+                            .map_err(|err: #barse_path::WrappedErr<#byte_ident::Err>| {
+                                err.at_index(#index_ident)
+                            })
+                        }
+                    });
+                    let write_value =
+                        if let Some(using) = cfg.write_as.as_deref().or(cfg.barse_as.as_deref()) {
+                            quote! {
+                                #barse_path::WriteAs::<#value_ty, _>::write_with::<#e, #sink_ty>(
+                                    { #using },
+                                    #value,
+                                    #to_ident,
+                                    #write_with
+                                )
+                                #limit_map
+                                #item_map
+                                .map_err(#write_ctx)?;
+                            }
+                        } else {
+                            quote! {
+                                <#value_ty as #trait_path>::#write_fn::<#e, #sink_ty>(
+                                    #value,
+                                    #to_ident,
+                                    #write_with
+                                )
+                                #await_suffix
+                                #limit_map
+                                #item_map
+                                .map_err(#write_ctx)?;
+                            }
+                        };
+                    if repeat.is_some() {
+                        quote! {
+                            for (#index_ident, #item_ident) in ::core::iter::Iterator::enumerate(
+                                ::core::iter::IntoIterator::into_iter(#name),
+                            ) {
+                                #write_value
+                            }
+                        }
+                        .to_tokens(&mut field_write);
+                    } else {
+                        write_value.to_tokens(&mut field_write);
                     }
                 }
+
+                if let Some((size, policy)) = &limit {
+                    field_write = quote! {{
+                        let #to_ident = &mut #barse_path::adapter::LimitedSink::new(
+                            &mut *#to_ident,
+                            #size,
+                        );
+                        #field_write
+                        #barse_path::adapter::LimitedSink::#finish_fn(
+                            #to_ident,
+                            #barse_path::adapter::Leftover::#policy,
+                        )
+                        #await_suffix
+                        .map_err(#barse_path::WrappedErr::from)
+                        #limit_map
+                        .map_err(#write_ctx)?;
+                    }};
+                }
+
+                if let Some(FieldBodies { write_body, .. }) = &magic {
+                    field_write = quote! { #write_body #field_write };
+                }
+
+                for FieldChecksum { state, .. } in covering.iter().rev() {
+                    field_write = quote! {{
+                        let #to_ident = &mut #barse_path::adapter::ChecksummedSink::new(
+                            &mut *#to_ident,
+                            &mut #state,
+                        );
+                        #field_write
+                    }};
+                }
+
+                if let Some((_, pos)) = &offset {
+                    field_write = quote! {{
+                        let #pos_ident: u64 = #pos;
+                        let #back_ident = <#sink_ty as #seek_sink>::position(#to_ident)
+                            .map_err(#barse_path::WrappedErr::from)
+                            .map_err(#checked_write_ctx)?;
+                        let #name = <#sink_ty as #seek_sink>::seek_to(#to_ident, #pos_ident)
+                            .map_err(#barse_path::WrappedErr::from)
+                            .map_err(#checked_write_ctx)
+                            .and_then(|()| {
+                                #field_write
+                                ::core::result::Result::Ok(())
+                            });
+                        let #restored_ident = <#sink_ty as #seek_sink>::seek_to(
+                            #to_ident,
+                            #back_ident,
+                        )
+                        .map_err(#barse_path::WrappedErr::from)
+                        .map_err(#checked_write_ctx);
+                        #name?;
+                        #restored_ident?;
+                    }};
+                }
+
+                if let Some((_, cond)) = &field_if {
+                    field_write = quote! {
+                        match (#cond, #name) {
+                            (true, ::core::option::Option::Some(#name)) => { #field_write }
+                            (false, ::core::option::Option::None) => {}
+                            _ => {
+                                return ::core::result::Result::Err(
+                                    #barse_path::WrappedErr::from_err(
+                                        #barse_path::Error::Assertion(
+                                            #barse_path::error::AssertionFailed {
+                                                message: "presence of optional field did not \
+                                                    match its condition",
+                                            },
+                                        ),
+                                    ),
+                                )
+                                .map_err(#checked_write_ctx);
+                            }
+                        }
+                    };
+                }
+
+                if let Some(align) = align {
+                    field_write = quote! {
+                        #barse_path::adapter::TrackedSink::#align_fn(#to_ident, #align)
+                            #await_suffix
+                            .map_err(#barse_path::WrappedErr::from)
+                            .map_err(#checked_write_ctx)?;
+                        #field_write
+                    };
+                }
+
+                if let Some(FieldBodies { write_body, .. }) = &assert {
+                    field_write = quote! { #write_body #field_write };
+                }
+
+                calc.to_tokens(write_body);
+                field_write.to_tokens(write_body);
+            }
+        }
+
+        // Record is padded after all fields.
+        if let Some(multiple) = pad_to_multiple {
+            for (
+                barse_trait,
+                FieldBodies {
+                    read_body,
+                    write_body,
+                },
+            ) in barse_traits.iter().zip(bodies.iter_mut())
+            {
+                let source_trait = barse_trait.source_trait(barse_path);
+                let sink_trait = barse_trait.sink_trait(barse_path);
+                let await_suffix = barse_trait.await_suffix();
+                let align_fn = barse_trait.align_fn();
+                quote! {
+                    #barse_path::adapter::Tracked::#align_fn(#from_ident, #multiple)
+                        #await_suffix
+                        .map_err(#barse_path::WrappedErr::from)
+                        .map_err(|err: #barse_path::WrappedErr<#byte_ident::Err>| err
+                            .at_offset(<#source_ty as #source_trait>::offset(#from_ident))
+                            .in_type(#type_name))?;
+                }
+                .to_tokens(read_body);
+                quote! {
+                    #barse_path::adapter::TrackedSink::#align_fn(#to_ident, #multiple)
+                        #await_suffix
+                        .map_err(#barse_path::WrappedErr::from)
+                        .map_err(|err: #barse_path::WrappedErr<#byte_ident::Err>| err
+                            .at_offset(<#sink_ty as #sink_trait>::offset(#to_ident))
+                            .in_type(#type_name))?;
+                }
                 .to_tokens(write_body);
             }
+            quote! {
+                let #len_ident = usize::saturating_add(
+                    #len_ident,
+                    #barse_path::adapter::padding_to(#len_ident, #multiple),
+                );
+            }
+            .to_tokens(len_body);
+            *fixed_size = None;
+        }
+
+        // Calculated fields are verified after all fields are read, as they may depend on any.
+        for ((name, field_name), (field, cfg)) in names.iter().zip(fields.iter().zip(&cfgs)) {
+            let Some(FieldConfig {
+                calc: Some(calc),
+                verify: Some(verify),
+                ..
+            }) = cfg
+            else {
+                continue;
+            };
+            let ty = &field.ty;
+            let calc = self_to_names(calc.expr.to_token_stream(), &names);
+            let asserts = opt::Asserts(vec![opt::Assert {
+                kw: kw::assert(verify.kw.span),
+                args: opt::AssertArgs {
+                    condition: ::syn::parse_quote! {
+                        <#ty as ::core::cmp::PartialEq>::eq(#name, &(#calc))
+                    },
+                    message: ::syn::LitStr::new(
+                        "calculated field did not match value read",
+                        verify.kw.span,
+                    ),
+                    write: None,
+                },
+            }]);
+            for (barse_trait, FieldBodies { read_body, .. }) in
+                barse_traits.iter().zip(bodies.iter_mut())
+            {
+                assert_bodies(
+                    (&asserts, &names),
+                    barse_trait,
+                    barse_path,
+                    byte_ident,
+                    (from_ident, &source_ty),
+                    (to_ident, &sink_ty),
+                    &quote! { .in_field(#field_name).in_type(#type_name) },
+                )
+                .read_body
+                .to_tokens(read_body);
+            }
+        }
+
+        // Assertions of type are checked after all fields are read, and before any are written.
+        if let Some(asserts) = asserts {
+            for (barse_trait, FieldBodies { read_body, .. }) in
+                barse_traits.iter().zip(bodies.iter_mut())
+            {
+                assert_bodies(
+                    (asserts, &names),
+                    barse_trait,
+                    barse_path,
+                    byte_ident,
+                    (from_ident, &source_ty),
+                    (to_ident, &sink_ty),
+                    &quote! { .in_type(#type_name) },
+                )
+                .read_body
+                .to_tokens(read_body);
+            }
         }
         f
     }
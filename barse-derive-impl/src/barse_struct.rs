@@ -1,14 +1,15 @@
 //! Implementation of derive for structs.
 
 use ::proc_macro2::TokenStream;
-use ::quote::{format_ident, quote};
+use ::quote::{format_ident, quote, ToTokens};
 use ::syn::{
     parse::Parser as _, parse_quote, punctuated::Punctuated, GenericParam, Generics, ItemStruct,
     Token, WhereClause,
 };
 
 use crate::{
-    barse_field::{FieldDeps, ProcessedFields},
+    barse_field::{FieldBodies, FieldDeps, ProcessedFields},
+    barse_trait::BarseTrait,
     impl_idents::ImplIdents,
     opt, path_expr,
     result_aggregate::ResAggr,
@@ -38,6 +39,33 @@ opt::opt_parser! {
 
         /// Set a fixed endian in use by struct (fields may overwrite to another fixed endian).
         endian: opt::Endian,
+
+        /// Lifetime borrowed from source.
+        borrow: opt::Borrow,
+
+        /// Also implement AsyncBarse.
+        async_impl: opt::Async,
+
+        /// Implement BarseSeek instead of Barse.
+        seek: opt::Seek,
+
+        /// Implement FixedBarse, with size summed from fields.
+        fixed: opt::Fixed,
+
+        /// Order of bits in bit fields.
+        bit_order: opt::BitOrder,
+
+        /// Magic bytes preceding fields.
+        magic: opt::Magic,
+
+        /// Assertions checked after all fields are read.
+        assert: opt::Asserts,
+
+        /// Struct is padded to a multiple of given size.
+        pad_to_multiple: opt::PadToMultiple,
+
+        /// What alignment and padding is relative to.
+        align_base: opt::AlignBase,
     },
 }
 
@@ -48,7 +76,7 @@ opt::opt_parser! {
 ///
 /// # Panics
 /// On bad implementation.
-pub fn derive_barse_struct(mut item: ItemStruct) -> Result<TokenStream, ::syn::Error> {
+pub fn derive_barse_struct(item: ItemStruct) -> Result<TokenStream, ::syn::Error> {
     let StructConfig {
         where_clause,
         barse_path,
@@ -57,6 +85,15 @@ pub fn derive_barse_struct(mut item: ItemStruct) -> Result<TokenStream, ::syn::E
         write_with,
         field_prefix,
         endian,
+        borrow,
+        async_impl,
+        seek,
+        fixed,
+        bit_order,
+        magic,
+        assert,
+        pad_to_multiple,
+        align_base,
     } = StructConfig::default().parse_attrs(&item.attrs)?;
 
     let mut aggr = ResAggr::<()>::new();
@@ -81,6 +118,14 @@ pub fn derive_barse_struct(mut item: ItemStruct) -> Result<TokenStream, ::syn::E
         |p| p.path,
     );
 
+    let barse_traits = BarseTrait::with_async(
+        &item.generics,
+        &item.fields,
+        borrow,
+        (async_impl, seek),
+        &mut aggr,
+    )?;
+
     let impl_idents @ ImplIdents {
         _r,
         endian_ident,
@@ -89,6 +134,7 @@ pub fn derive_barse_struct(mut item: ItemStruct) -> Result<TokenStream, ::syn::E
         to_ident,
         from_ident,
         discriminant_ident: _,
+        len_ident,
     } = &ImplIdents::new();
 
     let default_with = with.map_or_else(|| impl_idents.default_with(), |w| w.with_pat);
@@ -103,8 +149,9 @@ pub fn derive_barse_struct(mut item: ItemStruct) -> Result<TokenStream, ::syn::E
 
     let ProcessedFields {
         name_expansion,
-        read_body,
-        write_body,
+        bodies,
+        len_body,
+        fixed_size,
     } = ProcessedFields::new(
         &item.fields,
         FieldDeps {
@@ -113,7 +160,17 @@ pub fn derive_barse_struct(mut item: ItemStruct) -> Result<TokenStream, ::syn::E
             read_with_expr: &read_with_expr,
             write_with_expr: &write_with_expr,
             endian: endian.as_deref(),
+            bit_order: bit_order.as_deref(),
+            magic: magic.as_deref(),
+            asserts: assert.as_ref(),
             impl_idents,
+            barse_traits: &barse_traits,
+            source_ty: None,
+            position: None,
+            discriminant_field: false,
+            pad_to_multiple: pad_to_multiple.as_ref().map(|pad| &pad.multiple),
+            align_base: align_base.as_deref(),
+            type_name: &name.to_string(),
         },
         &mut aggr,
     );
@@ -134,37 +191,27 @@ pub fn derive_barse_struct(mut item: ItemStruct) -> Result<TokenStream, ::syn::E
         }
     };
 
-    if where_clause.is_none() {
-        let Generics {
-            params,
-            where_clause,
-            ..
-        } = &mut item.generics;
-        for param in params {
-            let GenericParam::Type(param) = param else {
-                continue;
-            };
-            let where_clause = where_clause.get_or_insert_with(|| WhereClause {
-                where_token: Default::default(),
-                predicates: Punctuated::new(),
-            });
-
-            let ident = &param.ident;
-
-            if !where_clause.predicates.empty_or_trailing() {
-                where_clause.predicates.push_punct(<Token![,]>::default());
+    // FixedBarse is only implemented when requested, fields then need to implement it.
+    let fixed_size = match (fixed_size, &fixed) {
+        (_, None) => None,
+        _ if !item.generics.params.is_empty() || !matches!(barse_traits[0], BarseTrait::Owned) => {
+            if let Some(fixed) = &fixed {
+                aggr.push_err(::syn::Error::new(
+                    fixed.kw.span,
+                    "'fixed' is not supported for generic or borrowing types",
+                ));
             }
-
-            where_clause
-                .predicates
-                .push(parse_quote!(#ident: #barse_path::Barse));
+            None
         }
-    }
-
-    let (impl_generics, ty_generics, split_where_clause) = item.generics.split_for_impl();
-    let where_clause = where_clause
-        .as_ref()
-        .map_or_else(|| Either::A(split_where_clause), Either::B);
+        (Some(fixed_size), Some(_)) => Some(fixed_size),
+        (None, Some(fixed)) => {
+            aggr.push_err(::syn::Error::new(
+                fixed.kw.span,
+                "'fixed' requires all fields to be read and written as fixed size types",
+            ));
+            None
+        }
+    };
 
     let read_with_ty = &read_with.ty;
     let write_with_ty = &write_with.ty;
@@ -172,37 +219,130 @@ pub fn derive_barse_struct(mut item: ItemStruct) -> Result<TokenStream, ::syn::E
     // Error if any minor errors encountered.
     aggr.into_inner()?;
 
-    Ok(quote! {
-        #[automatically_derived]
-        impl #impl_generics #barse_path::Barse for #name #ty_generics #where_clause {
-            type ReadWith = #read_with_ty;
-            type WriteWith = #write_with_ty;
-
-            fn read_with<#endian_ident, #byte_ident>(
-                #from_ident: &mut #byte_ident,
-                #read_with_pat: #read_with_ty
-            ) -> ::core::result::Result<Self, #barse_path::WrappedErr::<#byte_ident::Err>>
-            where
-                #endian_ident: #barse_path::Endian,
-                #byte_ident: #barse_path::ByteSource,
-            {
-                #read_body
-                #read_return
+    let mut impls = TokenStream::default();
+    if let Some(size) = fixed_size {
+        quote! {
+            #[automatically_derived]
+            impl #barse_path::FixedBarse for #name {
+                const SIZE: usize = #size;
+                type Bytes = [u8; #size];
             }
+        }
+        .to_tokens(&mut impls);
+    }
 
-            fn write_with<#endian_ident, #byte_ident>(
-                &self,
-                #to_ident: &mut #byte_ident,
-                #write_with_pat: #write_with_ty
-            ) -> ::core::result::Result<(), #barse_path::WrappedErr::<#byte_ident::Err>>
-            where
-                #endian_ident: #barse_path::Endian,
-                #byte_ident: #barse_path::ByteSink,
-            {
-                #write_prefix
-                #write_body
-                Ok(())
+    for (
+        barse_trait,
+        FieldBodies {
+            read_body,
+            write_body,
+        },
+    ) in barse_traits.iter().zip(bodies)
+    {
+        let trait_path = barse_trait.path(&barse_path);
+        let mut generics = item.generics.clone();
+
+        if where_clause.is_none() {
+            let Generics {
+                params,
+                where_clause,
+                ..
+            } = &mut generics;
+            for param in params {
+                let GenericParam::Type(param) = param else {
+                    continue;
+                };
+                let where_clause = where_clause.get_or_insert_with(|| WhereClause {
+                    where_token: Default::default(),
+                    predicates: Punctuated::new(),
+                });
+
+                let ident = &param.ident;
+
+                if !where_clause.predicates.empty_or_trailing() {
+                    where_clause.predicates.push_punct(<Token![,]>::default());
+                }
+
+                where_clause
+                    .predicates
+                    .push(parse_quote!(#ident: #trait_path));
+            }
+        }
+
+        let (impl_generics, ty_generics, split_where_clause) = generics.split_for_impl();
+        let where_clause = where_clause
+            .as_ref()
+            .map_or_else(|| Either::A(split_where_clause), Either::B);
+
+        let with_types = barse_trait.with_types(read_with_ty, write_with_ty);
+        let source_bound = barse_trait.source_bound(&barse_path);
+        let sink_bound = barse_trait.sink_bound(&barse_path);
+        let read_fn = barse_trait.read_fn();
+        let write_fn = barse_trait.write_fn();
+        let asyncness = barse_trait.asyncness();
+
+        // Only Barse has encoded_len.
+        let encoded_len = matches!(barse_trait, BarseTrait::Owned).then(|| {
+            quote! {
+                fn encoded_len(
+                    &self,
+                    #write_with_pat: #write_with_ty
+                ) -> ::core::result::Result<
+                    usize,
+                    #barse_path::WrappedErr::<::core::convert::Infallible>
+                > {
+                    #write_prefix
+                    let #len_ident = 0usize;
+                    #len_body
+                    Ok(#len_ident)
+                }
+            }
+        });
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #trait_path for #name #ty_generics #where_clause {
+                #with_types
+
+                #asyncness fn #read_fn<#endian_ident, #byte_ident>(
+                    #from_ident: &mut #byte_ident,
+                    #read_with_pat: #read_with_ty
+                ) -> ::core::result::Result<Self, #barse_path::WrappedErr::<#byte_ident::Err>>
+                where
+                    #endian_ident: #barse_path::Endian,
+                    #byte_ident: #source_bound,
+                {
+                    #read_body
+                    #read_return
+                }
+
+                #asyncness fn #write_fn<#endian_ident, #byte_ident>(
+                    &self,
+                    #to_ident: &mut #byte_ident,
+                    #write_with_pat: #write_with_ty
+                ) -> ::core::result::Result<(), #barse_path::WrappedErr::<#byte_ident::Err>>
+                where
+                    #endian_ident: #barse_path::Endian,
+                    #byte_ident: #sink_bound,
+                {
+                    #write_prefix
+                    #write_body
+                    Ok(())
+                }
+
+                #encoded_len
             }
         }
-    })
+        .to_tokens(&mut impls);
+
+        barse_trait
+            .seek_forward(
+                &barse_path,
+                impl_idents,
+                (&impl_generics, quote! { #name #ty_generics }, &where_clause),
+            )
+            .to_tokens(&mut impls);
+    }
+
+    Ok(impls)
 }
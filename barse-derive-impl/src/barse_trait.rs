@@ -0,0 +1,328 @@
+//! [BarseTrait] impl.
+
+use ::proc_macro2::TokenStream;
+use ::quote::{format_ident, quote, ToTokens};
+use ::syn::Generics;
+
+use crate::{impl_idents::ImplIdents, opt, result_aggregate::ResAggr};
+
+/// Check if a type is a shared reference to bytes or text with given lifetime.
+fn borrows_bytes(ty: &::syn::Type, lifetime: &::syn::Lifetime) -> bool {
+    /// Check if a type is u8.
+    fn is_u8(ty: &::syn::Type) -> bool {
+        matches!(ty, ::syn::Type::Path(ty) if ty.qself.is_none() && ty.path.is_ident("u8"))
+    }
+
+    match ty {
+        ::syn::Type::Reference(ty)
+            if ty.mutability.is_none() && ty.lifetime.as_ref() == Some(lifetime) =>
+        {
+            match &*ty.elem {
+                ::syn::Type::Path(elem) => elem.qself.is_none() && elem.path.is_ident("str"),
+                ::syn::Type::Slice(elem) => is_u8(&elem.elem),
+                ::syn::Type::Array(elem) => is_u8(&elem.elem),
+                _ => false,
+            }
+        }
+        ::syn::Type::Paren(ty) => borrows_bytes(&ty.elem, lifetime),
+        ::syn::Type::Group(ty) => borrows_bytes(&ty.elem, lifetime),
+        _ => false,
+    }
+}
+
+/// Trait implemented by derive, decided by attributes of type.
+#[derive(Debug, Clone)]
+pub enum BarseTrait {
+    /// Type does not borrow, [Barse] is implemented.
+    Owned,
+
+    /// Type borrows from source with given lifetime, [BarseBorrow] is implemented.
+    Borrowed(::syn::Lifetime),
+
+    /// [AsyncBarse] is implemented, alongside [Barse].
+    Async,
+
+    /// Type is read from seekable sources, [BarseSeek] is implemented.
+    Seek,
+}
+
+impl BarseTrait {
+    /// Decide trait to implement from generics, fields and borrow option.
+    ///
+    /// Types borrow from source if a lifetime is given using `#[barse(borrow = 'lifetime)]`, or
+    /// if they have a single lifetime and a field borrows bytes or text for it, such as
+    /// `&'a [u8]`, `&'a [u8; N]` or `&'a str`. Lifetimes of type are otherwise treated like any
+    /// other generic.
+    ///
+    /// # Errors
+    /// If the lifetime to borrow for is not a lifetime of type.
+    pub fn new<'f>(
+        generics: &Generics,
+        fields: impl IntoIterator<Item = &'f ::syn::Field>,
+        borrow: Option<opt::Borrow>,
+    ) -> Result<Self, ::syn::Error> {
+        let Some(borrow) = borrow else {
+            let mut lifetimes = generics.lifetimes();
+            return Ok(match (lifetimes.next(), lifetimes.next()) {
+                (Some(param), None)
+                    if fields
+                        .into_iter()
+                        .any(|field| borrows_bytes(&field.ty, &param.lifetime)) =>
+                {
+                    Self::Borrowed(param.lifetime.clone())
+                }
+                _ => Self::Owned,
+            });
+        };
+
+        if generics
+            .lifetimes()
+            .any(|param| param.lifetime == borrow.lifetime)
+        {
+            Ok(Self::Borrowed(borrow.lifetime))
+        } else {
+            Err(::syn::Error::new(
+                borrow.lifetime.span(),
+                "borrowed lifetime should be a lifetime parameter of type",
+            ))
+        }
+    }
+
+    /// Decide traits to implement, the decided trait, or [BarseSeek] if requested, followed by
+    /// [AsyncBarse] if requested.
+    ///
+    /// # Errors
+    /// If the lifetime to borrow for is not a lifetime of type.
+    pub fn with_async<'f>(
+        generics: &Generics,
+        fields: impl IntoIterator<Item = &'f ::syn::Field>,
+        borrow: Option<opt::Borrow>,
+        (async_impl, seek): (Option<opt::Async>, Option<opt::Seek>),
+        aggr: &mut ResAggr<()>,
+    ) -> Result<Vec<Self>, ::syn::Error> {
+        if cfg!(not(feature = "async")) {
+            aggr.requires_feature("async", &async_impl);
+        }
+
+        let mut traits = vec![Self::new(generics, fields, borrow)?];
+        if let Some(seek) = &seek {
+            if let Self::Borrowed(_) = traits[0] {
+                aggr.push_err(::syn::Error::new(
+                    seek.kw.span,
+                    "'seek' is not supported for types borrowing from source",
+                ));
+            } else {
+                traits[0] = Self::Seek;
+            }
+        }
+        if let Some(async_impl) = async_impl {
+            if let Self::Borrowed(_) = traits[0] {
+                aggr.push_err(::syn::Error::new(
+                    async_impl.kw.span,
+                    "'async' is not supported for types borrowing from source",
+                ));
+            } else if seek.is_some() {
+                aggr.push_err(::syn::Error::new(
+                    async_impl.kw.span,
+                    "'async' is not supported together with 'seek'",
+                ));
+            } else {
+                traits.push(Self::Async);
+            }
+        }
+        Ok(traits)
+    }
+
+    /// Get path to trait.
+    pub fn path(&self, barse_path: &::syn::Path) -> TokenStream {
+        match self {
+            BarseTrait::Owned => quote! { #barse_path::Barse },
+            BarseTrait::Borrowed(lifetime) => quote! { #barse_path::BarseBorrow<#lifetime> },
+            BarseTrait::Async => quote! { #barse_path::AsyncBarse },
+            BarseTrait::Seek => quote! { #barse_path::BarseSeek },
+        }
+    }
+
+    /// Get bound for source generic param.
+    pub fn source_bound(&self, barse_path: &::syn::Path) -> TokenStream {
+        match self {
+            BarseTrait::Owned => quote! { #barse_path::ByteSource },
+            BarseTrait::Borrowed(lifetime) => quote! { #barse_path::BorrowSource<#lifetime> },
+            BarseTrait::Async => quote! { #barse_path::AsyncByteSource },
+            BarseTrait::Seek => quote! { #barse_path::GuardedSource },
+        }
+    }
+
+    /// Get trait providing source methods.
+    pub fn source_trait(&self, barse_path: &::syn::Path) -> TokenStream {
+        match self {
+            BarseTrait::Owned | BarseTrait::Borrowed(_) | BarseTrait::Seek => {
+                quote! { #barse_path::ByteSource }
+            }
+            BarseTrait::Async => quote! { #barse_path::AsyncByteSource },
+        }
+    }
+
+    /// Get trait providing sink methods.
+    pub fn sink_trait(&self, barse_path: &::syn::Path) -> TokenStream {
+        match self {
+            BarseTrait::Owned | BarseTrait::Borrowed(_) | BarseTrait::Seek => {
+                quote! { #barse_path::ByteSink }
+            }
+            BarseTrait::Async => quote! { #barse_path::AsyncByteSink },
+        }
+    }
+
+    /// Get bound for sink generic param.
+    pub fn sink_bound(&self, barse_path: &::syn::Path) -> TokenStream {
+        match self {
+            BarseTrait::Seek => quote! { #barse_path::SeekSink },
+            BarseTrait::Owned | BarseTrait::Borrowed(_) | BarseTrait::Async => {
+                self.sink_trait(barse_path)
+            }
+        }
+    }
+
+    /// Get trait used to read and write discriminants.
+    pub const fn discriminant_trait(&self) -> BarseTrait {
+        match self {
+            BarseTrait::Owned | BarseTrait::Borrowed(_) | BarseTrait::Seek => BarseTrait::Owned,
+            BarseTrait::Async => BarseTrait::Async,
+        }
+    }
+
+    /// Get trait used for fields read from/written to adapters which cannot seek.
+    pub fn unseekable(&self) -> BarseTrait {
+        match self {
+            BarseTrait::Seek => BarseTrait::Owned,
+            BarseTrait::Owned | BarseTrait::Borrowed(_) | BarseTrait::Async => self.clone(),
+        }
+    }
+
+    /// Get impl of [BarseSeek] forwarding to [Barse] for owned types, such that they may be used
+    /// as fields of seekable types, given generics, type and where clause of the [Barse] impl.
+    pub fn seek_forward(
+        &self,
+        barse_path: &::syn::Path,
+        idents: &ImplIdents,
+        (impl_generics, self_ty, where_clause): (impl ToTokens, impl ToTokens, impl ToTokens),
+    ) -> Option<TokenStream> {
+        let BarseTrait::Owned = self else {
+            return None;
+        };
+        let ImplIdents {
+            endian_ident,
+            byte_ident,
+            with_ident,
+            to_ident,
+            from_ident,
+            ..
+        } = idents;
+        Some(quote! {
+            #[automatically_derived]
+            impl #impl_generics #barse_path::BarseSeek for #self_ty #where_clause {
+                type ReadWith = <Self as #barse_path::Barse>::ReadWith;
+                type WriteWith = <Self as #barse_path::Barse>::WriteWith;
+
+                #[inline]
+                fn read_seek<#endian_ident, #byte_ident>(
+                    #from_ident: &mut #byte_ident,
+                    #with_ident: Self::ReadWith,
+                ) -> ::core::result::Result<Self, #barse_path::WrappedErr::<#byte_ident::Err>>
+                where
+                    #endian_ident: #barse_path::Endian,
+                    #byte_ident: #barse_path::GuardedSource,
+                {
+                    <Self as #barse_path::Barse>::read_with::<#endian_ident, #byte_ident>(
+                        #from_ident,
+                        #with_ident,
+                    )
+                }
+
+                #[inline]
+                fn write_seek<#endian_ident, #byte_ident>(
+                    &self,
+                    #to_ident: &mut #byte_ident,
+                    #with_ident: Self::WriteWith,
+                ) -> ::core::result::Result<(), #barse_path::WrappedErr::<#byte_ident::Err>>
+                where
+                    #endian_ident: #barse_path::Endian,
+                    #byte_ident: #barse_path::SeekSink,
+                {
+                    <Self as #barse_path::Barse>::write_with::<#endian_ident, #byte_ident>(
+                        self,
+                        #to_ident,
+                        #with_ident,
+                    )
+                }
+            }
+        })
+    }
+
+    /// Get asyncness of generated functions.
+    pub fn asyncness(&self) -> Option<::syn::token::Async> {
+        match self {
+            BarseTrait::Owned | BarseTrait::Borrowed(_) | BarseTrait::Seek => None,
+            BarseTrait::Async => Some(Default::default()),
+        }
+    }
+
+    /// Get '.await' if calls need to be awaited.
+    pub fn await_suffix(&self) -> Option<TokenStream> {
+        match self {
+            BarseTrait::Owned | BarseTrait::Borrowed(_) | BarseTrait::Seek => None,
+            BarseTrait::Async => Some(quote! { .await }),
+        }
+    }
+
+    /// Get associated type declarations of trait, [AsyncBarse] uses those of [Barse].
+    pub fn with_types(&self, read_with: &::syn::Type, write_with: &::syn::Type) -> TokenStream {
+        match self {
+            BarseTrait::Owned | BarseTrait::Borrowed(_) | BarseTrait::Seek => quote! {
+                type ReadWith = #read_with;
+                type WriteWith = #write_with;
+            },
+            BarseTrait::Async => TokenStream::default(),
+        }
+    }
+
+    /// Get name of read function.
+    pub fn read_fn(&self) -> ::syn::Ident {
+        match self {
+            BarseTrait::Owned => format_ident!("read_with"),
+            BarseTrait::Borrowed(_) => format_ident!("read_borrow"),
+            BarseTrait::Async => format_ident!("read_with_async"),
+            BarseTrait::Seek => format_ident!("read_seek"),
+        }
+    }
+
+    /// Get name of write function.
+    pub fn write_fn(&self) -> ::syn::Ident {
+        match self {
+            BarseTrait::Owned => format_ident!("write_with"),
+            BarseTrait::Borrowed(_) => format_ident!("write_borrow"),
+            BarseTrait::Async => format_ident!("write_with_async"),
+            BarseTrait::Seek => format_ident!("write_seek"),
+        }
+    }
+
+    /// Get name of function used to finish limited sources and sinks.
+    pub fn finish_fn(&self) -> ::syn::Ident {
+        match self {
+            BarseTrait::Owned | BarseTrait::Borrowed(_) | BarseTrait::Seek => {
+                format_ident!("finish")
+            }
+            BarseTrait::Async => format_ident!("finish_async"),
+        }
+    }
+
+    /// Get name of function used to align tracked sources and sinks.
+    pub fn align_fn(&self) -> ::syn::Ident {
+        match self {
+            BarseTrait::Owned | BarseTrait::Borrowed(_) | BarseTrait::Seek => {
+                format_ident!("align")
+            }
+            BarseTrait::Async => format_ident!("align_async"),
+        }
+    }
+}
@@ -31,6 +31,9 @@ pub struct ImplIdents {
 
     /// Ident of discriminant.
     pub discriminant_ident: ::syn::Ident,
+
+    /// Ident of encoded length.
+    pub len_ident: ::syn::Ident,
 }
 
 impl ImplIdents {
@@ -46,6 +49,7 @@ impl ImplIdents {
             to_ident: format_ident!("__to_{r:x}"),
             from_ident: format_ident!("__from_{r:x}"),
             discriminant_ident: format_ident!("__dsicriminant_{r:x}"),
+            len_ident: format_ident!("__len_{r:x}"),
         }
     }
 
@@ -29,6 +29,31 @@ mod kw {
     custom_keyword!(write_as);
     custom_keyword!(discriminant);
     custom_keyword!(err_mod);
+    custom_keyword!(borrow);
+    custom_keyword!(count);
+    custom_keyword!(peek);
+    custom_keyword!(size);
+    custom_keyword!(leftover);
+    custom_keyword!(checksum);
+    custom_keyword!(over);
+    custom_keyword!(fixed);
+    custom_keyword!(bits);
+    custom_keyword!(bit_order);
+    custom_keyword!(magic);
+    custom_keyword!(assert);
+    custom_keyword!(write);
+    custom_keyword!(calc);
+    custom_keyword!(verify);
+    custom_keyword!(until);
+    custom_keyword!(until_eof);
+    custom_keyword!(align);
+    custom_keyword!(pad_to_multiple);
+    custom_keyword!(align_base);
+    custom_keyword!(seek);
+    custom_keyword!(offset);
+    custom_keyword!(catch_all);
+    custom_keyword!(discriminant_as);
+    custom_keyword!(discriminant_with);
 }
 
 mod barse_enum;
@@ -41,6 +66,8 @@ mod result_aggregate;
 
 mod barse_field;
 
+mod barse_trait;
+
 mod impl_idents;
 
 /// Derive barse for a struct or enum.
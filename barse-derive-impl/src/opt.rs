@@ -182,6 +182,45 @@ opt! {
         ty: ::syn::Type,
     },
 
+    /// Discriminant is read/written using a specific impl.
+    DiscriminantAs {
+        /// Opt keyword.
+        kw: kw::discriminant_as,
+
+        /// '=' token.
+        eq_token: Token![=],
+
+        /// Impl expression.
+        as_expr: ::syn::Expr,
+    },
+
+    /// Discriminant is read/written using given with value instead of '()'.
+    DiscriminantWith {
+        /// Opt keyword.
+        kw: kw::discriminant_with,
+
+        /// '=' token.
+        eq_token: Token![=],
+
+        /// With expression.
+        expr: ::syn::Expr,
+    },
+
+    /// Enum variant is selected by peeked bytes.
+    ///
+    /// Bytes are peeked from sources implementing PeekSource, and are otherwise read and replayed
+    /// to the variant, such that any source may be used.
+    EnumPeek {
+        /// Opt keyword.
+        kw: kw::peek,
+
+        /// '=' token.
+        eq_token: Token![=],
+
+        /// Amount of bytes peeked.
+        count: ::syn::Expr,
+    },
+
     /// Variant discriminant override.
     VariantDiscriminant {
         /// Opt keyword.
@@ -216,6 +255,277 @@ opt! {
         path: ::syn::Path,
     },
 
+    /// Lifetime of type borrowed from source, inferred for types with a single lifetime and
+    /// fields such as `&'a [u8]`.
+    Borrow {
+        /// Opt keyword.
+        kw: kw::borrow,
+
+        /// '=' token.
+        eq_token: Token![=],
+
+        /// Borrowed lifetime.
+        lifetime: ::syn::Lifetime,
+    },
+
+    /// Also implement async reads and writes.
+    Async {
+        /// Opt keyword.
+        kw: token::Async,
+    },
+
+    /// Implement BarseSeek instead of Barse, allowing fields to be read at offsets.
+    ///
+    /// Fields need to implement BarseSeek, which types not implementing seek forward to Barse.
+    Seek {
+        /// Opt keyword.
+        kw: kw::seek,
+    },
+
+    /// Implement FixedBarse, requiring fields to implement it.
+    Fixed {
+        /// Opt keyword.
+        kw: kw::fixed,
+    },
+
+    /// Amount of items/bytes of field.
+    Count {
+        /// Opt keyword.
+        kw: kw::count,
+
+        /// '=' token.
+        eq_token: Token![=],
+
+        /// Count expression.
+        count: ::syn::Expr,
+    },
+
+    /// Collection is read until an item matches.
+    Until {
+        /// Opt keyword.
+        kw: kw::until,
+
+        /// '=' token.
+        eq_token: Token![=],
+
+        /// Closure taking a reference to each item, returning true for the last item.
+        until: ::syn::Expr,
+    },
+
+    /// Collection is read until source is exhausted.
+    UntilEof {
+        /// Opt keyword.
+        kw: kw::until_eof,
+    },
+
+    /// Amount of bytes a field may use.
+    Size {
+        /// Opt keyword.
+        kw: kw::size,
+
+        /// '=' token.
+        eq_token: Token![=],
+
+        /// Size expression.
+        size: ::syn::Expr,
+    },
+
+    /// What to do with bytes left within size of field.
+    Leftover {
+        /// Opt keyword.
+        kw: kw::leftover,
+
+        /// '=' token.
+        eq_token: Token![=],
+
+        /// Policy, one of 'error', 'skip' or 'keep'.
+        policy: ::syn::Ident,
+    },
+
+    /// Checksum of other fields.
+    Checksum {
+        /// Opt keyword.
+        kw: kw::checksum,
+
+        /// Checksum arguments.
+        args: ChecksumArgs,
+    },
+
+    /// Arguments of checksum option.
+    [parser parse_checksum_args]
+    ChecksumArgs {
+        /// Checksum algorithm.
+        algorithm: ::syn::Path,
+
+        /// Fields covered by checksum.
+        over: Option<ChecksumOver>,
+    },
+
+    /// Fields covered by checksum.
+    ChecksumOver {
+        /// Opt keyword.
+        kw: kw::over,
+
+        /// '=' token.
+        eq_token: Token![=],
+
+        /// First field covered, or 'start'.
+        start: ::syn::Ident,
+
+        /// '..' token.
+        dot2_token: Token![..],
+
+        /// Field after last field covered, or 'here'.
+        end: ::syn::Ident,
+    },
+
+    /// Amount of bits field is stored in.
+    Bits {
+        /// Opt keyword.
+        kw: kw::bits,
+
+        /// '=' token.
+        eq_token: Token![=],
+
+        /// Amount of bits.
+        bits: ::syn::LitInt,
+    },
+
+    /// Order of bits in bit fields.
+    BitOrder {
+        /// Opt keyword.
+        kw: kw::bit_order,
+
+        /// '=' token.
+        eq_token: Token![=],
+
+        /// Bit order, one of 'msb0' or 'lsb0'.
+        order: ::syn::Ident,
+    },
+
+    /// Magic bytes read/written and verified.
+    Magic {
+        /// Opt keyword.
+        kw: kw::magic,
+
+        /// '=' token.
+        eq_token: Token![=],
+
+        /// Magic bytes.
+        magic: ::syn::LitByteStr,
+    },
+
+    /// Assertion on read values.
+    Assert {
+        /// Opt keyword.
+        kw: kw::assert,
+
+        /// Assertion arguments.
+        args: AssertArgs,
+    },
+
+    /// Arguments of assert option.
+    [parser parse_assert_args]
+    AssertArgs {
+        /// Condition which should hold.
+        condition: ::syn::Expr,
+
+        /// Message of error on failure.
+        message: ::syn::LitStr,
+
+        /// Assertion is also checked on write.
+        write: Option<kw::write>,
+    },
+
+    /// Value of field calculated on write.
+    Calc {
+        /// Opt keyword.
+        kw: kw::calc,
+
+        /// '=' token.
+        eq_token: Token![=],
+
+        /// Expression calculating value.
+        expr: ::syn::Expr,
+    },
+
+    /// Verify calculated value on read.
+    Verify {
+        /// Opt keyword.
+        kw: kw::verify,
+    },
+
+    /// Alignment of field relative to start of record, or offset in source/sink.
+    Align {
+        /// Opt keyword.
+        kw: kw::align,
+
+        /// '=' token.
+        eq_token: Token![=],
+
+        /// Alignment expression.
+        align: ::syn::Expr,
+    },
+
+    /// Record is padded to a multiple of a size.
+    PadToMultiple {
+        /// Opt keyword.
+        kw: kw::pad_to_multiple,
+
+        /// '=' token.
+        eq_token: Token![=],
+
+        /// Multiple expression.
+        multiple: ::syn::Expr,
+    },
+
+    /// What alignment and padding is relative to.
+    ///
+    /// Encoded lengths of types aligned relative to offset assume they start at an aligned offset.
+    AlignBase {
+        /// Opt keyword.
+        kw: kw::align_base,
+
+        /// '=' token.
+        eq_token: Token![=],
+
+        /// Base, one of 'record' or 'offset'.
+        base: ::syn::Ident,
+    },
+
+    /// Absolute position field is read at/written to.
+    ///
+    /// Like FilePtr, positions are tracked by the GuardedSource read from, such that cycles are
+    /// detected.
+    Offset {
+        /// Opt keyword.
+        kw: kw::offset,
+
+        /// '=' token.
+        eq_token: Token![=],
+
+        /// Position expression.
+        offset: ::syn::Expr,
+    },
+
+    /// Condition for optional field to be present.
+    FieldIf {
+        /// Opt keyword.
+        kw: token::If,
+
+        /// '=' token.
+        eq_token: Token![=],
+
+        /// Condition.
+        cond: ::syn::Expr,
+    },
+
+    /// Variant is read when no other variant matches.
+    CatchAll {
+        /// Opt keyword.
+        kw: kw::catch_all,
+    },
+
     /// Option to ignore a field.
     IgnoreField {
         /// Opt keyword.
@@ -307,6 +617,79 @@ fn parse_with_pat(input: ParseStream) -> syn::Result<WithPat> {
     })
 }
 
+/// Parse checksum arguments.
+///
+/// # Errors
+/// On failure to parse.
+fn parse_checksum_args(input: ParseStream) -> syn::Result<ChecksumArgs> {
+    let content;
+    ::syn::parenthesized!(content in input);
+    let algorithm = content.parse()?;
+    let over = if content.parse::<Option<Token![,]>>()?.is_some() && !content.is_empty() {
+        Some(content.parse()?)
+    } else {
+        None
+    };
+    if !content.is_empty() {
+        return Err(content.error("expected 'over = start..end'"));
+    }
+    Ok(ChecksumArgs { algorithm, over })
+}
+
+/// Parse assert arguments.
+///
+/// # Errors
+/// On failure to parse.
+fn parse_assert_args(input: ParseStream) -> syn::Result<AssertArgs> {
+    let content;
+    ::syn::parenthesized!(content in input);
+    let condition = content.parse()?;
+    content.parse::<Token![,]>()?;
+    let message = content.parse()?;
+    let write = if content.parse::<Option<Token![,]>>()?.is_some() && !content.is_empty() {
+        Some(content.parse()?)
+    } else {
+        None
+    };
+    if !content.is_empty() {
+        return Err(content.error("expected 'write'"));
+    }
+    Ok(AssertArgs {
+        condition,
+        message,
+        write,
+    })
+}
+
+/// Assertions, the assert option may be repeated.
+#[derive(Debug, Clone)]
+pub struct Asserts(pub Vec<Assert>);
+
+impl Parse for Asserts {
+    fn parse(input: ParseStream) -> ::syn::Result<Self> {
+        Ok(Self(vec![input.parse()?]))
+    }
+}
+
+impl Opt for Asserts {
+    fn peek(lookahead: &::syn::parse::Lookahead1) -> bool {
+        Assert::peek(lookahead)
+    }
+
+    fn name() -> impl ::core::fmt::Display {
+        Assert::name()
+    }
+
+    fn kw_span(&self) -> Span {
+        self.0.first().map_or_else(Span::call_site, Opt::kw_span)
+    }
+
+    fn merge(&mut self, other: Self) -> Result<(), Self> {
+        self.0.extend(other.0);
+        Ok(())
+    }
+}
+
 /// Parse barse attributes and run given function on tokens.
 ///
 /// # Errors
@@ -347,6 +730,17 @@ pub trait Opt {
 
     /// Get keyword span of option.
     fn kw_span(&self) -> Span;
+
+    /// Merge option with a repeat of it, giving the repeat back if options may not be repeated.
+    ///
+    /// # Errors
+    /// If option may not be repeated.
+    fn merge(&mut self, other: Self) -> Result<(), Self>
+    where
+        Self: Sized,
+    {
+        Err(other)
+    }
 }
 
 /// Option parsing chain.
@@ -386,15 +780,18 @@ impl<'a> Chain<'a> {
         let val = O::parse(self.input)?;
         self.flow = ControlFlow::Break(());
 
-        if opt.is_some() {
+        let Some(set) = opt else {
+            *opt = Some(val);
+            return Ok(self);
+        };
+
+        if let Err(val) = set.merge(val) {
             return Err(::syn::Error::new(
                 val.kw_span(),
                 format!("'{}' has already been set", O::name()),
             ));
         }
 
-        *opt = Some(val);
-
         Ok(self)
     }
 
@@ -0,0 +1,34 @@
+//! Adapters wrapping sources and sinks.
+
+pub use self::{
+    bits::{BitField, BitOrder, BitReader, BitWriter, Lsb0, Msb0},
+    checksum::{Additive, Adler32, Checksum, Crc32},
+    checksummed::Checksummed,
+    checksummed_sink::ChecksummedSink,
+    limited::{IntoLimit, Leftover, Limited},
+    limited_sink::LimitedSink,
+    prefixed::Prefixed,
+    ptr_guard::PtrGuard,
+    tracked::{offset_position, padding_to, Tracked},
+    tracked_sink::TrackedSink,
+};
+
+mod prefixed;
+
+mod limited;
+
+mod limited_sink;
+
+mod checksum;
+
+mod checksummed;
+
+mod checksummed_sink;
+
+mod bits;
+
+mod tracked;
+
+mod tracked_sink;
+
+mod ptr_guard;
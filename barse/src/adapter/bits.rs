@@ -0,0 +1,407 @@
+//! [BitReader] and [BitWriter] impl.
+
+use ::core::marker::PhantomData;
+
+use crate::{sealed::Sealed, ByteSink, ByteSource, Error};
+
+/// Order bits are read from/written to bytes in.
+pub trait BitOrder: Sealed {
+    /// Take count bits from byte, of which left bits have not been taken yet.
+    fn take(byte: u8, left: u32, count: u32) -> u8;
+
+    /// Place count bits in byte, of which left bits are still free.
+    fn place(bits: u8, left: u32, count: u32) -> u8;
+
+    /// Append count bits to value, of which done bits have been collected.
+    fn append(value: u64, bits: u8, done: u32, count: u32) -> u64;
+
+    /// Split count bits from value, of which done bits have been placed, out of total.
+    fn split(value: u64, done: u32, total: u32, count: u32) -> u8;
+}
+
+/// Most significant bit first, values are stored with their most significant bit first.
+///
+/// Used by most network protocols, such as the version and IHL fields of IPv4 headers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Msb0;
+
+/// Least significant bit first, values are stored with their least significant bit first.
+///
+/// Used by formats such as DEFLATE.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Lsb0;
+
+/// Mask of the lowest count bits of a byte.
+const fn mask(count: u32) -> u8 {
+    ((1u16 << count) - 1) as u8
+}
+
+impl Sealed for Msb0 {}
+impl BitOrder for Msb0 {
+    #[inline]
+    fn take(byte: u8, left: u32, count: u32) -> u8 {
+        (byte >> (left - count)) & mask(count)
+    }
+
+    #[inline]
+    fn place(bits: u8, left: u32, count: u32) -> u8 {
+        (bits & mask(count)) << (left - count)
+    }
+
+    #[inline]
+    fn append(value: u64, bits: u8, _done: u32, count: u32) -> u64 {
+        value.checked_shl(count).unwrap_or(0) | u64::from(bits)
+    }
+
+    #[inline]
+    fn split(value: u64, done: u32, total: u32, count: u32) -> u8 {
+        (value >> (total - done - count)) as u8 & mask(count)
+    }
+}
+
+impl Sealed for Lsb0 {}
+impl BitOrder for Lsb0 {
+    #[inline]
+    fn take(byte: u8, left: u32, count: u32) -> u8 {
+        (byte >> (8 - left)) & mask(count)
+    }
+
+    #[inline]
+    fn place(bits: u8, left: u32, count: u32) -> u8 {
+        (bits & mask(count)) << (8 - left)
+    }
+
+    #[inline]
+    fn append(value: u64, bits: u8, done: u32, _count: u32) -> u64 {
+        value | u64::from(bits) << done
+    }
+
+    #[inline]
+    fn split(value: u64, done: u32, _total: u32, count: u32) -> u8 {
+        (value >> done) as u8 & mask(count)
+    }
+}
+
+/// Values which may be stored in a bit field.
+pub trait BitField: Sized {
+    /// Convert from the lowest count bits of bits, if the value fits.
+    fn from_bits(bits: u64, count: u32) -> Option<Self>;
+
+    /// Convert to count bits, if the value fits.
+    fn to_bits(&self, count: u32) -> Option<u64>;
+}
+
+/// Mask of the lowest count bits of a u64.
+const fn mask_u64(count: u32) -> u64 {
+    match u64::BITS.checked_sub(count) {
+        Some(64) => 0,
+        Some(unused) => u64::MAX >> unused,
+        None => u64::MAX,
+    }
+}
+
+/// Implement [BitField] for unsigned integers.
+macro_rules! bit_field_unsigned {
+    ($($ty:ty),*) => {$(
+        impl BitField for $ty {
+            #[inline]
+            fn from_bits(bits: u64, _count: u32) -> Option<Self> {
+                Self::try_from(bits).ok()
+            }
+
+            #[inline]
+            fn to_bits(&self, count: u32) -> Option<u64> {
+                let bits = u64::try_from(*self).ok()?;
+                (bits & !mask_u64(count) == 0).then_some(bits)
+            }
+        }
+    )*};
+}
+
+/// Implement [BitField] for signed integers, stored as two's complement.
+macro_rules! bit_field_signed {
+    ($($ty:ty),*) => {$(
+        impl BitField for $ty {
+            #[inline]
+            fn from_bits(bits: u64, count: u32) -> Option<Self> {
+                let unused = u64::BITS.checked_sub(count)?;
+                let value = bits
+                    .checked_shl(unused)
+                    .map_or(0, |bits| bits.cast_signed() >> unused);
+                Self::try_from(value).ok()
+            }
+
+            #[inline]
+            fn to_bits(&self, count: u32) -> Option<u64> {
+                let value = i64::try_from(*self).ok()?;
+                let bits = value.cast_unsigned() & mask_u64(count);
+                (Self::from_bits(bits, count)? == *self).then_some(bits)
+            }
+        }
+    )*};
+}
+
+bit_field_unsigned!(u8, u16, u32, u64, usize);
+bit_field_signed!(i8, i16, i32, i64, isize);
+
+impl BitField for bool {
+    #[inline]
+    fn from_bits(bits: u64, _count: u32) -> Option<Self> {
+        Some(bits != 0)
+    }
+
+    #[inline]
+    fn to_bits(&self, _count: u32) -> Option<u64> {
+        Some(u64::from(*self))
+    }
+}
+
+/// Read values bit by bit from a [ByteSource], in order O.
+#[derive(Debug)]
+pub struct BitReader<B, O = Msb0> {
+    /// Wrapped source.
+    source: B,
+
+    /// Byte bits are currently read from.
+    byte: u8,
+
+    /// Bits of byte not yet read.
+    left: u32,
+
+    /// Bit order.
+    _p: PhantomData<fn() -> O>,
+}
+
+impl<B, O> BitReader<B, O>
+where
+    B: ByteSource,
+    O: BitOrder,
+{
+    /// Construct a new [BitReader] reading from source.
+    #[inline]
+    pub const fn new(source: B) -> Self {
+        Self {
+            source,
+            byte: 0,
+            left: 0,
+            _p: PhantomData,
+        }
+    }
+
+    /// Read count bits, at most 64.
+    ///
+    /// # Errors
+    /// If the wrapped source cannot be read from.
+    ///
+    /// # Panics
+    /// If more than 64 bits are requested.
+    pub fn read_bits(&mut self, count: u32) -> Result<u64, B::Err> {
+        assert!(count <= u64::BITS, "at most 64 bits may be read at once");
+        let mut value = 0;
+        let mut done = 0;
+        while done < count {
+            if self.left == 0 {
+                self.byte = self.source.read_byte()?;
+                self.left = 8;
+            }
+            let take = self.left.min(count - done);
+            value = O::append(value, O::take(self.byte, self.left, take), done, take);
+            self.left -= take;
+            done += take;
+        }
+        Ok(value)
+    }
+
+    /// Read a [BitField] stored in count bits.
+    ///
+    /// # Errors
+    /// If the wrapped source cannot be read from or the value does not fit in T.
+    ///
+    /// # Panics
+    /// If more than 64 bits are requested.
+    pub fn read_field<T>(&mut self, count: u32) -> Result<T, Error>
+    where
+        T: BitField,
+        B::Err: Into<Error>,
+    {
+        let bits = self.read_bits(count).map_err(Into::into)?;
+        T::from_bits(bits, count).ok_or(Error::Msg("bit field did not fit in type"))
+    }
+
+    /// Check if no bits of a partially read byte remain.
+    #[inline]
+    pub const fn is_aligned(&self) -> bool {
+        self.left == 0
+    }
+
+    /// Discard any remaining bits of a partially read byte.
+    #[inline]
+    pub const fn align(&mut self) {
+        self.left = 0;
+    }
+
+    /// Unwrap [BitReader] to wrapped source, remaining bits of a partially read byte are lost.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.source
+    }
+}
+
+/// Write values bit by bit to a [ByteSink], in order O.
+#[derive(Debug)]
+pub struct BitWriter<B, O = Msb0> {
+    /// Wrapped sink.
+    sink: B,
+
+    /// Byte bits are currently written to.
+    byte: u8,
+
+    /// Bits of byte not yet written.
+    left: u32,
+
+    /// Bit order.
+    _p: PhantomData<fn() -> O>,
+}
+
+impl<B, O> BitWriter<B, O>
+where
+    B: ByteSink,
+    O: BitOrder,
+{
+    /// Construct a new [BitWriter] writing to sink.
+    #[inline]
+    pub const fn new(sink: B) -> Self {
+        Self {
+            sink,
+            byte: 0,
+            left: 8,
+            _p: PhantomData,
+        }
+    }
+
+    /// Write the lowest count bits of value, at most 64.
+    ///
+    /// # Errors
+    /// If the wrapped sink cannot be written to.
+    ///
+    /// # Panics
+    /// If more than 64 bits are written.
+    pub fn write_bits(&mut self, value: u64, count: u32) -> Result<(), B::Err> {
+        assert!(count <= u64::BITS, "at most 64 bits may be written at once");
+        let mut done = 0;
+        while done < count {
+            let take = self.left.min(count - done);
+            self.byte |= O::place(O::split(value, done, count, take), self.left, take);
+            self.left -= take;
+            done += take;
+            if self.left == 0 {
+                self.sink.write_byte(self.byte)?;
+                self.byte = 0;
+                self.left = 8;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write a [BitField] using count bits.
+    ///
+    /// # Errors
+    /// If the wrapped sink cannot be written to or the value does not fit in count bits.
+    ///
+    /// # Panics
+    /// If more than 64 bits are written.
+    pub fn write_field<T>(&mut self, value: &T, count: u32) -> Result<(), Error>
+    where
+        T: BitField,
+        B::Err: Into<Error>,
+    {
+        let bits = value
+            .to_bits(count)
+            .ok_or(Error::Msg("value did not fit in bit field"))?;
+        self.write_bits(bits, count).map_err(Into::into)
+    }
+
+    /// Check if no bits of a partially written byte remain.
+    #[inline]
+    pub const fn is_aligned(&self) -> bool {
+        self.left == 8
+    }
+
+    /// Write a partially written byte, if any, padded with zeroes.
+    ///
+    /// # Errors
+    /// If the wrapped sink cannot be written to.
+    pub fn align(&mut self) -> Result<(), B::Err> {
+        if !self.is_aligned() {
+            self.sink.write_byte(self.byte)?;
+            self.byte = 0;
+            self.left = 8;
+        }
+        Ok(())
+    }
+
+    /// Align and unwrap [BitWriter] to wrapped sink.
+    ///
+    /// # Errors
+    /// If the wrapped sink cannot be written to.
+    #[inline]
+    pub fn finish(mut self) -> Result<B, B::Err> {
+        self.align()?;
+        Ok(self.sink)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    use crate::{SliceSink, SliceSrc};
+
+    use super::*;
+
+    #[test]
+    fn msb0() {
+        let mut reader = BitReader::<_, Msb0>::new(SliceSrc::new(&[0x45, 0xa5, 0xf0]));
+        assert_eq!(reader.read_bits(4).unwrap(), 4);
+        assert_eq!(reader.read_bits(4).unwrap(), 5);
+        assert_eq!(reader.read_bits(3).unwrap(), 0b101);
+        assert_eq!(reader.read_bits(9).unwrap(), 0b0_0101_1111);
+        assert!(!reader.is_aligned());
+        assert_eq!(reader.read_field::<i8>(4).unwrap(), 0);
+
+        let mut buf = [0u8; 3];
+        let mut writer = BitWriter::<_, Msb0>::new(SliceSink::new(&mut buf));
+        writer.write_bits(4, 4).unwrap();
+        writer.write_bits(5, 4).unwrap();
+        writer.write_bits(0b101, 3).unwrap();
+        writer.write_bits(0b0_0101_1111, 9).unwrap();
+        writer.finish().unwrap();
+        assert_eq!(buf, [0x45, 0xa5, 0xf0]);
+    }
+
+    #[test]
+    fn lsb0() {
+        let mut reader = BitReader::<_, Lsb0>::new(SliceSrc::new(&[0x45, 0xa5]));
+        assert_eq!(reader.read_bits(4).unwrap(), 5);
+        assert_eq!(reader.read_bits(8).unwrap(), 0x54);
+        assert_eq!(reader.read_field::<i8>(4).unwrap(), -6);
+
+        let mut buf = [0u8; 2];
+        let mut writer = BitWriter::<_, Lsb0>::new(SliceSink::new(&mut buf));
+        writer.write_bits(5, 4).unwrap();
+        writer.write_bits(0x54, 8).unwrap();
+        writer.write_field(&-6i8, 4).unwrap();
+        assert!(writer.is_aligned());
+        assert_eq!(buf, [0x45, 0xa5]);
+    }
+
+    #[test]
+    fn fields() {
+        assert_eq!(u8::from_bits(0b111, 3), Some(7));
+        assert_eq!(7u8.to_bits(2), None);
+        assert_eq!((-4i8).to_bits(3), Some(0b100));
+        assert_eq!((-5i8).to_bits(3), None);
+        assert_eq!(4i8.to_bits(3), None);
+        assert_eq!(bool::from_bits(1, 1), Some(true));
+    }
+}
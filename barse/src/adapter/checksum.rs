@@ -0,0 +1,215 @@
+//! [Checksum] trait and algorithms.
+
+/// Running checksum fed bytes by [Checksummed][crate::adapter::Checksummed] and
+/// [ChecksummedSink][crate::adapter::ChecksummedSink].
+pub trait Checksum {
+    /// Value of checksum.
+    type Output;
+
+    /// Feed bytes to checksum.
+    fn update(&mut self, bytes: &[u8]);
+
+    /// Get value of checksum for bytes fed so far.
+    fn value(&self) -> Self::Output;
+}
+
+impl<C> Checksum for &mut C
+where
+    C: Checksum,
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn update(&mut self, bytes: &[u8]) {
+        C::update(self, bytes);
+    }
+
+    #[inline]
+    fn value(&self) -> Self::Output {
+        C::value(self)
+    }
+}
+
+/// Lookup table for [Crc32].
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < table.len() {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// CRC-32 checksum, as used by zip, png and ethernet (ISO-HDLC).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Crc32 {
+    /// Inverted crc.
+    state: u32,
+}
+
+impl Crc32 {
+    /// Construct a new [Crc32] which has not been fed any bytes.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { state: !0 }
+    }
+}
+
+impl Default for Crc32 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Checksum for Crc32 {
+    type Output = u32;
+
+    #[inline]
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.state = CRC32_TABLE[usize::from(self.state as u8 ^ byte)] ^ (self.state >> 8);
+        }
+    }
+
+    #[inline]
+    fn value(&self) -> Self::Output {
+        !self.state
+    }
+}
+
+/// Largest prime smaller than 65536.
+const ADLER32_MOD: u32 = 65521;
+
+/// Largest amount of bytes that may be summed before sums of [Adler32] may overflow.
+const ADLER32_CHUNK: usize = 5552;
+
+/// Adler-32 checksum, as used by zlib.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Adler32 {
+    /// Sum of bytes.
+    a: u32,
+
+    /// Sum of a.
+    b: u32,
+}
+
+impl Adler32 {
+    /// Construct a new [Adler32] which has not been fed any bytes.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { a: 1, b: 0 }
+    }
+}
+
+impl Default for Adler32 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Checksum for Adler32 {
+    type Output = u32;
+
+    #[inline]
+    fn update(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(ADLER32_CHUNK) {
+            for &byte in chunk {
+                self.a += u32::from(byte);
+                self.b += self.a;
+            }
+            self.a %= ADLER32_MOD;
+            self.b %= ADLER32_MOD;
+        }
+    }
+
+    #[inline]
+    fn value(&self) -> Self::Output {
+        (self.b << 16) | self.a
+    }
+}
+
+/// Additive checksum, a wrapping sum of all bytes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Additive<T = u8> {
+    /// Sum of bytes.
+    sum: T,
+}
+
+/// Implement [Checksum] for [Additive].
+macro_rules! additive_impl {
+    ($($ty:ty),*) => {$(
+        impl Additive<$ty> {
+            /// Construct a new [Additive] which has not been fed any bytes.
+            #[inline]
+            pub const fn new() -> Self {
+                Self { sum: 0 }
+            }
+        }
+
+        impl Checksum for Additive<$ty> {
+            type Output = $ty;
+
+            #[inline]
+            fn update(&mut self, bytes: &[u8]) {
+                for &byte in bytes {
+                    self.sum = self.sum.wrapping_add(<$ty>::from(byte));
+                }
+            }
+
+            #[inline]
+            fn value(&self) -> Self::Output {
+                self.sum
+            }
+        }
+    )*};
+}
+
+additive_impl!(u8, u16, u32, u64);
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    use super::*;
+
+    /// Get checksum of bytes.
+    fn checksum<C: Checksum + Default>(bytes: &[u8]) -> C::Output {
+        let mut checksum = C::default();
+        let (head, tail) = bytes.split_at(bytes.len() / 2);
+        checksum.update(head);
+        checksum.update(tail);
+        checksum.value()
+    }
+
+    #[test]
+    fn algorithms() {
+        let check = b"123456789";
+
+        assert_eq!(checksum::<Crc32>(check), 0xCBF4_3926);
+        assert_eq!(checksum::<Crc32>(b""), 0);
+        assert_eq!(checksum::<Adler32>(check), 0x091E_01DE);
+        assert_eq!(checksum::<Adler32>(b"Wikipedia"), 0x11E6_0398);
+        assert_eq!(checksum::<Additive<u8>>(check), 0xDD);
+        assert_eq!(checksum::<Additive<u16>>(check), 0x01DD);
+        assert_eq!(checksum::<Adler32>(&[0xff; 100_000]), {
+            let (a, b) = (0..100_000u64).fold((1u64, 0u64), |(a, b), _| {
+                let a = (a + 0xff) % 65521;
+                (a, (b + a) % 65521)
+            });
+            ((b << 16) | a) as u32
+        });
+    }
+}
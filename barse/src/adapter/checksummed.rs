@@ -0,0 +1,209 @@
+//! [Checksummed] impl.
+
+use crate::{adapter::Checksum, BorrowSource, ByteSource, PeekSource};
+
+/// [ByteSource] adapter feeding all bytes read, or skipped, through a [Checksum].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Checksummed<B, C> {
+    /// Wrapped source.
+    source: B,
+
+    /// Running checksum.
+    checksum: C,
+}
+
+/// Size of chunks skipped bytes are read in.
+const SKIP_CHUNK: usize = 64;
+
+impl<B, C> Checksummed<B, C> {
+    /// Construct a new [Checksummed] feeding bytes read from source to checksum.
+    #[inline]
+    pub const fn new(source: B, checksum: C) -> Self {
+        Self { source, checksum }
+    }
+
+    /// Get running checksum.
+    #[inline]
+    pub const fn checksum(&self) -> &C {
+        &self.checksum
+    }
+
+    /// Get wrapped source.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.source
+    }
+
+    /// Get wrapped source and running checksum.
+    #[inline]
+    pub fn into_parts(self) -> (B, C) {
+        (self.source, self.checksum)
+    }
+}
+
+impl<B, C> Checksummed<B, C>
+where
+    C: Checksum,
+{
+    /// Get value of checksum for bytes read so far.
+    #[inline]
+    pub fn value(&self) -> C::Output {
+        self.checksum.value()
+    }
+}
+
+impl<B, C> ByteSource for Checksummed<B, C>
+where
+    B: ByteSource,
+    C: Checksum,
+{
+    type Err = B::Err;
+
+    #[inline]
+    fn read_slice(&mut self, buf: &mut [u8]) -> Result<(), Self::Err> {
+        self.source.read_slice(buf)?;
+        self.checksum.update(buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], Self::Err> {
+        let bytes = self.source.read_array::<N>()?;
+        self.checksum.update(&bytes);
+        Ok(bytes)
+    }
+
+    #[inline]
+    fn read_byte(&mut self) -> Result<u8, Self::Err> {
+        let byte = self.source.read_byte()?;
+        self.checksum.update(&[byte]);
+        Ok(byte)
+    }
+
+    #[inline]
+    fn read_byte_or_eof(&mut self) -> Result<Option<u8>, Self::Err> {
+        let byte = self.source.read_byte_or_eof()?;
+        if let Some(byte) = byte {
+            self.checksum.update(&[byte]);
+        }
+        Ok(byte)
+    }
+
+    #[inline]
+    fn try_peek_slice(&mut self, buf: &mut [u8]) -> Result<bool, Self::Err> {
+        self.source.try_peek_slice(buf)
+    }
+
+    #[inline]
+    fn skip(&mut self, mut count: usize) -> Result<(), Self::Err> {
+        let mut buf = [0u8; SKIP_CHUNK];
+        while count != 0 {
+            let buf = &mut buf[..count.min(SKIP_CHUNK)];
+            self.read_slice(buf)?;
+            count -= buf.len();
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn remaining(&self) -> Option<usize> {
+        self.source.remaining()
+    }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        self.source.offset()
+    }
+}
+
+impl<B, C> PeekSource for Checksummed<B, C>
+where
+    B: PeekSource,
+    C: Checksum,
+{
+    #[inline]
+    fn peek_slice(&mut self, buf: &mut [u8]) -> Result<(), Self::Err> {
+        self.source.peek_slice(buf)
+    }
+}
+
+impl<'src, B, C> BorrowSource<'src> for Checksummed<B, C>
+where
+    B: BorrowSource<'src>,
+    C: Checksum,
+{
+    #[inline]
+    fn borrow_slice(&mut self, len: usize) -> Result<&'src [u8], Self::Err> {
+        let bytes = self.source.borrow_slice(len)?;
+        self.checksum.update(bytes);
+        Ok(bytes)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<B, C> crate::AsyncByteSource for Checksummed<B, C>
+where
+    B: crate::AsyncByteSource,
+    C: Checksum,
+{
+    type Err = B::Err;
+
+    #[inline]
+    async fn read_slice(&mut self, buf: &mut [u8]) -> Result<(), Self::Err> {
+        self.source.read_slice(buf).await?;
+        self.checksum.update(buf);
+        Ok(())
+    }
+
+    #[inline]
+    async fn read_byte_or_eof(&mut self) -> Result<Option<u8>, Self::Err> {
+        let byte = self.source.read_byte_or_eof().await?;
+        if let Some(byte) = byte {
+            self.checksum.update(&[byte]);
+        }
+        Ok(byte)
+    }
+
+    #[inline]
+    async fn skip(&mut self, mut count: usize) -> Result<(), Self::Err> {
+        let mut buf = [0u8; SKIP_CHUNK];
+        while count != 0 {
+            let buf = &mut buf[..count.min(SKIP_CHUNK)];
+            crate::AsyncByteSource::read_slice(self, buf).await?;
+            count -= buf.len();
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn remaining(&self) -> Option<usize> {
+        self.source.remaining()
+    }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        self.source.offset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    use crate::{adapter::Crc32, SliceSrc};
+
+    use super::*;
+
+    #[test]
+    fn checksummed() {
+        let mut source = Checksummed::new(SliceSrc::new(b"1234567890"), Crc32::new());
+
+        assert_eq!(source.peek_array::<2>(), Ok(*b"12"));
+        assert_eq!(source.read_array::<3>(), Ok(*b"123"));
+        assert_eq!(source.skip(4), Ok(()));
+        assert_eq!(source.borrow_slice(2), Ok(&b"89"[..]));
+        assert_eq!(source.value(), 0xCBF4_3926);
+        assert_eq!(source.read_byte(), Ok(b'0'));
+        assert_ne!(source.value(), 0xCBF4_3926);
+    }
+}
@@ -0,0 +1,133 @@
+//! [ChecksummedSink] impl.
+
+use crate::{adapter::Checksum, ByteSink};
+
+/// [ByteSink] adapter feeding all bytes written through a [Checksum].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ChecksummedSink<B, C> {
+    /// Wrapped sink.
+    sink: B,
+
+    /// Running checksum.
+    checksum: C,
+}
+
+impl<B, C> ChecksummedSink<B, C> {
+    /// Construct a new [ChecksummedSink] feeding bytes written to sink to checksum.
+    #[inline]
+    pub const fn new(sink: B, checksum: C) -> Self {
+        Self { sink, checksum }
+    }
+
+    /// Get running checksum.
+    #[inline]
+    pub const fn checksum(&self) -> &C {
+        &self.checksum
+    }
+
+    /// Get wrapped sink.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.sink
+    }
+
+    /// Get wrapped sink and running checksum.
+    #[inline]
+    pub fn into_parts(self) -> (B, C) {
+        (self.sink, self.checksum)
+    }
+}
+
+impl<B, C> ChecksummedSink<B, C>
+where
+    C: Checksum,
+{
+    /// Get value of checksum for bytes written so far.
+    #[inline]
+    pub fn value(&self) -> C::Output {
+        self.checksum.value()
+    }
+}
+
+impl<B, C> ByteSink for ChecksummedSink<B, C>
+where
+    B: ByteSink,
+    C: Checksum,
+{
+    type Err = B::Err;
+
+    #[inline]
+    fn write_slice(&mut self, buf: &[u8]) -> Result<(), Self::Err> {
+        self.sink.write_slice(buf)?;
+        self.checksum.update(buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_array<const N: usize>(&mut self, bytes: [u8; N]) -> Result<(), Self::Err> {
+        self.sink.write_array(bytes)?;
+        self.checksum.update(&bytes);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_byte(&mut self, byte: u8) -> Result<(), Self::Err> {
+        self.sink.write_byte(byte)?;
+        self.checksum.update(&[byte]);
+        Ok(())
+    }
+
+    #[inline]
+    fn remaining(&self) -> Option<usize> {
+        self.sink.remaining()
+    }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        self.sink.offset()
+    }
+}
+
+#[cfg(feature = "async")]
+impl<B, C> crate::AsyncByteSink for ChecksummedSink<B, C>
+where
+    B: crate::AsyncByteSink,
+    C: Checksum,
+{
+    type Err = B::Err;
+
+    #[inline]
+    async fn write_slice(&mut self, buf: &[u8]) -> Result<(), Self::Err> {
+        self.sink.write_slice(buf).await?;
+        self.checksum.update(buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        self.sink.offset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    use crate::{adapter::Adler32, SliceSink};
+
+    use super::*;
+
+    #[test]
+    fn checksummed_sink() {
+        let mut buf = [0u8; 9];
+        let mut sink = ChecksummedSink::new(SliceSink::new(&mut buf), Adler32::new());
+
+        assert_eq!(sink.write_array(*b"Wiki"), Ok(()));
+        assert_eq!(sink.write_byte(b'p'), Ok(()));
+        assert_eq!(sink.write_slice(b"edia"), Ok(()));
+        assert_eq!(sink.value(), 0x11E6_0398);
+        assert!(sink.write_byte(b'!').is_err());
+        assert_eq!(sink.value(), 0x11E6_0398);
+        assert_eq!(&buf, b"Wikipedia");
+    }
+}
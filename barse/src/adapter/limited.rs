@@ -0,0 +1,310 @@
+//! [Limited] impl.
+
+use crate::{error::LimitErr, BorrowSource, ByteSource, PeekSource};
+
+/// What to do with bytes left within a limit when finished.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Leftover {
+    /// Leftover bytes are an error.
+    #[default]
+    Error,
+
+    /// Leftover bytes are skipped, for sinks they are written as zeroes.
+    Skip,
+
+    /// Leftover bytes are kept, and may be read from/written to wrapped source/sink later.
+    Keep,
+}
+
+/// Values that may be used as a limit, implemented for unsigned integers and references to them.
+pub trait IntoLimit {
+    /// Get limit as usize, values too large for usize saturate.
+    fn into_limit(self) -> usize;
+}
+
+/// Implement IntoLimit for unsigned integers.
+macro_rules! into_limit_impl {
+    ($($ty:ty),*) => {$(
+        impl IntoLimit for $ty {
+            #[inline]
+            fn into_limit(self) -> usize {
+                usize::try_from(self).unwrap_or(usize::MAX)
+            }
+        }
+    )*};
+}
+
+into_limit_impl!(u8, u16, u32, u64, u128, usize);
+
+impl<T> IntoLimit for &T
+where
+    T: IntoLimit + Copy,
+{
+    #[inline]
+    fn into_limit(self) -> usize {
+        T::into_limit(*self)
+    }
+}
+
+/// [ByteSource] adapter restricting wrapped source to a limited amount of bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Limited<B> {
+    /// Wrapped source.
+    source: B,
+
+    /// Bytes left within limit.
+    left: usize,
+}
+
+impl<B> Limited<B> {
+    /// Construct a new [Limited] allowing at most limit bytes to be read from source.
+    #[inline]
+    pub fn new(source: B, limit: impl IntoLimit) -> Self {
+        Self {
+            source,
+            left: limit.into_limit(),
+        }
+    }
+
+    /// Get amount of bytes left within limit.
+    #[inline]
+    pub const fn left(&self) -> usize {
+        self.left
+    }
+
+    /// Get wrapped source.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.source
+    }
+
+    /// Use count bytes of limit.
+    ///
+    /// # Errors
+    /// If count is larger than what is left of limit.
+    #[inline]
+    const fn consume<E>(&mut self, count: usize) -> Result<(), LimitErr<E>> {
+        if count > self.left {
+            return Err(LimitErr::Exceeded);
+        }
+        self.left -= count;
+        Ok(())
+    }
+
+    /// Check if there are bytes left within limit, using leftover policy.
+    ///
+    /// # Returns
+    /// Amount of bytes that should be skipped.
+    ///
+    /// # Errors
+    /// If bytes are left and they are not allowed to be.
+    #[inline]
+    const fn leftover<E>(&mut self, leftover: Leftover) -> Result<usize, LimitErr<E>> {
+        let left = self.left;
+        match leftover {
+            Leftover::Error if left != 0 => Err(LimitErr::Leftover(left)),
+            Leftover::Error | Leftover::Keep => Ok(0),
+            Leftover::Skip => {
+                self.left = 0;
+                Ok(left)
+            }
+        }
+    }
+}
+
+impl<B> Limited<B>
+where
+    B: ByteSource,
+{
+    /// Finish reading from limited source, handling leftover bytes according to policy.
+    ///
+    /// # Errors
+    /// If leftover bytes are not allowed, or cannot be skipped.
+    #[inline]
+    pub fn finish(&mut self, leftover: Leftover) -> Result<(), LimitErr<B::Err>> {
+        let skip = self.leftover(leftover)?;
+        Ok(self.source.skip(skip)?)
+    }
+}
+
+impl<B> ByteSource for Limited<B>
+where
+    B: ByteSource,
+{
+    type Err = LimitErr<B::Err>;
+
+    #[inline]
+    fn read_slice(&mut self, buf: &mut [u8]) -> Result<(), Self::Err> {
+        self.consume(buf.len())?;
+        Ok(self.source.read_slice(buf)?)
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], Self::Err> {
+        self.consume(N)?;
+        Ok(self.source.read_array()?)
+    }
+
+    #[inline]
+    fn read_byte(&mut self) -> Result<u8, Self::Err> {
+        self.consume(1)?;
+        Ok(self.source.read_byte()?)
+    }
+
+    #[inline]
+    fn read_byte_or_eof(&mut self) -> Result<Option<u8>, Self::Err> {
+        if self.left == 0 {
+            return Ok(None);
+        }
+        let byte = self.source.read_byte_or_eof()?;
+        if byte.is_some() {
+            self.left -= 1;
+        }
+        Ok(byte)
+    }
+
+    #[inline]
+    fn try_peek_slice(&mut self, buf: &mut [u8]) -> Result<bool, Self::Err> {
+        if buf.len() > self.left {
+            return Err(LimitErr::Exceeded);
+        }
+        Ok(self.source.try_peek_slice(buf)?)
+    }
+
+    #[inline]
+    fn skip(&mut self, count: usize) -> Result<(), Self::Err> {
+        self.consume(count)?;
+        Ok(self.source.skip(count)?)
+    }
+
+    #[inline]
+    fn remaining(&self) -> Option<usize> {
+        Some(
+            self.source
+                .remaining()
+                .map_or(self.left, |remaining| remaining.min(self.left)),
+        )
+    }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        self.source.offset()
+    }
+}
+
+impl<B> PeekSource for Limited<B>
+where
+    B: PeekSource,
+{
+    #[inline]
+    fn peek_slice(&mut self, buf: &mut [u8]) -> Result<(), Self::Err> {
+        if buf.len() > self.left {
+            return Err(LimitErr::Exceeded);
+        }
+        Ok(self.source.peek_slice(buf)?)
+    }
+}
+
+impl<'src, B> BorrowSource<'src> for Limited<B>
+where
+    B: BorrowSource<'src>,
+{
+    #[inline]
+    fn borrow_slice(&mut self, len: usize) -> Result<&'src [u8], Self::Err> {
+        self.consume(len)?;
+        Ok(self.source.borrow_slice(len)?)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<B> Limited<B>
+where
+    B: crate::AsyncByteSource,
+{
+    /// Finish reading from limited async source, handling leftover bytes according to policy.
+    ///
+    /// # Errors
+    /// If leftover bytes are not allowed, or cannot be skipped.
+    #[inline]
+    pub async fn finish_async(&mut self, leftover: Leftover) -> Result<(), LimitErr<B::Err>> {
+        let skip = self.leftover(leftover)?;
+        Ok(self.source.skip(skip).await?)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<B> crate::AsyncByteSource for Limited<B>
+where
+    B: crate::AsyncByteSource,
+{
+    type Err = LimitErr<B::Err>;
+
+    #[inline]
+    async fn read_slice(&mut self, buf: &mut [u8]) -> Result<(), Self::Err> {
+        self.consume(buf.len())?;
+        Ok(self.source.read_slice(buf).await?)
+    }
+
+    #[inline]
+    async fn read_byte_or_eof(&mut self) -> Result<Option<u8>, Self::Err> {
+        if self.left == 0 {
+            return Ok(None);
+        }
+        let byte = self.source.read_byte_or_eof().await?;
+        if byte.is_some() {
+            self.left -= 1;
+        }
+        Ok(byte)
+    }
+
+    #[inline]
+    async fn skip(&mut self, count: usize) -> Result<(), Self::Err> {
+        self.consume(count)?;
+        Ok(self.source.skip(count).await?)
+    }
+
+    #[inline]
+    fn remaining(&self) -> Option<usize> {
+        Some(
+            self.source
+                .remaining()
+                .map_or(self.left, |remaining| remaining.min(self.left)),
+        )
+    }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        self.source.offset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    use crate::{error::SliceSrcEmpty, SliceSrc};
+
+    use super::*;
+
+    #[test]
+    fn limited() {
+        let mut source = SliceSrc::new(b"0123456789");
+        let mut limited = Limited::new(&mut source, 6u32);
+
+        assert_eq!(limited.remaining(), Some(6));
+        assert_eq!(limited.read_array::<4>(), Ok(*b"0123"));
+        assert_eq!(limited.read_array::<4>(), Err(LimitErr::Exceeded));
+        assert_eq!(limited.peek_array::<2>(), Ok(*b"45"));
+        assert_eq!(limited.left(), 2);
+        assert_eq!(limited.finish(Leftover::Error), Err(LimitErr::Leftover(2)));
+        assert_eq!(limited.finish(Leftover::Keep), Ok(()));
+        assert_eq!(limited.finish(Leftover::Skip), Ok(()));
+        assert_eq!(limited.finish(Leftover::Error), Ok(()));
+        assert_eq!(source.read_byte(), Ok(b'6'));
+
+        let mut limited = Limited::new(&mut source, 16u64);
+        assert_eq!(limited.remaining(), Some(3));
+        assert_eq!(limited.read_array::<3>(), Ok(*b"789"));
+        assert_eq!(limited.read_byte(), Err(LimitErr::Inner(SliceSrcEmpty)));
+    }
+}
@@ -0,0 +1,200 @@
+//! [LimitedSink] impl.
+
+use crate::{
+    adapter::{IntoLimit, Leftover},
+    error::LimitErr,
+    ByteSink,
+};
+
+/// [ByteSink] adapter restricting wrapped sink to a limited amount of bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LimitedSink<B> {
+    /// Wrapped sink.
+    sink: B,
+
+    /// Bytes left within limit.
+    left: usize,
+}
+
+impl<B> LimitedSink<B> {
+    /// Construct a new [LimitedSink] allowing at most limit bytes to be written to sink.
+    #[inline]
+    pub fn new(sink: B, limit: impl IntoLimit) -> Self {
+        Self {
+            sink,
+            left: limit.into_limit(),
+        }
+    }
+
+    /// Get amount of bytes left within limit.
+    #[inline]
+    pub const fn left(&self) -> usize {
+        self.left
+    }
+
+    /// Get wrapped sink.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.sink
+    }
+
+    /// Use count bytes of limit.
+    ///
+    /// # Errors
+    /// If count is larger than what is left of limit.
+    #[inline]
+    const fn consume<E>(&mut self, count: usize) -> Result<(), LimitErr<E>> {
+        if count > self.left {
+            return Err(LimitErr::Exceeded);
+        }
+        self.left -= count;
+        Ok(())
+    }
+
+    /// Check if there are bytes left within limit, using leftover policy.
+    ///
+    /// # Returns
+    /// Amount of zeroes that should be written.
+    ///
+    /// # Errors
+    /// If bytes are left and they are not allowed to be.
+    #[inline]
+    const fn leftover<E>(&mut self, leftover: Leftover) -> Result<usize, LimitErr<E>> {
+        let left = self.left;
+        match leftover {
+            Leftover::Error if left != 0 => Err(LimitErr::Leftover(left)),
+            Leftover::Error | Leftover::Keep => Ok(0),
+            Leftover::Skip => {
+                self.left = 0;
+                Ok(left)
+            }
+        }
+    }
+}
+
+/// Zeroes written in chunks when padding.
+pub(super) const ZEROES: [u8; 64] = [0u8; 64];
+
+impl<B> LimitedSink<B>
+where
+    B: ByteSink,
+{
+    /// Finish writing to limited sink, handling leftover bytes according to policy.
+    ///
+    /// # Errors
+    /// If leftover bytes are not allowed, or cannot be written.
+    #[inline]
+    pub fn finish(&mut self, leftover: Leftover) -> Result<(), LimitErr<B::Err>> {
+        let mut pad = self.leftover(leftover)?;
+        while pad != 0 {
+            let len = pad.min(ZEROES.len());
+            self.sink.write_slice(&ZEROES[..len])?;
+            pad -= len;
+        }
+        Ok(())
+    }
+}
+
+impl<B> ByteSink for LimitedSink<B>
+where
+    B: ByteSink,
+{
+    type Err = LimitErr<B::Err>;
+
+    #[inline]
+    fn write_slice(&mut self, buf: &[u8]) -> Result<(), Self::Err> {
+        self.consume(buf.len())?;
+        Ok(self.sink.write_slice(buf)?)
+    }
+
+    #[inline]
+    fn write_array<const N: usize>(&mut self, bytes: [u8; N]) -> Result<(), Self::Err> {
+        self.consume(N)?;
+        Ok(self.sink.write_array(bytes)?)
+    }
+
+    #[inline]
+    fn write_byte(&mut self, byte: u8) -> Result<(), Self::Err> {
+        self.consume(1)?;
+        Ok(self.sink.write_byte(byte)?)
+    }
+
+    #[inline]
+    fn remaining(&self) -> Option<usize> {
+        Some(
+            self.sink
+                .remaining()
+                .map_or(self.left, |remaining| remaining.min(self.left)),
+        )
+    }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        self.sink.offset()
+    }
+}
+
+#[cfg(feature = "async")]
+impl<B> LimitedSink<B>
+where
+    B: crate::AsyncByteSink,
+{
+    /// Finish writing to limited async sink, handling leftover bytes according to policy.
+    ///
+    /// # Errors
+    /// If leftover bytes are not allowed, or cannot be written.
+    #[inline]
+    pub async fn finish_async(&mut self, leftover: Leftover) -> Result<(), LimitErr<B::Err>> {
+        let mut pad = self.leftover(leftover)?;
+        while pad != 0 {
+            let len = pad.min(ZEROES.len());
+            self.sink.write_slice(&ZEROES[..len]).await?;
+            pad -= len;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<B> crate::AsyncByteSink for LimitedSink<B>
+where
+    B: crate::AsyncByteSink,
+{
+    type Err = LimitErr<B::Err>;
+
+    #[inline]
+    async fn write_slice(&mut self, buf: &[u8]) -> Result<(), Self::Err> {
+        self.consume(buf.len())?;
+        Ok(self.sink.write_slice(buf).await?)
+    }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        self.sink.offset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    use crate::SliceSink;
+
+    use super::*;
+
+    #[test]
+    fn limited_sink() {
+        let mut buf = [0xffu8; 8];
+        let mut sink = SliceSink::new(&mut buf);
+        let mut limited = LimitedSink::new(&mut sink, 6usize);
+
+        assert_eq!(limited.remaining(), Some(6));
+        assert_eq!(limited.write_array(*b"abcd"), Ok(()));
+        assert_eq!(limited.write_array(*b"efg"), Err(LimitErr::Exceeded));
+        assert_eq!(limited.finish(Leftover::Error), Err(LimitErr::Leftover(2)));
+        assert_eq!(limited.finish(Leftover::Skip), Ok(()));
+        assert_eq!(limited.left(), 0);
+        assert_eq!(sink.write_byte(b'!'), Ok(()));
+        assert_eq!(&buf, b"abcd\0\0!\xff");
+    }
+}
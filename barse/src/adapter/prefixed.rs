@@ -0,0 +1,244 @@
+//! [Prefixed] impl.
+
+use crate::{ByteSource, Error, GuardedSource, PeekSource, SeekSource};
+
+/// [ByteSource] adapter yielding bytes of a prefix before those of the wrapped source.
+///
+/// Used to make bytes that have already been read available again, such as those a variant was
+/// selected by.
+#[derive(Debug)]
+pub struct Prefixed<'p, B> {
+    /// Bytes yielded before those of source.
+    prefix: &'p [u8],
+
+    /// Wrapped source.
+    source: B,
+}
+
+impl<'p, B> Prefixed<'p, B> {
+    /// Construct a new [Prefixed] yielding prefix then bytes of source.
+    #[inline]
+    pub const fn new(prefix: &'p [u8], source: B) -> Self {
+        Self { prefix, source }
+    }
+
+    /// Get bytes of prefix that have not yet been read.
+    #[inline]
+    pub const fn prefix(&self) -> &'p [u8] {
+        self.prefix
+    }
+
+    /// Get wrapped source, any unread bytes of prefix are lost.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.source
+    }
+
+    /// Take up to count bytes of prefix.
+    #[inline]
+    fn take_prefix(&mut self, count: usize) -> &'p [u8] {
+        let (taken, rest) = self.prefix.split_at(count.min(self.prefix.len()));
+        self.prefix = rest;
+        taken
+    }
+
+    /// Offset of head given the offset of source.
+    #[inline]
+    fn offset_from(&self, offset: Option<u64>) -> Option<u64> {
+        offset?.checked_sub(self.prefix.len() as u64)
+    }
+}
+
+impl<B> ByteSource for Prefixed<'_, B>
+where
+    B: ByteSource,
+{
+    type Err = B::Err;
+
+    #[inline]
+    fn read_slice(&mut self, buf: &mut [u8]) -> Result<(), Self::Err> {
+        let taken = self.take_prefix(buf.len());
+        let (head, tail) = buf.split_at_mut(taken.len());
+        head.copy_from_slice(taken);
+        if tail.is_empty() {
+            Ok(())
+        } else {
+            self.source.read_slice(tail)
+        }
+    }
+
+    #[inline]
+    fn read_byte_or_eof(&mut self) -> Result<Option<u8>, Self::Err> {
+        match self.take_prefix(1) {
+            [byte] => Ok(Some(*byte)),
+            _ => self.source.read_byte_or_eof(),
+        }
+    }
+
+    #[inline]
+    fn try_peek_slice(&mut self, buf: &mut [u8]) -> Result<bool, Self::Err> {
+        let len = buf.len().min(self.prefix.len());
+        let (head, tail) = buf.split_at_mut(len);
+        if tail.is_empty() || self.source.try_peek_slice(tail)? {
+            head.copy_from_slice(&self.prefix[..len]);
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    #[inline]
+    fn skip(&mut self, count: usize) -> Result<(), Self::Err> {
+        let taken = self.take_prefix(count).len();
+        if taken == count {
+            Ok(())
+        } else {
+            self.source.skip(count - taken)
+        }
+    }
+
+    #[inline]
+    fn remaining(&self) -> Option<usize> {
+        self.source.remaining()?.checked_add(self.prefix.len())
+    }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        self.offset_from(self.source.offset())
+    }
+}
+
+impl<B> PeekSource for Prefixed<'_, B>
+where
+    B: PeekSource,
+{
+    #[inline]
+    fn peek_slice(&mut self, buf: &mut [u8]) -> Result<(), Self::Err> {
+        let len = buf.len().min(self.prefix.len());
+        let (head, tail) = buf.split_at_mut(len);
+        head.copy_from_slice(&self.prefix[..len]);
+        if tail.is_empty() {
+            Ok(())
+        } else {
+            self.source.peek_slice(tail)
+        }
+    }
+}
+
+/// Seeking discards the prefix, the position of head is that of source less unread prefix bytes.
+impl<B> SeekSource for Prefixed<'_, B>
+where
+    B: SeekSource,
+{
+    #[inline]
+    fn position(&mut self) -> Result<u64, Self::Err> {
+        Ok(self
+            .source
+            .position()?
+            .saturating_sub(self.prefix.len() as u64))
+    }
+
+    #[inline]
+    fn seek_to(&mut self, pos: u64) -> Result<(), Self::Err> {
+        self.source.seek_to(pos)?;
+        self.prefix = &[];
+        Ok(())
+    }
+}
+
+impl<B> GuardedSource for Prefixed<'_, B>
+where
+    B: GuardedSource,
+{
+    #[inline]
+    fn enter_ptr(&mut self, pos: u64) -> Result<(), Error> {
+        self.source.enter_ptr(pos)
+    }
+
+    #[inline]
+    fn leave_ptr(&mut self, pos: u64) {
+        self.source.leave_ptr(pos);
+    }
+}
+
+#[cfg(feature = "async")]
+impl<B> crate::AsyncByteSource for Prefixed<'_, B>
+where
+    B: crate::AsyncByteSource,
+{
+    type Err = B::Err;
+
+    #[inline]
+    async fn read_slice(&mut self, buf: &mut [u8]) -> Result<(), Self::Err> {
+        let taken = self.take_prefix(buf.len());
+        let (head, tail) = buf.split_at_mut(taken.len());
+        head.copy_from_slice(taken);
+        if tail.is_empty() {
+            Ok(())
+        } else {
+            self.source.read_slice(tail).await
+        }
+    }
+
+    #[inline]
+    async fn read_byte_or_eof(&mut self) -> Result<Option<u8>, Self::Err> {
+        match self.take_prefix(1) {
+            [byte] => Ok(Some(*byte)),
+            _ => self.source.read_byte_or_eof().await,
+        }
+    }
+
+    #[inline]
+    async fn skip(&mut self, count: usize) -> Result<(), Self::Err> {
+        let taken = self.take_prefix(count).len();
+        if taken == count {
+            Ok(())
+        } else {
+            self.source.skip(count - taken).await
+        }
+    }
+
+    #[inline]
+    fn remaining(&self) -> Option<usize> {
+        self.source.remaining()?.checked_add(self.prefix.len())
+    }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        self.offset_from(self.source.offset())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    use crate::{error::SliceSrcEmpty, SliceSrc};
+
+    use super::*;
+
+    #[test]
+    fn prefixed() {
+        let mut source = SliceSrc::new(b"0123456789");
+        let peeked = source.read_array::<4>().unwrap();
+        let mut prefixed = Prefixed::new(&peeked, &mut source);
+
+        assert_eq!(prefixed.offset(), Some(0));
+        assert_eq!(prefixed.read_array::<3>(), Ok(*b"012"));
+        assert_eq!(prefixed.remaining(), Some(7));
+        assert_eq!(prefixed.peek_array::<3>(), Ok(*b"345"));
+        let mut peeked = [0u8; 2];
+        assert_eq!(prefixed.try_peek_slice(&mut peeked), Ok(true));
+        assert_eq!(&peeked, b"34");
+        assert_eq!(prefixed.read_array::<3>(), Ok(*b"345"));
+        assert_eq!(prefixed.offset(), Some(6));
+        assert_eq!(prefixed.skip(2), Ok(()));
+        assert_eq!(prefixed.read_byte(), Ok(b'8'));
+        assert_eq!(prefixed.read_array::<2>(), Err(SliceSrcEmpty));
+
+        let mut prefixed = Prefixed::new(b"ab", SliceSrc::new(b"cd"));
+        assert_eq!(prefixed.skip(3), Ok(()));
+        assert_eq!(prefixed.read_byte(), Ok(b'd'));
+        assert!(prefixed.prefix().is_empty());
+    }
+}
@@ -0,0 +1,186 @@
+//! [PtrGuard] impl.
+
+use crate::{
+    error::PtrCycle, BorrowSource, ByteSource, Error, GuardedSource, PeekSource, SeekSource,
+};
+
+/// [SeekSource] adapter keeping track of positions being read through pointers, such that
+/// pointer cycles are detected instead of recursing forever.
+///
+/// At most DEPTH pointers may be followed at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PtrGuard<B, const DEPTH: usize = 32> {
+    /// Wrapped source.
+    source: B,
+
+    /// Positions being read through pointers.
+    followed: [u64; DEPTH],
+
+    /// Amount of pointers being followed.
+    depth: usize,
+}
+
+impl<B, const DEPTH: usize> PtrGuard<B, DEPTH> {
+    /// Construct a new [PtrGuard] tracking pointers followed in source.
+    #[inline]
+    pub const fn new(source: B) -> Self {
+        Self {
+            source,
+            followed: [0; DEPTH],
+            depth: 0,
+        }
+    }
+
+    /// Get amount of pointers currently being followed.
+    #[inline]
+    pub const fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Get wrapped source.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.source
+    }
+}
+
+impl<B, const DEPTH: usize> ByteSource for PtrGuard<B, DEPTH>
+where
+    B: ByteSource,
+{
+    type Err = B::Err;
+
+    #[inline]
+    fn read_slice(&mut self, buf: &mut [u8]) -> Result<(), Self::Err> {
+        self.source.read_slice(buf)
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], Self::Err> {
+        self.source.read_array()
+    }
+
+    #[inline]
+    fn read_byte(&mut self) -> Result<u8, Self::Err> {
+        self.source.read_byte()
+    }
+
+    #[inline]
+    fn read_byte_or_eof(&mut self) -> Result<Option<u8>, Self::Err> {
+        self.source.read_byte_or_eof()
+    }
+
+    #[inline]
+    fn try_peek_slice(&mut self, buf: &mut [u8]) -> Result<bool, Self::Err> {
+        self.source.try_peek_slice(buf)
+    }
+
+    #[inline]
+    fn skip(&mut self, count: usize) -> Result<(), Self::Err> {
+        self.source.skip(count)
+    }
+
+    #[inline]
+    fn remaining(&self) -> Option<usize> {
+        self.source.remaining()
+    }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        self.source.offset()
+    }
+}
+
+impl<B, const DEPTH: usize> PeekSource for PtrGuard<B, DEPTH>
+where
+    B: PeekSource,
+{
+    #[inline]
+    fn peek_slice(&mut self, buf: &mut [u8]) -> Result<(), Self::Err> {
+        self.source.peek_slice(buf)
+    }
+}
+
+impl<'src, B, const DEPTH: usize> BorrowSource<'src> for PtrGuard<B, DEPTH>
+where
+    B: BorrowSource<'src>,
+{
+    #[inline]
+    fn borrow_slice(&mut self, len: usize) -> Result<&'src [u8], Self::Err> {
+        self.source.borrow_slice(len)
+    }
+}
+
+impl<B, const DEPTH: usize> SeekSource for PtrGuard<B, DEPTH>
+where
+    B: SeekSource,
+{
+    #[inline]
+    fn position(&mut self) -> Result<u64, Self::Err> {
+        self.source.position()
+    }
+
+    #[inline]
+    fn seek_to(&mut self, pos: u64) -> Result<(), Self::Err> {
+        self.source.seek_to(pos)
+    }
+}
+
+impl<B, const DEPTH: usize> GuardedSource for PtrGuard<B, DEPTH>
+where
+    B: SeekSource,
+{
+    #[inline]
+    fn enter_ptr(&mut self, pos: u64) -> Result<(), Error> {
+        if self.followed[..self.depth].contains(&pos) {
+            return Err(Error::PtrCycle(PtrCycle { position: pos }));
+        }
+        if self.depth == DEPTH {
+            return Err(Error::Msg("pointers nested too deeply to be tracked"));
+        }
+        self.followed[self.depth] = pos;
+        self.depth += 1;
+        Ok(())
+    }
+
+    #[inline]
+    fn leave_ptr(&mut self, pos: u64) {
+        if let Some(depth) = self.followed[..self.depth]
+            .iter()
+            .rposition(|followed| *followed == pos)
+        {
+            self.followed.copy_within(depth + 1..self.depth, depth);
+            self.depth -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    use crate::SliceSrc;
+
+    use super::*;
+
+    #[test]
+    fn ptr_guard() {
+        let mut source = PtrGuard::<_, 2>::new(SliceSrc::new(b"0123"));
+
+        assert!(source.enter_ptr(1).is_ok());
+        assert!(matches!(
+            source.enter_ptr(1),
+            Err(Error::PtrCycle(PtrCycle { position: 1 }))
+        ));
+        assert!(source.enter_ptr(2).is_ok());
+        assert!(source.enter_ptr(3).is_err());
+        source.leave_ptr(1);
+        assert_eq!(source.depth(), 1);
+        assert!(source.enter_ptr(1).is_ok());
+        source.leave_ptr(1);
+        source.leave_ptr(2);
+        assert_eq!(source.depth(), 0);
+        assert_eq!(source.seek_to(2), Ok(()));
+        assert_eq!(source.read_byte(), Ok(b'2'));
+    }
+}
@@ -0,0 +1,311 @@
+//! [Tracked] impl.
+
+use crate::{BorrowSource, ByteSource, Error, GuardedSource, PeekSource, SeekSource};
+
+/// Get amount of bytes needed to move position to next multiple of align.
+///
+/// An align of zero never needs any padding.
+#[inline]
+pub const fn padding_to(position: usize, align: usize) -> usize {
+    match position.checked_rem(align) {
+        Some(0) | None => 0,
+        Some(rem) => align - rem,
+    }
+}
+
+/// Get tracked position after head has been moved from one absolute position to another.
+///
+/// The distance moved saturates should it not fit in a usize.
+#[inline]
+pub(crate) fn moved_position(position: usize, from: u64, to: u64) -> usize {
+    if to >= from {
+        position.saturating_add(usize::try_from(to - from).unwrap_or(usize::MAX))
+    } else {
+        position.saturating_sub(usize::try_from(from - to).unwrap_or(usize::MAX))
+    }
+}
+
+/// Get position to start tracking at from the offset of a source or sink, such that alignment is
+/// relative to the offset instead of the start of a record.
+///
+/// # Errors
+/// If offset is not known, or does not fit in a usize.
+#[inline]
+pub fn offset_position(offset: Option<u64>) -> Result<usize, Error> {
+    offset
+        .and_then(|offset| usize::try_from(offset).ok())
+        .ok_or(Error::Msg(
+            "alignment relative to offset requires a known offset",
+        ))
+}
+
+/// [ByteSource] adapter keeping track of how many bytes have been read, or skipped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Tracked<B> {
+    /// Wrapped source.
+    source: B,
+
+    /// Bytes read so far.
+    position: usize,
+}
+
+impl<B> Tracked<B> {
+    /// Construct a new [Tracked] counting bytes read from source, starting at zero.
+    #[inline]
+    pub const fn new(source: B) -> Self {
+        Self::with_position(source, 0)
+    }
+
+    /// Construct a new [Tracked] counting bytes read from source, starting at position.
+    #[inline]
+    pub const fn with_position(source: B, position: usize) -> Self {
+        Self { source, position }
+    }
+
+    /// Get amount of bytes read so far.
+    ///
+    /// Named apart from [SeekSource::position], which is the position of the wrapped source.
+    #[inline]
+    pub const fn tracked_position(&self) -> usize {
+        self.position
+    }
+
+    /// Get amount of bytes needed to reach next multiple of align.
+    #[inline]
+    pub const fn padding_to(&self, align: usize) -> usize {
+        padding_to(self.position, align)
+    }
+
+    /// Get wrapped source.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.source
+    }
+
+    /// Count bytes as read.
+    #[inline]
+    const fn advance(&mut self, count: usize) {
+        self.position = self.position.saturating_add(count);
+    }
+}
+
+impl<B> Tracked<B>
+where
+    B: ByteSource,
+{
+    /// Skip bytes until position is a multiple of align.
+    ///
+    /// # Errors
+    /// If bytes cannot be skipped.
+    #[inline]
+    pub fn align(&mut self, align: usize) -> Result<(), B::Err> {
+        ByteSource::skip(self, self.padding_to(align))
+    }
+}
+
+impl<B> ByteSource for Tracked<B>
+where
+    B: ByteSource,
+{
+    type Err = B::Err;
+
+    #[inline]
+    fn read_slice(&mut self, buf: &mut [u8]) -> Result<(), Self::Err> {
+        self.source.read_slice(buf)?;
+        self.advance(buf.len());
+        Ok(())
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], Self::Err> {
+        let bytes = self.source.read_array()?;
+        self.advance(N);
+        Ok(bytes)
+    }
+
+    #[inline]
+    fn read_byte(&mut self) -> Result<u8, Self::Err> {
+        let byte = self.source.read_byte()?;
+        self.advance(1);
+        Ok(byte)
+    }
+
+    #[inline]
+    fn read_byte_or_eof(&mut self) -> Result<Option<u8>, Self::Err> {
+        let byte = self.source.read_byte_or_eof()?;
+        if byte.is_some() {
+            self.advance(1);
+        }
+        Ok(byte)
+    }
+
+    #[inline]
+    fn try_peek_slice(&mut self, buf: &mut [u8]) -> Result<bool, Self::Err> {
+        self.source.try_peek_slice(buf)
+    }
+
+    #[inline]
+    fn skip(&mut self, count: usize) -> Result<(), Self::Err> {
+        self.source.skip(count)?;
+        self.advance(count);
+        Ok(())
+    }
+
+    #[inline]
+    fn remaining(&self) -> Option<usize> {
+        self.source.remaining()
+    }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        self.source.offset()
+    }
+}
+
+impl<B> PeekSource for Tracked<B>
+where
+    B: PeekSource,
+{
+    #[inline]
+    fn peek_slice(&mut self, buf: &mut [u8]) -> Result<(), Self::Err> {
+        self.source.peek_slice(buf)
+    }
+}
+
+impl<'src, B> BorrowSource<'src> for Tracked<B>
+where
+    B: BorrowSource<'src>,
+{
+    #[inline]
+    fn borrow_slice(&mut self, len: usize) -> Result<&'src [u8], Self::Err> {
+        let bytes = self.source.borrow_slice(len)?;
+        self.advance(len);
+        Ok(bytes)
+    }
+}
+
+/// Position moves along with head when seeking, such that bytes read elsewhere are not counted.
+impl<B> SeekSource for Tracked<B>
+where
+    B: SeekSource,
+{
+    #[inline]
+    fn position(&mut self) -> Result<u64, Self::Err> {
+        self.source.position()
+    }
+
+    #[inline]
+    fn seek_to(&mut self, pos: u64) -> Result<(), Self::Err> {
+        let from = self.source.position()?;
+        self.source.seek_to(pos)?;
+        self.position = moved_position(self.position, from, pos);
+        Ok(())
+    }
+}
+
+impl<B> GuardedSource for Tracked<B>
+where
+    B: GuardedSource,
+{
+    #[inline]
+    fn enter_ptr(&mut self, pos: u64) -> Result<(), Error> {
+        self.source.enter_ptr(pos)
+    }
+
+    #[inline]
+    fn leave_ptr(&mut self, pos: u64) {
+        self.source.leave_ptr(pos);
+    }
+}
+
+#[cfg(feature = "async")]
+impl<B> Tracked<B>
+where
+    B: crate::AsyncByteSource,
+{
+    /// Skip bytes of async source until position is a multiple of align.
+    ///
+    /// # Errors
+    /// If bytes cannot be skipped.
+    #[inline]
+    pub async fn align_async(&mut self, align: usize) -> Result<(), B::Err> {
+        crate::AsyncByteSource::skip(self, self.padding_to(align)).await
+    }
+}
+
+#[cfg(feature = "async")]
+impl<B> crate::AsyncByteSource for Tracked<B>
+where
+    B: crate::AsyncByteSource,
+{
+    type Err = B::Err;
+
+    #[inline]
+    async fn read_slice(&mut self, buf: &mut [u8]) -> Result<(), Self::Err> {
+        self.source.read_slice(buf).await?;
+        self.advance(buf.len());
+        Ok(())
+    }
+
+    #[inline]
+    async fn read_byte_or_eof(&mut self) -> Result<Option<u8>, Self::Err> {
+        let byte = self.source.read_byte_or_eof().await?;
+        if byte.is_some() {
+            self.advance(1);
+        }
+        Ok(byte)
+    }
+
+    #[inline]
+    async fn skip(&mut self, count: usize) -> Result<(), Self::Err> {
+        self.source.skip(count).await?;
+        self.advance(count);
+        Ok(())
+    }
+
+    #[inline]
+    fn remaining(&self) -> Option<usize> {
+        self.source.remaining()
+    }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        self.source.offset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    use crate::SliceSrc;
+
+    use super::*;
+
+    #[test]
+    fn tracked() {
+        let mut source = Tracked::with_position(SliceSrc::new(b"0123456789"), 1);
+
+        assert_eq!(source.peek_array::<2>(), Ok(*b"01"));
+        assert_eq!(source.read_array::<3>(), Ok(*b"012"));
+        assert_eq!(source.tracked_position(), 4);
+        assert_eq!(source.padding_to(4), 0);
+        assert_eq!(source.padding_to(0), 0);
+        assert_eq!(source.read_byte(), Ok(b'3'));
+        assert_eq!(source.padding_to(4), 3);
+        assert_eq!(source.align(4), Ok(()));
+        assert_eq!(source.tracked_position(), 8);
+        assert_eq!(source.borrow_slice(2), Ok(&b"78"[..]));
+        assert_eq!(source.tracked_position(), 10);
+        assert_eq!(source.seek_to(2), Ok(()));
+        assert_eq!(source.read_byte(), Ok(b'2'));
+        assert_eq!(source.seek_to(9), Ok(()));
+        assert_eq!(source.tracked_position(), 10);
+
+        // Seeking before where tracking started saturates.
+        let mut source = Tracked::new(source.into_inner());
+        assert_eq!(source.seek_to(0), Ok(()));
+        assert_eq!(source.tracked_position(), 0);
+        assert_eq!(source.position(), Ok(0));
+    }
+}
@@ -0,0 +1,200 @@
+//! [TrackedSink] impl.
+
+use crate::{
+    adapter::{limited_sink::ZEROES, padding_to, tracked::moved_position},
+    ByteSink, SeekSink,
+};
+
+/// [ByteSink] adapter keeping track of how many bytes have been written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TrackedSink<B> {
+    /// Wrapped sink.
+    sink: B,
+
+    /// Bytes written so far.
+    position: usize,
+}
+
+impl<B> TrackedSink<B> {
+    /// Construct a new [TrackedSink] counting bytes written to sink, starting at zero.
+    #[inline]
+    pub const fn new(sink: B) -> Self {
+        Self::with_position(sink, 0)
+    }
+
+    /// Construct a new [TrackedSink] counting bytes written to sink, starting at position.
+    #[inline]
+    pub const fn with_position(sink: B, position: usize) -> Self {
+        Self { sink, position }
+    }
+
+    /// Get amount of bytes written so far.
+    ///
+    /// Named apart from [SeekSink::position], which is the position of the wrapped sink.
+    #[inline]
+    pub const fn tracked_position(&self) -> usize {
+        self.position
+    }
+
+    /// Get amount of bytes needed to reach next multiple of align.
+    #[inline]
+    pub const fn padding_to(&self, align: usize) -> usize {
+        padding_to(self.position, align)
+    }
+
+    /// Get wrapped sink.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.sink
+    }
+
+    /// Count bytes as written.
+    #[inline]
+    const fn advance(&mut self, count: usize) {
+        self.position = self.position.saturating_add(count);
+    }
+}
+
+impl<B> TrackedSink<B>
+where
+    B: ByteSink,
+{
+    /// Write zeroes until position is a multiple of align.
+    ///
+    /// # Errors
+    /// If zeroes cannot be written.
+    #[inline]
+    pub fn align(&mut self, align: usize) -> Result<(), B::Err> {
+        let mut pad = self.padding_to(align);
+        while pad != 0 {
+            let len = pad.min(ZEROES.len());
+            ByteSink::write_slice(self, &ZEROES[..len])?;
+            pad -= len;
+        }
+        Ok(())
+    }
+}
+
+impl<B> ByteSink for TrackedSink<B>
+where
+    B: ByteSink,
+{
+    type Err = B::Err;
+
+    #[inline]
+    fn write_slice(&mut self, buf: &[u8]) -> Result<(), Self::Err> {
+        self.sink.write_slice(buf)?;
+        self.advance(buf.len());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_array<const N: usize>(&mut self, bytes: [u8; N]) -> Result<(), Self::Err> {
+        self.sink.write_array(bytes)?;
+        self.advance(N);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_byte(&mut self, byte: u8) -> Result<(), Self::Err> {
+        self.sink.write_byte(byte)?;
+        self.advance(1);
+        Ok(())
+    }
+
+    #[inline]
+    fn remaining(&self) -> Option<usize> {
+        self.sink.remaining()
+    }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        self.sink.offset()
+    }
+}
+
+/// Position moves along with head when seeking, such that bytes written elsewhere are not counted.
+impl<B> SeekSink for TrackedSink<B>
+where
+    B: SeekSink,
+{
+    #[inline]
+    fn position(&mut self) -> Result<u64, Self::Err> {
+        self.sink.position()
+    }
+
+    #[inline]
+    fn seek_to(&mut self, pos: u64) -> Result<(), Self::Err> {
+        let from = self.sink.position()?;
+        self.sink.seek_to(pos)?;
+        self.position = moved_position(self.position, from, pos);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<B> TrackedSink<B>
+where
+    B: crate::AsyncByteSink,
+{
+    /// Write zeroes to async sink until position is a multiple of align.
+    ///
+    /// # Errors
+    /// If zeroes cannot be written.
+    #[inline]
+    pub async fn align_async(&mut self, align: usize) -> Result<(), B::Err> {
+        let mut pad = self.padding_to(align);
+        while pad != 0 {
+            let len = pad.min(ZEROES.len());
+            crate::AsyncByteSink::write_slice(self, &ZEROES[..len]).await?;
+            pad -= len;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<B> crate::AsyncByteSink for TrackedSink<B>
+where
+    B: crate::AsyncByteSink,
+{
+    type Err = B::Err;
+
+    #[inline]
+    async fn write_slice(&mut self, buf: &[u8]) -> Result<(), Self::Err> {
+        self.sink.write_slice(buf).await?;
+        self.advance(buf.len());
+        Ok(())
+    }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        self.sink.offset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    use crate::SliceSink;
+
+    use super::*;
+
+    #[test]
+    fn tracked_sink() {
+        let mut buf = [0xffu8; 8];
+        let mut sink = TrackedSink::new(SliceSink::new(&mut buf));
+
+        assert_eq!(sink.write_array(*b"abc"), Ok(()));
+        assert_eq!(sink.padding_to(4), 1);
+        assert_eq!(sink.align(4), Ok(()));
+        assert_eq!(sink.tracked_position(), 4);
+        assert_eq!(sink.align(4), Ok(()));
+        assert_eq!(sink.write_byte(b'd'), Ok(()));
+        assert_eq!(sink.align(8), Ok(()));
+        assert!(sink.write_byte(b'!').is_err());
+        assert_eq!(sink.tracked_position(), 8);
+        assert_eq!(&buf, b"abc\0d\0\0\0");
+    }
+}
@@ -1,8 +1,8 @@
 //! [Barse] trait and implementations.
 
-use ::core::marker::PhantomData;
+use ::core::{convert::Infallible, marker::PhantomData};
 
-use crate::{ByteSink, ByteSource, Endian, WrappedErr};
+use crate::{endian::Native, ByteSink, ByteSource, CountingSink, Endian, WrappedErr};
 
 /// Trait to serialize and deserialize from binary data.
 pub trait Barse: Sized {
@@ -29,6 +29,18 @@ pub trait Barse: Sized {
     where
         E: Endian,
         B: ByteSink;
+
+    /// Get amount of bytes an instance writes.
+    ///
+    /// By default the instance is written with native endianess to a [CountingSink].
+    ///
+    /// # Errors
+    /// If implementation errors.
+    fn encoded_len(&self, with: Self::WriteWith) -> Result<usize, WrappedErr<Infallible>> {
+        let mut sink = CountingSink::new();
+        self.write_with::<Native, _>(&mut sink, with)?;
+        Ok(usize::try_from(sink.count()).unwrap_or(usize::MAX))
+    }
 }
 
 integer_impl!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
@@ -48,8 +60,8 @@ where
         B: ByteSource,
     {
         let mut values = [const { None }; N];
-        for value in values.iter_mut() {
-            *value = Some(T::read_with::<E, B>(from, with.clone())?);
+        for (i, value) in values.iter_mut().enumerate() {
+            *value = Some(T::read_with::<E, B>(from, with.clone()).map_err(|err| err.at_index(i))?);
         }
         Ok(values.map(|value| value.expect("all values should be some")))
     }
@@ -59,11 +71,18 @@ where
         E: Endian,
         B: ByteSink,
     {
-        for value in self {
-            T::write_with::<E, B>(value, to, with.clone())?;
+        for (i, value) in self.iter().enumerate() {
+            T::write_with::<E, B>(value, to, with.clone()).map_err(|err| err.at_index(i))?;
         }
         Ok(())
     }
+
+    fn encoded_len(&self, with: Self::WriteWith) -> Result<usize, WrappedErr<Infallible>> {
+        self.iter().enumerate().try_fold(0usize, |len, (i, value)| {
+            let value_len = T::encoded_len(value, with.clone()).map_err(|err| err.at_index(i))?;
+            Ok(len.saturating_add(value_len))
+        })
+    }
 }
 
 impl Barse for () {
@@ -90,6 +109,11 @@ impl Barse for () {
     {
         Ok(())
     }
+
+    #[inline]
+    fn encoded_len(&self, _with: Self::WriteWith) -> Result<usize, WrappedErr<Infallible>> {
+        Ok(0)
+    }
 }
 
 impl<T> Barse for PhantomData<T> {
@@ -116,6 +140,11 @@ impl<T> Barse for PhantomData<T> {
     {
         Ok(())
     }
+
+    #[inline]
+    fn encoded_len(&self, _with: Self::WriteWith) -> Result<usize, WrappedErr<Infallible>> {
+        Ok(0)
+    }
 }
 
 /// Implement Barse trait for integers.
@@ -158,6 +187,11 @@ macro_rules! integer_impl {
             {
                 Ok(to.write_array(E::write::<Self>(*self))?)
             }
+
+            #[inline]
+            fn encoded_len(&self, _with: ()) -> Result<usize, WrappedErr<Infallible>> {
+                Ok(size_of::<$ty>())
+            }
         }
         )*
     };
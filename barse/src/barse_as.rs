@@ -8,6 +8,10 @@ use crate::{Barse, ByteSink, ByteSource, Endian};
 #[cfg(feature = "util")]
 mod bytes;
 
+#[cfg_attr(docsrs, doc(cfg(feature = "util")))]
+#[cfg(feature = "util")]
+mod var_int;
+
 #[cfg_attr(docsrs, doc(cfg(feature = "zerocopy")))]
 #[cfg(feature = "zerocopy")]
 mod zerocopy;
@@ -19,6 +23,9 @@ mod bytemuck;
 #[cfg(feature = "util")]
 pub use bytes::Bytes;
 
+#[cfg(feature = "util")]
+pub use var_int::{Sleb128, Uleb128, Vlq, ZigZag};
+
 #[doc(inline)]
 pub use crate::endian::{Big as BigEndian, Little as LittleEndian, Native as NativeEndian};
 
@@ -0,0 +1,52 @@
+//! Variable length integer [ReadAs]/[WriteAs] implementors.
+
+use crate::{util, Barse, ReadAs, WriteAs};
+
+/// Implement [ReadAs] and [WriteAs] using a wrapper from [util].
+macro_rules! var_int_as {
+    ($($name:ident),* $(,)?) => {$(
+        #[doc = concat!(
+            "[ReadAs]/[WriteAs] implementor reading integers as [util::",
+            stringify!($name),
+            "].",
+        )]
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name;
+
+        impl<T> ReadAs<T> for $name
+        where
+            util::$name<T>: Barse<ReadWith = ()>,
+        {
+            #[inline]
+            fn read_with<E, B>(self, from: &mut B, _with: ()) -> Result<T, crate::WrappedErr<B::Err>>
+            where
+                E: crate::Endian,
+                B: crate::ByteSource,
+            {
+                util::$name::<T>::read_with::<E, B>(from, ()).map(util::$name::into_inner)
+            }
+        }
+
+        impl<T> WriteAs<T> for $name
+        where
+            T: Copy,
+            util::$name<T>: Barse<WriteWith = ()>,
+        {
+            #[inline]
+            fn write_with<E, B>(
+                self,
+                value: &T,
+                to: &mut B,
+                _with: (),
+            ) -> Result<(), crate::WrappedErr<B::Err>>
+            where
+                E: crate::Endian,
+                B: crate::ByteSink,
+            {
+                util::$name::new(*value).write_with::<E, B>(to, ())
+            }
+        }
+    )*};
+}
+
+var_int_as!(Uleb128, Sleb128, Vlq, ZigZag);
@@ -0,0 +1,144 @@
+//! [BarseBorrow] trait and implementations.
+
+use crate::{Barse, BorrowSource, ByteSink, Endian, Error, WrappedErr};
+
+/// Trait to deserialize values borrowing from the source, and serialize them.
+///
+/// Implemented for all [Barse] implementors, as well as `&'src [u8]`, `&'src str` and
+/// `&'src [u8; N]` which are read without copying from a [BorrowSource] such as
+/// [SliceSrc][crate::SliceSrc].
+pub trait BarseBorrow<'src>: Sized {
+    /// Additional data needed to read.
+    type ReadWith;
+
+    /// Additional data needed to write.
+    type WriteWith;
+
+    /// Read an instance borrowing from source with given endianess.
+    ///
+    /// # Errors
+    /// If Source or implementation errors.
+    fn read_borrow<E, B>(from: &mut B, with: Self::ReadWith) -> Result<Self, WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: BorrowSource<'src>;
+
+    /// Write an instance to a sink with given endianess.
+    ///
+    /// # Errors
+    /// If Sink or implementation errors.
+    fn write_borrow<E, B>(
+        &self,
+        to: &mut B,
+        with: Self::WriteWith,
+    ) -> Result<(), WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: ByteSink;
+}
+
+impl<'src, T> BarseBorrow<'src> for T
+where
+    T: Barse,
+{
+    type ReadWith = T::ReadWith;
+
+    type WriteWith = T::WriteWith;
+
+    #[inline]
+    fn read_borrow<E, B>(from: &mut B, with: Self::ReadWith) -> Result<Self, WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: BorrowSource<'src>,
+    {
+        T::read_with::<E, B>(from, with)
+    }
+
+    #[inline]
+    fn write_borrow<E, B>(
+        &self,
+        to: &mut B,
+        with: Self::WriteWith,
+    ) -> Result<(), WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: ByteSink,
+    {
+        T::write_with::<E, B>(self, to, with)
+    }
+}
+
+/// Read with value is the amount of bytes to borrow.
+impl<'src> BarseBorrow<'src> for &'src [u8] {
+    type ReadWith = usize;
+
+    type WriteWith = ();
+
+    #[inline]
+    fn read_borrow<E, B>(from: &mut B, with: usize) -> Result<Self, WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: BorrowSource<'src>,
+    {
+        Ok(from.borrow_slice(with)?)
+    }
+
+    #[inline]
+    fn write_borrow<E, B>(&self, to: &mut B, _with: ()) -> Result<(), WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: ByteSink,
+    {
+        Ok(to.write_slice(self)?)
+    }
+}
+
+/// Read with value is the amount of bytes to borrow, which need to be valid UTF-8.
+impl<'src> BarseBorrow<'src> for &'src str {
+    type ReadWith = usize;
+
+    type WriteWith = ();
+
+    #[inline]
+    fn read_borrow<E, B>(from: &mut B, with: usize) -> Result<Self, WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: BorrowSource<'src>,
+    {
+        ::core::str::from_utf8(from.borrow_slice(with)?)
+            .map_err(|_| WrappedErr::from_err(Error::Msg("borrowed str is not valid UTF-8")))
+    }
+
+    #[inline]
+    fn write_borrow<E, B>(&self, to: &mut B, _with: ()) -> Result<(), WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: ByteSink,
+    {
+        Ok(to.write_slice(self.as_bytes())?)
+    }
+}
+
+impl<'src, const N: usize> BarseBorrow<'src> for &'src [u8; N] {
+    type ReadWith = ();
+
+    type WriteWith = ();
+
+    #[inline]
+    fn read_borrow<E, B>(from: &mut B, _with: ()) -> Result<Self, WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: BorrowSource<'src>,
+    {
+        Ok(from.borrow_array()?)
+    }
+
+    #[inline]
+    fn write_borrow<E, B>(&self, to: &mut B, _with: ()) -> Result<(), WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: ByteSink,
+    {
+        Ok(to.write_array(**self)?)
+    }
+}
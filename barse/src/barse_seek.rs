@@ -0,0 +1,138 @@
+//! [BarseSeek] trait and implementations.
+
+use ::core::marker::PhantomData;
+
+use crate::{Endian, GuardedSource, SeekSink, WrappedErr};
+
+/// Trait to deserialize and serialize values from seekable sources and sinks, such as those
+/// containing pointers to data elsewhere.
+///
+/// Implemented for [FilePtr][crate::util::FilePtr], as well as arrays, boxes, options and vectors
+/// of implementors. Other types of this crate and derived types forward to [Barse][crate::Barse],
+/// manual [Barse][crate::Barse] implementors may do the same to be used as fields of seekable
+/// types.
+///
+/// Values are read from a [GuardedSource] such that pointer cycles are always detected, any
+/// [SeekSource][crate::SeekSource] may be guarded using [PtrGuard][crate::adapter::PtrGuard].
+pub trait BarseSeek: Sized {
+    /// Additional data needed to read.
+    type ReadWith;
+
+    /// Additional data needed to write.
+    type WriteWith;
+
+    /// Read an instance from a guarded seekable source with given endianess.
+    ///
+    /// # Errors
+    /// If Source or implementation errors, or a pointer cycle is found.
+    fn read_seek<E, B>(from: &mut B, with: Self::ReadWith) -> Result<Self, WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: GuardedSource;
+
+    /// Write an instance to a seekable sink with given endianess.
+    ///
+    /// # Errors
+    /// If Sink or implementation errors.
+    fn write_seek<E, B>(&self, to: &mut B, with: Self::WriteWith) -> Result<(), WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: SeekSink;
+}
+
+/// Implement [BarseSeek] by forwarding to [Barse][crate::Barse], for types which cannot contain
+/// pointers.
+macro_rules! forward_seek {
+    ($(impl[$($gen:tt)*] $ty:ty $(where [$($bound:tt)*])?;)*) => {
+        $(
+        impl<$($gen)*> $crate::BarseSeek for $ty
+        where
+            Self: $crate::Barse,
+            $($($bound)*)?
+        {
+            type ReadWith = <Self as $crate::Barse>::ReadWith;
+            type WriteWith = <Self as $crate::Barse>::WriteWith;
+
+            #[inline]
+            fn read_seek<E, B>(
+                from: &mut B,
+                with: Self::ReadWith,
+            ) -> Result<Self, $crate::WrappedErr<B::Err>>
+            where
+                E: $crate::Endian,
+                B: $crate::GuardedSource,
+            {
+                <Self as $crate::Barse>::read_with::<E, B>(from, with)
+            }
+
+            #[inline]
+            fn write_seek<E, B>(
+                &self,
+                to: &mut B,
+                with: Self::WriteWith,
+            ) -> Result<(), $crate::WrappedErr<B::Err>>
+            where
+                E: $crate::Endian,
+                B: $crate::SeekSink,
+            {
+                <Self as $crate::Barse>::write_with::<E, B>(self, to, with)
+            }
+        }
+        )*
+    };
+}
+#[cfg_attr(
+    not(any(feature = "util", feature = "alloc")),
+    expect(unused_imports, reason = "only used by util and alloc types")
+)]
+pub(crate) use forward_seek;
+
+forward_seek! {
+    impl[] u8;
+    impl[] u16;
+    impl[] u32;
+    impl[] u64;
+    impl[] u128;
+    impl[] i8;
+    impl[] i16;
+    impl[] i32;
+    impl[] i64;
+    impl[] i128;
+    impl[] f32;
+    impl[] f64;
+    impl[] ();
+    impl[T] PhantomData<T>;
+}
+
+impl<T, ReadWith, WriteWith, const N: usize> BarseSeek for [T; N]
+where
+    T: BarseSeek<ReadWith = ReadWith, WriteWith = WriteWith>,
+    ReadWith: Clone,
+    WriteWith: Clone,
+{
+    type ReadWith = ReadWith;
+    type WriteWith = WriteWith;
+
+    fn read_seek<E, B>(from: &mut B, with: Self::ReadWith) -> Result<Self, WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: GuardedSource,
+    {
+        let mut values = [const { None }; N];
+        for (i, value) in values.iter_mut().enumerate() {
+            *value = Some(T::read_seek::<E, B>(from, with.clone()).map_err(|err| err.at_index(i))?);
+        }
+        Ok(values.map(|value| value.expect("all values should be some")))
+    }
+
+    fn write_seek<E, B>(&self, to: &mut B, with: Self::WriteWith) -> Result<(), WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: SeekSink,
+    {
+        for (i, value) in self.iter().enumerate() {
+            T::write_seek::<E, B>(value, to, with.clone()).map_err(|err| err.at_index(i))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+//! [BorrowSource] trait.
+
+use crate::ByteSource;
+
+/// [ByteSource] which may lend out bytes living for `'src`, without copying them.
+pub trait BorrowSource<'src>: ByteSource {
+    /// Borrow a slice of len bytes, moving head past it.
+    ///
+    /// # Errors
+    /// If len bytes cannot be borrowed from source.
+    fn borrow_slice(&mut self, len: usize) -> Result<&'src [u8], Self::Err>;
+
+    /// Borrow an array of bytes, moving head past it.
+    ///
+    /// # Errors
+    /// If N bytes cannot be borrowed from source.
+    #[inline]
+    fn borrow_array<const N: usize>(&mut self) -> Result<&'src [u8; N], Self::Err> {
+        let slice = self.borrow_slice(N)?;
+        Ok(slice
+            .try_into()
+            .expect("borrowed slice should have requested length"))
+    }
+}
+
+impl<'src, Src> BorrowSource<'src> for &mut Src
+where
+    Src: BorrowSource<'src>,
+{
+    #[inline]
+    fn borrow_slice(&mut self, len: usize) -> Result<&'src [u8], Self::Err> {
+        Src::borrow_slice(self, len)
+    }
+
+    #[inline]
+    fn borrow_array<const N: usize>(&mut self) -> Result<&'src [u8; N], Self::Err> {
+        Src::borrow_array(self)
+    }
+}
@@ -37,6 +37,14 @@ pub trait ByteSink: Sized {
     fn remaining(&self) -> Option<usize> {
         None
     }
+
+    /// Get offset of head from start of sink, if known.
+    ///
+    /// Used to give errors context, as such it should be cheap to call.
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        None
+    }
 }
 
 impl<Sink> ByteSink for &mut Sink
@@ -64,6 +72,11 @@ where
     fn remaining(&self) -> Option<usize> {
         Sink::remaining(self)
     }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        Sink::offset(self)
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -96,4 +109,9 @@ where
     fn remaining(&self) -> Option<usize> {
         Sink::remaining(self)
     }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        Sink::offset(self)
+    }
 }
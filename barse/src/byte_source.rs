@@ -33,6 +33,36 @@ pub trait ByteSource: Sized {
         Ok(byte)
     }
 
+    /// Read a single byte, or [None] if the end of source has been reached.
+    ///
+    /// By default [ByteSource::remaining] is used to detect the end, sources not knowing it
+    /// should override this.
+    ///
+    /// # Errors
+    /// If the byte cannot be read from source.
+    #[inline]
+    fn read_byte_or_eof(&mut self) -> Result<Option<u8>, Self::Err> {
+        if self.remaining() == Some(0) {
+            Ok(None)
+        } else {
+            self.read_byte().map(Some)
+        }
+    }
+
+    /// Fill buf with upcoming bytes without consuming them, if source supports peeking.
+    ///
+    /// Returns `false` if it does not, in which case nothing has been read. Implementors of
+    /// [PeekSource][crate::PeekSource] should override this to peek, such that peeking may be
+    /// used by code only requiring a [ByteSource].
+    ///
+    /// # Errors
+    /// If source can peek but cannot fill buffer, or otherwise fails.
+    #[inline]
+    fn try_peek_slice(&mut self, buf: &mut [u8]) -> Result<bool, Self::Err> {
+        _ = buf;
+        Ok(false)
+    }
+
     /// Skip bytes, as if they have been read.
     ///
     /// # Errors
@@ -61,6 +91,14 @@ pub trait ByteSource: Sized {
     fn remaining(&self) -> Option<usize> {
         None
     }
+
+    /// Get offset of head from start of source, if known.
+    ///
+    /// Used to give errors context, as such it should be cheap to call.
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        None
+    }
 }
 
 impl<Src> ByteSource for &mut Src
@@ -84,6 +122,16 @@ where
         Src::read_byte(self)
     }
 
+    #[inline]
+    fn read_byte_or_eof(&mut self) -> Result<Option<u8>, Self::Err> {
+        Src::read_byte_or_eof(self)
+    }
+
+    #[inline]
+    fn try_peek_slice(&mut self, buf: &mut [u8]) -> Result<bool, Self::Err> {
+        Src::try_peek_slice(self, buf)
+    }
+
     #[inline]
     fn skip(&mut self, count: usize) -> Result<(), Self::Err> {
         Src::skip(self, count)
@@ -98,6 +146,11 @@ where
     fn remaining(&self) -> Option<usize> {
         Src::remaining(self)
     }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        Src::offset(self)
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -126,6 +179,16 @@ where
         Src::read_byte(self)
     }
 
+    #[inline]
+    fn read_byte_or_eof(&mut self) -> Result<Option<u8>, Self::Err> {
+        Src::read_byte_or_eof(self)
+    }
+
+    #[inline]
+    fn try_peek_slice(&mut self, buf: &mut [u8]) -> Result<bool, Self::Err> {
+        Src::try_peek_slice(self, buf)
+    }
+
     #[inline]
     fn skip(&mut self, count: usize) -> Result<(), Self::Err> {
         Src::skip(self, count)
@@ -140,4 +203,9 @@ where
     fn remaining(&self) -> Option<usize> {
         Src::remaining(self)
     }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        Src::offset(self)
+    }
 }
@@ -0,0 +1,75 @@
+//! [Collection] trait.
+
+use crate::Error;
+
+/// Collections which may be built one item at a time.
+///
+/// Used by derived fields using `count`, `until` or `until_eof`, written items are those given by
+/// iterating a reference to the collection.
+pub trait Collection: Sized {
+    /// Type of items in collection.
+    type Item;
+
+    /// Collection being built.
+    type Builder;
+
+    /// Start building a collection, len is a hint of how many items will be pushed.
+    fn builder(len: usize) -> Self::Builder;
+
+    /// Push an item to collection being built.
+    ///
+    /// # Errors
+    /// If collection cannot hold any more items.
+    fn push(builder: &mut Self::Builder, item: Self::Item) -> Result<(), Error>;
+
+    /// Finish building collection.
+    ///
+    /// # Errors
+    /// If collection cannot be built from the items pushed.
+    fn finish(builder: Self::Builder) -> Result<Self, Error>;
+}
+
+impl<T, const N: usize> Collection for [T; N] {
+    type Item = T;
+    type Builder = ([Option<T>; N], usize);
+
+    #[inline]
+    fn builder(_len: usize) -> Self::Builder {
+        ([const { None }; N], 0)
+    }
+
+    #[inline]
+    fn push((items, len): &mut Self::Builder, item: Self::Item) -> Result<(), Error> {
+        let slot = items
+            .get_mut(*len)
+            .ok_or(Error::Msg("more items than array holds were read"))?;
+        *slot = Some(item);
+        *len += 1;
+        Ok(())
+    }
+
+    #[inline]
+    fn finish((items, len): Self::Builder) -> Result<Self, Error> {
+        if len != N {
+            return Err(Error::Msg("fewer items than array holds were read"));
+        }
+        Ok(items.map(|item| item.expect("all items should be some")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    use super::*;
+
+    #[test]
+    fn array_collection() {
+        let mut builder = <[u8; 2]>::builder(2);
+        <[u8; 2]>::push(&mut builder, 1).unwrap();
+        assert!(<[u8; 2]>::finish(builder).is_err());
+        <[u8; 2]>::push(&mut builder, 2).unwrap();
+        assert!(<[u8; 2]>::push(&mut { builder }, 3).is_err());
+        assert_eq!(<[u8; 2]>::finish(builder).unwrap(), [1, 2]);
+    }
+}
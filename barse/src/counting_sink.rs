@@ -0,0 +1,102 @@
+//! [CountingSink] implementation.
+
+use ::core::convert::Infallible;
+
+use crate::{ByteSink, SeekSink};
+
+/// [ByteSink] implementor discarding all bytes, keeping count of how many were written.
+///
+/// When the head is moved using [SeekSink] the count is the furthest position written to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CountingSink {
+    /// Position of head.
+    head: u64,
+
+    /// Furthest position written to.
+    end: u64,
+}
+
+impl CountingSink {
+    /// Create a new [CountingSink] with a count of zero.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { head: 0, end: 0 }
+    }
+
+    /// Get amount of bytes written.
+    #[inline]
+    pub const fn count(&self) -> u64 {
+        self.end
+    }
+
+    /// Move head forward by count bytes.
+    #[inline]
+    const fn advance(&mut self, count: usize) {
+        self.head = self.head.saturating_add(count as u64);
+        if self.head > self.end {
+            self.end = self.head;
+        }
+    }
+}
+
+impl ByteSink for CountingSink {
+    type Err = Infallible;
+
+    #[inline]
+    fn write_slice(&mut self, buf: &[u8]) -> Result<(), Self::Err> {
+        self.advance(buf.len());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_array<const N: usize>(&mut self, _bytes: [u8; N]) -> Result<(), Self::Err> {
+        self.advance(N);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_byte(&mut self, _byte: u8) -> Result<(), Self::Err> {
+        self.advance(1);
+        Ok(())
+    }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        Some(self.head)
+    }
+}
+
+impl SeekSink for CountingSink {
+    #[inline]
+    fn position(&mut self) -> Result<u64, Self::Err> {
+        Ok(self.head)
+    }
+
+    #[inline]
+    fn seek_to(&mut self, pos: u64) -> Result<(), Self::Err> {
+        self.head = pos;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+    use super::*;
+
+    #[test]
+    fn count() {
+        let mut sink = CountingSink::new();
+
+        assert_eq!(sink.write_slice(b"hello"), Ok(()));
+        assert_eq!(sink.write_array(*b"!!"), Ok(()));
+        assert_eq!(sink.count(), 7);
+        assert_eq!(sink.seek_to(2), Ok(()));
+        assert_eq!(sink.write_byte(b'L'), Ok(()));
+        assert_eq!(sink.offset(), Some(3));
+        assert_eq!(sink.count(), 7);
+        assert_eq!(sink.seek_to(9), Ok(()));
+        assert_eq!(sink.write_byte(0), Ok(()));
+        assert_eq!(sink.count(), 10);
+    }
+}
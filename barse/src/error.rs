@@ -1,15 +1,43 @@
 //! Error types in use by crate.
 //!
 //! [WrappedErr] and [Error] are re-exported in crate root.
+//! [Context] describes where an error occurred.
 
-use ::core::fmt::Display;
+use ::core::{
+    borrow::{Borrow, BorrowMut},
+    fmt::Display,
+};
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+pub use self::context::{Context, Segment};
+
+mod context;
+
+/// Storage of [Context] in [WrappedErr], boxed when possible to keep errors small.
+#[cfg(feature = "alloc")]
+type ContextSlot = Option<alloc::boxed::Box<Context>>;
+
+/// Storage of [Context] in [WrappedErr], boxed when possible to keep errors small.
+#[cfg(not(feature = "alloc"))]
+type ContextSlot = Option<Context>;
+
 /// Crate error type used to report sink/source specific errors and general read/write errors.
+///
+/// Besides the [ErrKind] an error may carry a [Context] describing where it occurred.
 #[derive(Debug)]
-pub enum WrappedErr<E> {
+pub struct WrappedErr<E> {
+    /// Kind of error.
+    kind: ErrKind<E>,
+
+    /// Where error occurred, if known.
+    context: ContextSlot,
+}
+
+/// Kind of [WrappedErr].
+#[derive(Debug)]
+pub enum ErrKind<E> {
     /// Wrapped source/sink error.
     Wrapped(E),
 
@@ -18,27 +46,154 @@ pub enum WrappedErr<E> {
 }
 
 impl<E> From<E> for WrappedErr<E> {
+    #[inline]
     fn from(value: E) -> Self {
-        Self::Wrapped(value)
+        Self::new(ErrKind::Wrapped(value))
     }
 }
 
 impl<E> WrappedErr<E> {
+    /// Construct a new error without context.
+    #[inline]
+    pub const fn new(kind: ErrKind<E>) -> Self {
+        Self {
+            kind,
+            context: None,
+        }
+    }
+
     /// Convert [Error] into wrapped error.
     #[inline]
     pub const fn from_err(value: Error) -> Self {
-        Self::Other(value)
+        Self::new(ErrKind::Other(value))
+    }
+
+    /// Get kind of error.
+    #[inline]
+    pub const fn kind(&self) -> &ErrKind<E> {
+        &self.kind
+    }
+
+    /// Convert into kind of error, discarding context.
+    #[inline]
+    pub fn into_kind(self) -> ErrKind<E> {
+        self.kind
+    }
+
+    /// Get context of error, if any has been added.
+    #[inline]
+    pub fn context(&self) -> Option<&Context> {
+        self.context.as_ref().map(Borrow::borrow)
+    }
+
+    /// Get context of error, adding it if missing.
+    #[inline]
+    fn context_mut(&mut self) -> &mut Context {
+        self.context
+            .get_or_insert_with(Default::default)
+            .borrow_mut()
+    }
+
+    /// Set offset at which error occurred, if not already set.
+    ///
+    /// Since errors are annotated from the inside out the innermost known offset is kept.
+    #[inline]
+    #[must_use]
+    pub fn at_offset(mut self, offset: Option<u64>) -> Self {
+        if offset.is_some() {
+            self.context_mut().set_offset(offset);
+        }
+        self
+    }
+
+    /// Add a field to the path of error.
+    #[inline]
+    #[must_use]
+    pub fn in_field(mut self, name: &'static str) -> Self {
+        self.context_mut().push(Segment::Field(name));
+        self
+    }
+
+    /// Add an index to the path of error.
+    #[inline]
+    #[must_use]
+    pub fn at_index(mut self, index: usize) -> Self {
+        self.context_mut().push(Segment::Index(index));
+        self
+    }
+
+    /// Set name of type at root of path, replacing any previous name.
+    #[inline]
+    #[must_use]
+    pub fn in_type(mut self, name: &'static str) -> Self {
+        self.context_mut().set_root(name);
+        self
+    }
+
+    /// Add base to offset of error, for errors from sources/sinks nested in other ones.
+    ///
+    /// If base is [None] the offset is cleared.
+    #[inline]
+    #[must_use]
+    pub fn shift_offset(mut self, base: Option<u64>) -> Self {
+        if let Some(context) = &mut self.context {
+            BorrowMut::<Context>::borrow_mut(context).shift_offset(base);
+        }
+        self
+    }
+
+    /// Map wrapped error, keeping context.
+    #[inline]
+    pub fn map_wrapped<F>(self, map: impl FnOnce(E) -> F) -> WrappedErr<F> {
+        let Self { kind, context } = self;
+        let kind = match kind {
+            ErrKind::Wrapped(err) => ErrKind::Wrapped(map(err)),
+            ErrKind::Other(err) => ErrKind::Other(err),
+        };
+        WrappedErr { kind, context }
+    }
+
+    /// Map wrapped error, into either a new wrapped error or an [Error], keeping context.
+    #[inline]
+    pub fn try_map_wrapped<F>(self, map: impl FnOnce(E) -> Result<F, Error>) -> WrappedErr<F> {
+        let Self { kind, context } = self;
+        let kind = match kind {
+            ErrKind::Wrapped(err) => match map(err) {
+                Ok(err) => ErrKind::Wrapped(err),
+                Err(err) => ErrKind::Other(err),
+            },
+            ErrKind::Other(err) => ErrKind::Other(err),
+        };
+        WrappedErr { kind, context }
+    }
+
+    /// Convert wrapped error into an [Error], keeping context.
+    #[inline]
+    pub fn rewrap<F>(self) -> WrappedErr<F>
+    where
+        Error: From<E>,
+    {
+        let Self { kind, context } = self;
+        WrappedErr {
+            kind: ErrKind::Other(match kind {
+                ErrKind::Wrapped(err) => err.into(),
+                ErrKind::Other(err) => err,
+            }),
+            context,
+        }
     }
 
     /// Merge variants into a type which may converted to from both of them.
+    ///
+    /// Context is discarded.
     #[inline]
     pub fn merge_into<T>(self) -> T
     where
         T: From<Error> + From<E>,
     {
-        match self {
-            WrappedErr::Wrapped(err) => err.into(),
-            WrappedErr::Other(err) => err.into(),
+        match self.kind {
+            ErrKind::Wrapped(err) => err.into(),
+            ErrKind::Other(err) => err.into(),
         }
     }
 }
@@ -48,9 +203,20 @@ where
     E: Display,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        match self {
-            WrappedErr::Wrapped(e) => Display::fmt(e, f),
-            WrappedErr::Other(error) => Display::fmt(error, f),
+        match &self.kind {
+            ErrKind::Wrapped(e) => Display::fmt(e, f)?,
+            ErrKind::Other(error) => Display::fmt(error, f)?,
+        }
+
+        let Some(context) = self.context() else {
+            return Ok(());
+        };
+
+        match (context.is_path_empty(), context.offset()) {
+            (true, None) => Ok(()),
+            (true, Some(offset)) => write!(f, " (at offset {offset})"),
+            (false, None) => write!(f, " (in {context})"),
+            (false, Some(offset)) => write!(f, " (in {context}, at offset {offset})"),
         }
     }
 }
@@ -64,6 +230,18 @@ pub enum Error {
     /// Error is only a message.
     Msg(&'static str),
 
+    /// A checksum did not match the bytes it covers.
+    Checksum(ChecksumMismatch),
+
+    /// Magic bytes did not match the expected bytes.
+    Magic(MagicMismatch),
+
+    /// An assertion on read or written values failed.
+    Assertion(AssertionFailed),
+
+    /// A pointer was followed to a position already being read through another pointer.
+    PtrCycle(PtrCycle),
+
     /// Error is tracked using a reference to a static [::core::error::Error] implementor.
     Dyn(&'static (dyn ::core::error::Error + Send + Sync)),
 
@@ -73,6 +251,13 @@ pub enum Error {
     Box(alloc::boxed::Box<dyn ::core::error::Error + Send + Sync>),
 }
 
+impl From<::core::convert::Infallible> for Error {
+    #[inline]
+    fn from(value: ::core::convert::Infallible) -> Self {
+        match value {}
+    }
+}
+
 impl From<&'static str> for Error {
     #[inline]
     fn from(value: &'static str) -> Self {
@@ -110,6 +295,10 @@ impl Display for Error {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Error::Msg(msg) => f.write_str(msg),
+            Error::Checksum(mismatch) => Display::fmt(mismatch, f),
+            Error::Magic(mismatch) => Display::fmt(mismatch, f),
+            Error::Assertion(failed) => Display::fmt(failed, f),
+            Error::PtrCycle(cycle) => Display::fmt(cycle, f),
             Error::Dyn(err) => Display::fmt(err, f),
             #[cfg(feature = "alloc")]
             Error::Box(err) => Display::fmt(err, f),
@@ -117,16 +306,149 @@ impl Display for Error {
     }
 }
 
+impl From<ChecksumMismatch> for Error {
+    #[inline]
+    fn from(value: ChecksumMismatch) -> Self {
+        Self::Checksum(value)
+    }
+}
+
+impl From<MagicMismatch> for Error {
+    #[inline]
+    fn from(value: MagicMismatch) -> Self {
+        Self::Magic(value)
+    }
+}
+
+impl From<AssertionFailed> for Error {
+    #[inline]
+    fn from(value: AssertionFailed) -> Self {
+        Self::Assertion(value)
+    }
+}
+
+impl From<PtrCycle> for Error {
+    #[inline]
+    fn from(value: PtrCycle) -> Self {
+        Self::PtrCycle(value)
+    }
+}
+
 impl Error {
     /// Convert error into any kind of [WrappedErr].
     #[inline]
     pub const fn into_wrapped<E>(self) -> WrappedErr<E> {
-        WrappedErr::Other(self)
+        WrappedErr::from_err(self)
     }
 }
 
 impl ::core::error::Error for Error {}
 
+/// Mismatch between a read checksum and the checksum of the bytes it covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ChecksumMismatch {
+    /// Checksum computed from bytes.
+    pub expected: u64,
+
+    /// Checksum that was read.
+    pub actual: u64,
+}
+
+impl Display for ChecksumMismatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let Self { expected, actual } = self;
+        write!(
+            f,
+            "checksum mismatch, expected {expected:#x} but read {actual:#x}"
+        )
+    }
+}
+
+impl ::core::error::Error for ChecksumMismatch {}
+
+/// Mismatch between read magic bytes and the expected magic bytes.
+///
+/// At most [MagicMismatch::ACTUAL_MAX] read bytes are kept, as to keep [Error] small.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MagicMismatch {
+    /// Expected magic bytes.
+    expected: &'static &'static [u8],
+
+    /// Start of bytes that were read, as many as were expected are used.
+    actual: [u8; MagicMismatch::ACTUAL_MAX],
+}
+
+impl MagicMismatch {
+    /// Max amount of read bytes kept.
+    pub const ACTUAL_MAX: usize = 8;
+
+    /// Construct a new [MagicMismatch] from expected and read bytes.
+    pub fn new(expected: &'static &'static [u8], actual: &[u8]) -> Self {
+        let mut kept = [0u8; Self::ACTUAL_MAX];
+        let len = actual.len().min(Self::ACTUAL_MAX);
+        kept[..len].copy_from_slice(&actual[..len]);
+        Self {
+            expected,
+            actual: kept,
+        }
+    }
+
+    /// Get expected magic bytes.
+    #[inline]
+    pub const fn expected(&self) -> &'static [u8] {
+        self.expected
+    }
+
+    /// Get bytes that were read, at most [MagicMismatch::ACTUAL_MAX].
+    #[inline]
+    pub fn actual(&self) -> &[u8] {
+        &self.actual[..self.expected.len().min(Self::ACTUAL_MAX)]
+    }
+}
+
+impl Display for MagicMismatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "magic mismatch, expected b\"{}\" but read b\"{}\"",
+            self.expected().escape_ascii(),
+            self.actual().escape_ascii(),
+        )
+    }
+}
+
+impl ::core::error::Error for MagicMismatch {}
+
+/// Failure of an assertion on read or written values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AssertionFailed {
+    /// Message describing assertion.
+    pub message: &'static str,
+}
+
+impl Display for AssertionFailed {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "assertion failed, {}", self.message)
+    }
+}
+
+impl ::core::error::Error for AssertionFailed {}
+
+/// A pointer was followed to a position already being read through another pointer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PtrCycle {
+    /// Position pointed to.
+    pub position: u64,
+}
+
+impl Display for PtrCycle {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "pointer cycle at position {}", self.position)
+    }
+}
+
+impl ::core::error::Error for PtrCycle {}
+
 /// Error returned by [SliceSink][crate::SliceSink] when bytes cannot be written.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct SliceSinkFull;
@@ -164,3 +486,69 @@ impl From<SliceSrcEmpty> for crate::Error {
         crate::Error::Dyn(&ERR)
     }
 }
+
+/// Error returned by [Limited][crate::adapter::Limited] and
+/// [LimitedSink][crate::adapter::LimitedSink].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LimitErr<E> {
+    /// Wrapped source/sink errored.
+    Inner(E),
+
+    /// More bytes than the limit allows were to be read/written.
+    Exceeded,
+
+    /// Bytes were left when finished, and leftover bytes were not allowed.
+    Leftover(usize),
+}
+
+impl<E> LimitErr<E> {
+    /// Get error of wrapped source/sink, or an [Error] if the limit caused the error.
+    ///
+    /// # Errors
+    /// If the error was caused by the limit.
+    #[inline]
+    pub fn into_inner(self) -> Result<E, Error> {
+        match self {
+            LimitErr::Inner(err) => Ok(err),
+            LimitErr::Exceeded => Err(Error::Msg("value did not fit within limit")),
+            LimitErr::Leftover(_) => Err(Error::Msg("value did not use all bytes within limit")),
+        }
+    }
+}
+
+impl<E> From<E> for LimitErr<E> {
+    #[inline]
+    fn from(value: E) -> Self {
+        Self::Inner(value)
+    }
+}
+
+impl<E> Display for LimitErr<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LimitErr::Inner(err) => Display::fmt(err, f),
+            LimitErr::Exceeded => f.write_str("value did not fit within limit"),
+            LimitErr::Leftover(count) => {
+                write!(
+                    f,
+                    "value did not use all bytes within limit, {count} bytes left"
+                )
+            }
+        }
+    }
+}
+
+impl<E> ::core::error::Error for LimitErr<E>
+where
+    E: ::core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn ::core::error::Error + 'static)> {
+        match self {
+            LimitErr::Inner(err) => Some(err),
+            LimitErr::Exceeded | LimitErr::Leftover(_) => None,
+        }
+    }
+}
@@ -0,0 +1,253 @@
+//! [Context] impl.
+
+use ::core::fmt::Display;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+/// Segment of a [Context] path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Segment {
+    /// Named or tuple field of a struct or variant.
+    Field(&'static str),
+
+    /// Index into an array or collection.
+    Index(usize),
+}
+
+impl Display for Segment {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Segment::Field(name) => write!(f, ".{name}"),
+            Segment::Index(index) => write!(f, "[{index}]"),
+        }
+    }
+}
+
+/// Where an error occurred, attached to [WrappedErr][crate::WrappedErr] values.
+///
+/// The path is built from the innermost value outwards, such that it may be displayed as
+/// `Header.entries[3].kind`.
+#[cfg_attr(
+    not(feature = "alloc"),
+    doc = "\n\nWithout the `alloc` feature only the three innermost segments are kept."
+)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Context {
+    /// Offset in source/sink at which the error occurred.
+    offset: Option<u64>,
+
+    /// Name of outermost type.
+    root: Option<&'static str>,
+
+    /// Segments of path, innermost first.
+    trail: Trail,
+}
+
+impl Context {
+    /// Get offset in source/sink at which error occurred, if known.
+    #[inline]
+    pub const fn offset(&self) -> Option<u64> {
+        self.offset
+    }
+
+    /// Get name of outermost type in path, if known.
+    #[inline]
+    pub const fn root(&self) -> Option<&'static str> {
+        self.root
+    }
+
+    /// Get segments of path, outermost first.
+    #[inline]
+    pub fn segments(&self) -> impl '_ + DoubleEndedIterator<Item = Segment> {
+        self.trail.iter().rev()
+    }
+
+    /// Returns `true` if outer segments have been dropped due to the path being too long.
+    #[inline]
+    pub const fn is_truncated(&self) -> bool {
+        self.trail.is_truncated()
+    }
+
+    /// Returns `true` if there is no path.
+    #[inline]
+    pub fn is_path_empty(&self) -> bool {
+        self.root.is_none() && self.trail.iter().next().is_none()
+    }
+
+    /// Set offset if not already set.
+    #[inline]
+    pub(crate) const fn set_offset(&mut self, offset: Option<u64>) {
+        if self.offset.is_none() {
+            self.offset = offset;
+        }
+    }
+
+    /// Add base to offset, clearing it if base is unknown.
+    #[inline]
+    pub(crate) fn shift_offset(&mut self, base: Option<u64>) {
+        self.offset = self.offset.zip(base).map(|(offset, base)| offset + base);
+    }
+
+    /// Set root type name, replacing any previous name.
+    #[inline]
+    pub(crate) const fn set_root(&mut self, name: &'static str) {
+        self.root = Some(name);
+    }
+
+    /// Add a segment outside of current segments.
+    #[inline]
+    pub(crate) fn push(&mut self, segment: Segment) {
+        self.trail.push(segment);
+    }
+}
+
+impl Display for Context {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut segments = self.segments().peekable();
+
+        if let Some(root) = self.root {
+            f.write_str(root)?;
+        } else if let Some(Segment::Field(name)) =
+            segments.next_if(|s| matches!(s, Segment::Field(_)))
+        {
+            // Leading dot is not needed if path has no root.
+            f.write_str(name)?;
+        }
+
+        if self.is_truncated() {
+            f.write_str("..")?;
+        }
+
+        for segment in segments {
+            Display::fmt(&segment, f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Segments of a path, innermost first.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+struct Trail(alloc::vec::Vec<Segment>);
+
+#[cfg(feature = "alloc")]
+impl Trail {
+    /// Iterate segments, innermost first.
+    fn iter(&self) -> impl '_ + DoubleEndedIterator<Item = Segment> {
+        self.0.iter().copied()
+    }
+
+    /// Trail is never truncated when allocation is possible.
+    const fn is_truncated(&self) -> bool {
+        false
+    }
+
+    /// Add an outer segment.
+    fn push(&mut self, segment: Segment) {
+        self.0.push(segment);
+    }
+}
+
+/// Segments of a path, innermost first, with a fixed capacity.
+///
+/// Field names and indices are stored apart to keep errors small, indices of [u32::MAX] and
+/// above are saturated to [usize::MAX].
+#[cfg(not(feature = "alloc"))]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+struct Trail {
+    /// Field names of stored segments, [None] for indices. Only the first len are valid.
+    names: [Option<&'static str>; Trail::CAPACITY],
+
+    /// Indices of stored segments that are not fields.
+    indices: [u32; Trail::CAPACITY],
+
+    /// Amount of stored segments.
+    len: u8,
+
+    /// Set if any segment has been dropped.
+    truncated: bool,
+}
+
+#[cfg(not(feature = "alloc"))]
+impl Trail {
+    /// Max amount of segments stored.
+    const CAPACITY: usize = 3;
+
+    /// Iterate segments, innermost first.
+    fn iter(&self) -> impl '_ + DoubleEndedIterator<Item = Segment> {
+        let len = usize::from(self.len);
+        self.names[..len]
+            .iter()
+            .zip(&self.indices[..len])
+            .map(|(name, index)| {
+                name.map_or_else(
+                    || {
+                        Segment::Index(match *index {
+                            u32::MAX => usize::MAX,
+                            index => usize::try_from(index).unwrap_or(usize::MAX),
+                        })
+                    },
+                    Segment::Field,
+                )
+            })
+    }
+
+    /// Check if any segment has been dropped.
+    const fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Add an outer segment, dropping it if full.
+    fn push(&mut self, segment: Segment) {
+        let len = usize::from(self.len);
+        if len == Self::CAPACITY {
+            self.truncated = true;
+            return;
+        }
+        match segment {
+            Segment::Field(name) => self.names[len] = Some(name),
+            Segment::Index(index) => {
+                self.names[len] = None;
+                self.indices[len] = u32::try_from(index).unwrap_or(u32::MAX);
+            }
+        }
+        self.len += 1;
+    }
+}
+
+#[cfg(all(test, not(feature = "alloc")))]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    extern crate std;
+
+    use std::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn fixed_trail() {
+        let mut context = Context::default();
+        context.push(Segment::Field("kind"));
+        context.push(Segment::Index(3));
+        context.push(Segment::Field("entries"));
+        context.set_root("Header");
+
+        assert!(!context.is_truncated());
+        assert_eq!(context.to_string(), "Header.entries[3].kind");
+
+        for index in 0..Trail::CAPACITY {
+            context.push(Segment::Index(index));
+        }
+
+        assert!(context.is_truncated());
+        assert_eq!(context.segments().count(), Trail::CAPACITY);
+        assert_eq!(context.to_string(), "Header...entries[3].kind");
+
+        let mut context = Context::default();
+        context.push(Segment::Index(usize::MAX));
+        assert_eq!(context.segments().next(), Some(Segment::Index(usize::MAX)));
+    }
+}
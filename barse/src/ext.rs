@@ -1,8 +1,8 @@
 //! Extension traits.
 
 pub use self::{
-    barse_read::BarseReadExt, barse_write::BarseWriteExt, byte_sink_ext::ByteSinkExt,
-    byte_source_ext::ByteSourceExt,
+    barse_read::BarseReadExt, barse_write::BarseWriteExt, borrow_source_ext::BorrowSourceExt,
+    byte_sink_ext::ByteSinkExt, byte_source_ext::ByteSourceExt, seek_source_ext::SeekSourceExt,
 };
 
 #[cfg(feature = "barse_as")]
@@ -15,6 +15,10 @@ mod byte_source_ext;
 
 mod byte_sink_ext;
 
+mod borrow_source_ext;
+
+mod seek_source_ext;
+
 mod barse_read;
 
 mod barse_write;
@@ -0,0 +1,88 @@
+//! Borrow source extension trait.
+
+use crate::{
+    endian::{Big, Little, Native},
+    BarseBorrow, BorrowSource, Endian, WrappedErr,
+};
+
+/// Extension to [BorrowSource] adding some convenient functions.
+pub trait BorrowSourceExt<'src>: BorrowSource<'src> {
+    /// Read a value implementing [BarseBorrow] using given endian.
+    ///
+    /// # Errors
+    /// If source or barse implementation errors.
+    fn read_borrow<T: BarseBorrow<'src, ReadWith = ()>, E: Endian>(
+        &mut self,
+    ) -> Result<T, WrappedErr<Self::Err>>;
+
+    /// Read a value implementing [BarseBorrow] using given endian and additional value.
+    ///
+    /// # Errors
+    /// If source or barse implementation errors.
+    fn read_borrow_with<T: BarseBorrow<'src>, E: Endian>(
+        &mut self,
+        with: T::ReadWith,
+    ) -> Result<T, WrappedErr<Self::Err>>;
+
+    /// Read a value implementing [BarseBorrow] using little endian.
+    ///
+    /// # Errors
+    /// If source or barse implementation errors.
+    fn read_borrow_le<T: BarseBorrow<'src, ReadWith = ()>>(
+        &mut self,
+    ) -> Result<T, WrappedErr<Self::Err>>;
+
+    /// Read a value implementing [BarseBorrow] using big endian.
+    ///
+    /// # Errors
+    /// If source or barse implementation errors.
+    fn read_borrow_be<T: BarseBorrow<'src, ReadWith = ()>>(
+        &mut self,
+    ) -> Result<T, WrappedErr<Self::Err>>;
+
+    /// Read a value implementing [BarseBorrow] using native endian.
+    ///
+    /// # Errors
+    /// If source or barse implementation errors.
+    fn read_borrow_ne<T: BarseBorrow<'src, ReadWith = ()>>(
+        &mut self,
+    ) -> Result<T, WrappedErr<Self::Err>>;
+}
+
+impl<'src, S: BorrowSource<'src>> BorrowSourceExt<'src> for S {
+    #[inline]
+    fn read_borrow<T: BarseBorrow<'src, ReadWith = ()>, E: Endian>(
+        &mut self,
+    ) -> Result<T, WrappedErr<Self::Err>> {
+        T::read_borrow::<E, Self>(self, ())
+    }
+
+    #[inline]
+    fn read_borrow_with<T: BarseBorrow<'src>, E: Endian>(
+        &mut self,
+        with: T::ReadWith,
+    ) -> Result<T, WrappedErr<Self::Err>> {
+        T::read_borrow::<E, Self>(self, with)
+    }
+
+    #[inline]
+    fn read_borrow_le<T: BarseBorrow<'src, ReadWith = ()>>(
+        &mut self,
+    ) -> Result<T, WrappedErr<Self::Err>> {
+        Self::read_borrow::<T, Little>(self)
+    }
+
+    #[inline]
+    fn read_borrow_be<T: BarseBorrow<'src, ReadWith = ()>>(
+        &mut self,
+    ) -> Result<T, WrappedErr<Self::Err>> {
+        Self::read_borrow::<T, Big>(self)
+    }
+
+    #[inline]
+    fn read_borrow_ne<T: BarseBorrow<'src, ReadWith = ()>>(
+        &mut self,
+    ) -> Result<T, WrappedErr<Self::Err>> {
+        Self::read_borrow::<T, Native>(self)
+    }
+}
@@ -0,0 +1,79 @@
+//! Seek source extension trait.
+
+use crate::{
+    adapter::PtrGuard,
+    endian::{Big, Little, Native},
+    BarseSeek, Endian, SeekSource, WrappedErr,
+};
+
+/// Extension to [SeekSource] adding some convenient functions.
+///
+/// Values are read through a [PtrGuard], such that pointer cycles are detected.
+pub trait SeekSourceExt: SeekSource {
+    /// Read a value implementing [BarseSeek] using given endian.
+    ///
+    /// # Errors
+    /// If source or barse implementation errors.
+    fn read_seek<T: BarseSeek<ReadWith = ()>, E: Endian>(
+        &mut self,
+    ) -> Result<T, WrappedErr<Self::Err>>;
+
+    /// Read a value implementing [BarseSeek] using given endian and additional value.
+    ///
+    /// # Errors
+    /// If source or barse implementation errors.
+    fn read_seek_with<T: BarseSeek, E: Endian>(
+        &mut self,
+        with: T::ReadWith,
+    ) -> Result<T, WrappedErr<Self::Err>>;
+
+    /// Read a value implementing [BarseSeek] using little endian.
+    ///
+    /// # Errors
+    /// If source or barse implementation errors.
+    fn read_seek_le<T: BarseSeek<ReadWith = ()>>(&mut self) -> Result<T, WrappedErr<Self::Err>>;
+
+    /// Read a value implementing [BarseSeek] using big endian.
+    ///
+    /// # Errors
+    /// If source or barse implementation errors.
+    fn read_seek_be<T: BarseSeek<ReadWith = ()>>(&mut self) -> Result<T, WrappedErr<Self::Err>>;
+
+    /// Read a value implementing [BarseSeek] using native endian.
+    ///
+    /// # Errors
+    /// If source or barse implementation errors.
+    fn read_seek_ne<T: BarseSeek<ReadWith = ()>>(&mut self) -> Result<T, WrappedErr<Self::Err>>;
+}
+
+impl<S: SeekSource> SeekSourceExt for S {
+    #[inline]
+    fn read_seek<T: BarseSeek<ReadWith = ()>, E: Endian>(
+        &mut self,
+    ) -> Result<T, WrappedErr<Self::Err>> {
+        Self::read_seek_with::<T, E>(self, ())
+    }
+
+    #[inline]
+    fn read_seek_with<T: BarseSeek, E: Endian>(
+        &mut self,
+        with: T::ReadWith,
+    ) -> Result<T, WrappedErr<Self::Err>> {
+        T::read_seek::<E, _>(&mut PtrGuard::<_>::new(self), with)
+    }
+
+    #[inline]
+    fn read_seek_le<T: BarseSeek<ReadWith = ()>>(&mut self) -> Result<T, WrappedErr<Self::Err>> {
+        Self::read_seek::<T, Little>(self)
+    }
+
+    #[inline]
+    fn read_seek_be<T: BarseSeek<ReadWith = ()>>(&mut self) -> Result<T, WrappedErr<Self::Err>> {
+        Self::read_seek::<T, Big>(self)
+    }
+
+    #[inline]
+    fn read_seek_ne<T: BarseSeek<ReadWith = ()>>(&mut self) -> Result<T, WrappedErr<Self::Err>> {
+        Self::read_seek::<T, Native>(self)
+    }
+}
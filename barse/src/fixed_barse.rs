@@ -0,0 +1,138 @@
+//! [FixedBarse] trait and implementations.
+
+use ::core::{convert::Infallible, marker::PhantomData};
+
+use crate::{sealed::Sealed, Barse, Empty, Endian, SliceSink, SliceSrc, WrappedErr};
+
+/// Byte arrays used by [FixedBarse], implemented for `[u8; N]`.
+pub trait FixedBytes: Sealed + AsRef<[u8]> + AsMut<[u8]> + Copy {
+    /// Length of array.
+    const LEN: usize;
+
+    /// Get an array of zeroes.
+    fn zeroed() -> Self;
+}
+
+impl<const N: usize> FixedBytes for [u8; N] {
+    const LEN: usize = N;
+
+    #[inline]
+    fn zeroed() -> Self {
+        [0u8; N]
+    }
+}
+
+/// [Barse] implementor always reading and writing the same amount of bytes.
+///
+/// Derived for structs using `#[barse(fixed)]`, which requires all fields to implement it.
+pub trait FixedBarse: Barse {
+    /// Amount of bytes read and written.
+    const SIZE: usize;
+
+    /// Byte array of length [SIZE][FixedBarse::SIZE].
+    type Bytes: FixedBytes;
+
+    /// Write an instance to an array with given endianess.
+    ///
+    /// # Errors
+    /// If implementation errors.
+    fn to_bytes_with<E>(&self, with: Self::WriteWith) -> Result<Self::Bytes, WrappedErr<Infallible>>
+    where
+        E: Endian,
+    {
+        let mut bytes = Self::Bytes::zeroed();
+        self.write_with::<E, _>(&mut SliceSink::new(bytes.as_mut()), with)
+            .map_err(WrappedErr::rewrap)?;
+        Ok(bytes)
+    }
+
+    /// Read an instance from an array with given endianess.
+    ///
+    /// # Errors
+    /// If implementation errors.
+    fn from_bytes_with<E>(
+        bytes: &Self::Bytes,
+        with: Self::ReadWith,
+    ) -> Result<Self, WrappedErr<Infallible>>
+    where
+        E: Endian,
+    {
+        Self::read_with::<E, _>(&mut SliceSrc::new(bytes.as_ref()), with)
+            .map_err(WrappedErr::rewrap)
+    }
+
+    /// Write an instance to an array with given endianess, where no with value is needed.
+    ///
+    /// # Errors
+    /// If implementation errors.
+    fn to_bytes<E>(&self) -> Result<Self::Bytes, WrappedErr<Infallible>>
+    where
+        E: Endian,
+        Self::WriteWith: Empty,
+    {
+        self.to_bytes_with::<E>(Self::WriteWith::instance())
+    }
+
+    /// Read an instance from an array with given endianess, where no with value is needed.
+    ///
+    /// # Errors
+    /// If implementation errors.
+    fn from_bytes<E>(bytes: &Self::Bytes) -> Result<Self, WrappedErr<Infallible>>
+    where
+        E: Endian,
+        Self::ReadWith: Empty,
+    {
+        Self::from_bytes_with::<E>(bytes, Self::ReadWith::instance())
+    }
+}
+
+integer_impl!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+impl<const N: usize> FixedBarse for [u8; N] {
+    const SIZE: usize = N;
+    type Bytes = [u8; N];
+}
+
+impl<const N: usize> FixedBarse for [i8; N] {
+    const SIZE: usize = N;
+    type Bytes = [u8; N];
+}
+
+impl FixedBarse for () {
+    const SIZE: usize = 0;
+    type Bytes = [u8; 0];
+}
+
+impl<T> FixedBarse for PhantomData<T> {
+    const SIZE: usize = 0;
+    type Bytes = [u8; 0];
+}
+
+/// Implement [FixedBarse] for integers.
+macro_rules! integer_impl {
+    ($($ty:ty),*) => {$(
+        impl FixedBarse for $ty {
+            const SIZE: usize = size_of::<$ty>();
+            type Bytes = [u8; size_of::<$ty>()];
+        }
+    )*};
+}
+use integer_impl;
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    use crate::endian::{Big, Little};
+
+    use super::*;
+
+    #[test]
+    fn to_from_bytes() {
+        assert_eq!(0x0102_u16.to_bytes::<Big>().unwrap(), [1, 2]);
+        assert_eq!(u32::from_bytes::<Little>(&[1, 0, 0, 0]).unwrap(), 1);
+        assert_eq!(<[u8; 3]>::from_bytes::<Big>(b"abc").unwrap(), *b"abc");
+        assert_eq!(<i128 as FixedBarse>::SIZE, 16);
+        assert_eq!(().to_bytes::<Big>().unwrap(), []);
+    }
+}
@@ -0,0 +1,56 @@
+//! [GuardedSource] trait.
+
+use crate::{Error, SeekSource};
+
+/// [SeekSource] keeping track of positions being read through pointers, such that pointer cycles
+/// are detected instead of recursing forever.
+///
+/// Values implementing [BarseSeek][crate::BarseSeek] are read from guarded sources only, any
+/// [SeekSource] may be guarded using [PtrGuard][crate::adapter::PtrGuard].
+pub trait GuardedSource: SeekSource {
+    /// Mark a position as being read through a pointer, before the pointer is followed.
+    ///
+    /// # Errors
+    /// If position is already being read through another pointer, or too many pointers are
+    /// being followed.
+    fn enter_ptr(&mut self, pos: u64) -> Result<(), Error>;
+
+    /// Unmark a position marked by [GuardedSource::enter_ptr], once the pointer has been
+    /// followed.
+    fn leave_ptr(&mut self, pos: u64);
+}
+
+impl<Src> GuardedSource for &mut Src
+where
+    Src: GuardedSource,
+{
+    #[inline]
+    fn enter_ptr(&mut self, pos: u64) -> Result<(), Error> {
+        Src::enter_ptr(self, pos)
+    }
+
+    #[inline]
+    fn leave_ptr(&mut self, pos: u64) {
+        Src::leave_ptr(self, pos);
+    }
+}
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+impl<Src> GuardedSource for alloc::boxed::Box<Src>
+where
+    Src: GuardedSource,
+{
+    #[inline]
+    fn enter_ptr(&mut self, pos: u64) -> Result<(), Error> {
+        Src::enter_ptr(self, pos)
+    }
+
+    #[inline]
+    fn leave_ptr(&mut self, pos: u64) {
+        Src::leave_ptr(self, pos);
+    }
+}
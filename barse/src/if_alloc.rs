@@ -2,9 +2,18 @@
 
 use ::core::convert::Infallible;
 
-use alloc::{borrow::Cow, boxed::Box};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    collections::VecDeque,
+    string::String,
+    vec::Vec,
+};
 
-use crate::{Barse, ByteSink, ByteSource};
+use crate::{
+    barse_seek::forward_seek, Barse, BarseSeek, ByteSink, ByteSource, Collection, Empty, Endian,
+    Error, GuardedSource, SeekSink, WrappedErr,
+};
 
 extern crate alloc;
 
@@ -15,6 +24,11 @@ impl ByteSink for alloc::vec::Vec<u8> {
         self.extend_from_slice(buf);
         Ok(())
     }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        Some(self.len() as u64)
+    }
 }
 
 impl<T> Barse for Box<T>
@@ -49,6 +63,14 @@ where
     {
         T::write_with::<E, _>(self, to, with)
     }
+
+    #[inline]
+    fn encoded_len(
+        &self,
+        with: Self::WriteWith,
+    ) -> Result<usize, crate::WrappedErr<::core::convert::Infallible>> {
+        T::encoded_len(self, with)
+    }
 }
 
 impl<'a, T> Barse for Cow<'a, T>
@@ -84,4 +106,510 @@ where
     {
         T::write_with::<E, _>(self, to, with)
     }
+
+    #[inline]
+    fn encoded_len(
+        &self,
+        with: Self::WriteWith,
+    ) -> Result<usize, crate::WrappedErr<::core::convert::Infallible>> {
+        T::encoded_len(self, with)
+    }
+}
+
+/// Largest amount of items allocated up front when reading collections, since counts may come
+/// from untrusted input.
+const PREALLOC_LIMIT: usize = 1024;
+
+/// Read count items.
+///
+/// # Errors
+/// If an item cannot be read.
+fn read_items<T, E, B>(from: &mut B, count: usize) -> Result<Vec<T>, WrappedErr<B::Err>>
+where
+    T: Barse,
+    T::ReadWith: Empty,
+    E: Endian,
+    B: ByteSource,
+{
+    let mut items = Vec::with_capacity(count.min(PREALLOC_LIMIT));
+    for i in 0..count {
+        items.push(
+            T::read_with::<E, B>(from, T::ReadWith::instance()).map_err(|err| err.at_index(i))?,
+        );
+    }
+    Ok(items)
+}
+
+/// Write all items.
+///
+/// # Errors
+/// If an item cannot be written.
+fn write_items<T, E, B>(
+    items: &[T],
+    to: &mut B,
+    with: T::WriteWith,
+) -> Result<(), WrappedErr<B::Err>>
+where
+    T: Barse,
+    T::WriteWith: Clone,
+    E: Endian,
+    B: ByteSink,
+{
+    for (i, item) in items.iter().enumerate() {
+        item.write_with::<E, B>(to, with.clone())
+            .map_err(|err| err.at_index(i))?;
+    }
+    Ok(())
+}
+
+/// Sum encoded length of all items.
+///
+/// # Errors
+/// If the length of an item cannot be determined.
+fn items_len<T>(items: &[T], with: T::WriteWith) -> Result<usize, WrappedErr<Infallible>>
+where
+    T: Barse,
+    T::WriteWith: Clone,
+{
+    items.iter().enumerate().try_fold(0usize, |len, (i, item)| {
+        let item_len = item
+            .encoded_len(with.clone())
+            .map_err(|err| err.at_index(i))?;
+        Ok(len.saturating_add(item_len))
+    })
+}
+
+/// Items are read using the count given as [ReadWith][Barse::ReadWith], no count is written.
+impl<T> Barse for Vec<T>
+where
+    T: Barse,
+    T::ReadWith: Empty,
+    T::WriteWith: Clone,
+{
+    type ReadWith = usize;
+
+    type WriteWith = T::WriteWith;
+
+    #[inline]
+    fn read_with<E, B>(from: &mut B, with: Self::ReadWith) -> Result<Self, WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: ByteSource,
+    {
+        read_items::<T, E, B>(from, with)
+    }
+
+    #[inline]
+    fn write_with<E, B>(&self, to: &mut B, with: Self::WriteWith) -> Result<(), WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: ByteSink,
+    {
+        write_items::<T, E, B>(self, to, with)
+    }
+
+    #[inline]
+    fn encoded_len(&self, with: Self::WriteWith) -> Result<usize, WrappedErr<Infallible>> {
+        items_len(self, with)
+    }
+}
+
+/// Items are read using the count given as [ReadWith][Barse::ReadWith], no count is written.
+impl<T> Barse for Box<[T]>
+where
+    T: Barse,
+    T::ReadWith: Empty,
+    T::WriteWith: Clone,
+{
+    type ReadWith = usize;
+
+    type WriteWith = T::WriteWith;
+
+    #[inline]
+    fn read_with<E, B>(from: &mut B, with: Self::ReadWith) -> Result<Self, WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: ByteSource,
+    {
+        read_items::<T, E, B>(from, with).map(Vec::into_boxed_slice)
+    }
+
+    #[inline]
+    fn write_with<E, B>(&self, to: &mut B, with: Self::WriteWith) -> Result<(), WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: ByteSink,
+    {
+        write_items::<T, E, B>(self, to, with)
+    }
+
+    #[inline]
+    fn encoded_len(&self, with: Self::WriteWith) -> Result<usize, WrappedErr<Infallible>> {
+        items_len(self, with)
+    }
+}
+
+/// UTF-8 bytes are read using the byte count given as [ReadWith][Barse::ReadWith], no count is
+/// written.
+impl Barse for String {
+    type ReadWith = usize;
+
+    type WriteWith = ();
+
+    fn read_with<E, B>(from: &mut B, with: Self::ReadWith) -> Result<Self, WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: ByteSource,
+    {
+        let mut bytes = Vec::with_capacity(with.min(PREALLOC_LIMIT));
+        let mut chunk = [0u8; 64];
+        let mut count = with;
+        while count != 0 {
+            let chunk = &mut chunk[..count.min(64)];
+            from.read_slice(chunk)?;
+            bytes.extend_from_slice(chunk);
+            count -= chunk.len();
+        }
+        String::from_utf8(bytes)
+            .map_err(|_| WrappedErr::from_err(Error::Msg("string was not valid UTF-8")))
+    }
+
+    #[inline]
+    fn write_with<E, B>(&self, to: &mut B, _with: Self::WriteWith) -> Result<(), WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: ByteSink,
+    {
+        Ok(to.write_slice(self.as_bytes())?)
+    }
+
+    #[inline]
+    fn encoded_len(&self, _with: Self::WriteWith) -> Result<usize, WrappedErr<Infallible>> {
+        Ok(self.len())
+    }
+}
+
+/// Value is read if the flag given as [ReadWith][Barse::ReadWith] is true, no flag is written.
+impl<T> Barse for Option<T>
+where
+    T: Barse,
+    T::ReadWith: Empty,
+{
+    type ReadWith = bool;
+
+    type WriteWith = T::WriteWith;
+
+    #[inline]
+    fn read_with<E, B>(from: &mut B, with: Self::ReadWith) -> Result<Self, WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: ByteSource,
+    {
+        if with {
+            T::read_with::<E, B>(from, T::ReadWith::instance()).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    #[inline]
+    fn write_with<E, B>(&self, to: &mut B, with: Self::WriteWith) -> Result<(), WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: ByteSink,
+    {
+        match self {
+            Some(value) => value.write_with::<E, B>(to, with),
+            None => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn encoded_len(&self, with: Self::WriteWith) -> Result<usize, WrappedErr<Infallible>> {
+        match self {
+            Some(value) => value.encoded_len(with),
+            None => Ok(0),
+        }
+    }
+}
+
+forward_seek! {
+    impl[] String;
+    impl['a, T] Cow<'a, T> where [T: ToOwned + 'a];
+}
+
+impl<T> BarseSeek for Box<T>
+where
+    T: BarseSeek,
+{
+    type ReadWith = T::ReadWith;
+
+    type WriteWith = T::WriteWith;
+
+    #[inline]
+    fn read_seek<E, B>(from: &mut B, with: Self::ReadWith) -> Result<Self, WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: GuardedSource,
+    {
+        T::read_seek::<E, B>(from, with).map(Box::new)
+    }
+
+    #[inline]
+    fn write_seek<E, B>(&self, to: &mut B, with: Self::WriteWith) -> Result<(), WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: SeekSink,
+    {
+        T::write_seek::<E, B>(self, to, with)
+    }
+}
+
+/// Read count items from a guarded source.
+///
+/// # Errors
+/// If an item cannot be read.
+fn read_seek_items<T, E, B>(from: &mut B, count: usize) -> Result<Vec<T>, WrappedErr<B::Err>>
+where
+    T: BarseSeek,
+    T::ReadWith: Empty,
+    E: Endian,
+    B: GuardedSource,
+{
+    let mut items = Vec::with_capacity(count.min(PREALLOC_LIMIT));
+    for i in 0..count {
+        items.push(
+            T::read_seek::<E, B>(from, T::ReadWith::instance()).map_err(|err| err.at_index(i))?,
+        );
+    }
+    Ok(items)
+}
+
+/// Write all items to a seekable sink.
+///
+/// # Errors
+/// If an item cannot be written.
+fn write_seek_items<T, E, B>(
+    items: &[T],
+    to: &mut B,
+    with: T::WriteWith,
+) -> Result<(), WrappedErr<B::Err>>
+where
+    T: BarseSeek,
+    T::WriteWith: Clone,
+    E: Endian,
+    B: SeekSink,
+{
+    for (i, item) in items.iter().enumerate() {
+        item.write_seek::<E, B>(to, with.clone())
+            .map_err(|err| err.at_index(i))?;
+    }
+    Ok(())
+}
+
+/// Items are read using the count given as [ReadWith][BarseSeek::ReadWith], no count is written.
+impl<T> BarseSeek for Vec<T>
+where
+    T: BarseSeek,
+    T::ReadWith: Empty,
+    T::WriteWith: Clone,
+{
+    type ReadWith = usize;
+
+    type WriteWith = T::WriteWith;
+
+    #[inline]
+    fn read_seek<E, B>(from: &mut B, with: Self::ReadWith) -> Result<Self, WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: GuardedSource,
+    {
+        read_seek_items::<T, E, B>(from, with)
+    }
+
+    #[inline]
+    fn write_seek<E, B>(&self, to: &mut B, with: Self::WriteWith) -> Result<(), WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: SeekSink,
+    {
+        write_seek_items::<T, E, B>(self, to, with)
+    }
+}
+
+/// Items are read using the count given as [ReadWith][BarseSeek::ReadWith], no count is written.
+impl<T> BarseSeek for Box<[T]>
+where
+    T: BarseSeek,
+    T::ReadWith: Empty,
+    T::WriteWith: Clone,
+{
+    type ReadWith = usize;
+
+    type WriteWith = T::WriteWith;
+
+    #[inline]
+    fn read_seek<E, B>(from: &mut B, with: Self::ReadWith) -> Result<Self, WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: GuardedSource,
+    {
+        read_seek_items::<T, E, B>(from, with).map(Vec::into_boxed_slice)
+    }
+
+    #[inline]
+    fn write_seek<E, B>(&self, to: &mut B, with: Self::WriteWith) -> Result<(), WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: SeekSink,
+    {
+        write_seek_items::<T, E, B>(self, to, with)
+    }
+}
+
+/// Value is read if the flag given as [ReadWith][BarseSeek::ReadWith] is true, no flag is
+/// written.
+impl<T> BarseSeek for Option<T>
+where
+    T: BarseSeek,
+    T::ReadWith: Empty,
+{
+    type ReadWith = bool;
+
+    type WriteWith = T::WriteWith;
+
+    #[inline]
+    fn read_seek<E, B>(from: &mut B, with: Self::ReadWith) -> Result<Self, WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: GuardedSource,
+    {
+        if with {
+            T::read_seek::<E, B>(from, T::ReadWith::instance()).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    #[inline]
+    fn write_seek<E, B>(&self, to: &mut B, with: Self::WriteWith) -> Result<(), WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: SeekSink,
+    {
+        match self {
+            Some(value) => value.write_seek::<E, B>(to, with),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T> Collection for Vec<T> {
+    type Item = T;
+    type Builder = Self;
+
+    #[inline]
+    fn builder(len: usize) -> Self::Builder {
+        Self::with_capacity(len.min(PREALLOC_LIMIT))
+    }
+
+    #[inline]
+    fn push(builder: &mut Self::Builder, item: Self::Item) -> Result<(), Error> {
+        builder.push(item);
+        Ok(())
+    }
+
+    #[inline]
+    fn finish(builder: Self::Builder) -> Result<Self, Error> {
+        Ok(builder)
+    }
+}
+
+impl<T> Collection for Box<[T]> {
+    type Item = T;
+    type Builder = Vec<T>;
+
+    #[inline]
+    fn builder(len: usize) -> Self::Builder {
+        <Vec<T> as Collection>::builder(len)
+    }
+
+    #[inline]
+    fn push(builder: &mut Self::Builder, item: Self::Item) -> Result<(), Error> {
+        <Vec<T> as Collection>::push(builder, item)
+    }
+
+    #[inline]
+    fn finish(builder: Self::Builder) -> Result<Self, Error> {
+        Ok(builder.into_boxed_slice())
+    }
+}
+
+impl<T> Collection for VecDeque<T> {
+    type Item = T;
+    type Builder = Self;
+
+    #[inline]
+    fn builder(len: usize) -> Self::Builder {
+        Self::with_capacity(len.min(PREALLOC_LIMIT))
+    }
+
+    #[inline]
+    fn push(builder: &mut Self::Builder, item: Self::Item) -> Result<(), Error> {
+        builder.push_back(item);
+        Ok(())
+    }
+
+    #[inline]
+    fn finish(builder: Self::Builder) -> Result<Self, Error> {
+        Ok(builder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    use alloc::{string::ToString, vec};
+
+    use crate::{endian::Little, SliceSrc};
+
+    use super::*;
+
+    #[test]
+    fn collections() {
+        let mut source = SliceSrc::new(b"\x01\x00\x02\x00hi!\xff");
+
+        assert_eq!(
+            Vec::<u16>::read_with::<Little, _>(&mut source, 2).unwrap(),
+            vec![1, 2]
+        );
+        assert_eq!(
+            String::read_with::<Little, _>(&mut source, 3).unwrap(),
+            "hi!"
+        );
+        assert_eq!(
+            Option::<u8>::read_with::<Little, _>(&mut source, false).unwrap(),
+            None
+        );
+        assert!(String::read_with::<Little, _>(&mut source, 1).is_err());
+
+        let mut bytes = Vec::new();
+        vec![1u16, 2]
+            .write_with::<Little, _>(&mut bytes, ())
+            .unwrap();
+        "hi!"
+            .to_string()
+            .write_with::<Little, _>(&mut bytes, ())
+            .unwrap();
+        Some(0xffu8)
+            .write_with::<Little, _>(&mut bytes, ())
+            .unwrap();
+        None::<u8>.write_with::<Little, _>(&mut bytes, ()).unwrap();
+        assert_eq!(bytes, b"\x01\x00\x02\x00hi!\xff");
+
+        let boxed = Box::<[u8]>::read_with::<Little, _>(&mut SliceSrc::new(b"abc"), 3).unwrap();
+        assert_eq!(&*boxed, b"abc");
+        assert_eq!(boxed.encoded_len(()).unwrap(), 3);
+    }
 }
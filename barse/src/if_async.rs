@@ -0,0 +1,59 @@
+//! Items requiring async support.
+#![expect(
+    clippy::future_not_send,
+    reason = "futures are Send whenever the source/sink and values used are"
+)]
+
+use ::futures_io::{AsyncRead, AsyncWrite};
+
+use crate::{Empty, WrappedErr};
+
+pub use self::{
+    async_barse::AsyncBarse, async_byte_sink::AsyncByteSink, async_byte_source::AsyncByteSource,
+    read_source::AsyncReadSource, write_sink::AsyncWriteSink,
+};
+
+mod async_barse;
+
+mod async_byte_source;
+
+mod async_byte_sink;
+
+mod read_source;
+
+mod write_sink;
+
+/// Write a value using native endian to an [AsyncWrite] implementor.
+///
+/// # Errors
+/// If [AsyncBarse] implementation errors.
+/// Or if the bytes resulting from it cannot be written.
+pub async fn write_value_async<T>(value: &T, to: impl AsyncWrite + Unpin) -> ::std::io::Result<()>
+where
+    T: AsyncBarse<WriteWith: Empty>,
+{
+    T::write_with_async::<crate::endian::Native, _>(
+        value,
+        &mut AsyncWriteSink::new(to),
+        Empty::instance(),
+    )
+    .await
+    .map_err(WrappedErr::merge_into)
+}
+
+/// Read a value using native endian from an [AsyncRead] implementor.
+///
+/// # Errors
+/// If [AsyncBarse] implementation errors.
+/// Or if the bytes needed cannot be read.
+pub async fn read_value_async<T>(from: impl AsyncRead + Unpin) -> ::std::io::Result<T>
+where
+    T: AsyncBarse<ReadWith: Empty>,
+{
+    T::read_with_async::<crate::endian::Native, _>(
+        &mut AsyncReadSource::new(from),
+        Empty::instance(),
+    )
+    .await
+    .map_err(WrappedErr::merge_into)
+}
@@ -0,0 +1,158 @@
+//! [AsyncBarse] trait and implementations.
+
+use ::core::{future::Future, marker::PhantomData};
+
+use crate::{AsyncByteSink, AsyncByteSource, Barse, Endian, WrappedErr};
+
+/// Trait to asynchronously serialize and deserialize from binary data.
+///
+/// Implementors should read and write the same bytes as their [Barse] implementation.
+pub trait AsyncBarse: Barse {
+    /// Read an instance from an async source with given endianess.
+    ///
+    /// # Errors
+    /// If Source or implementation errors.
+    fn read_with_async<E, B>(
+        from: &mut B,
+        with: Self::ReadWith,
+    ) -> impl Future<Output = Result<Self, WrappedErr<B::Err>>>
+    where
+        E: Endian,
+        B: AsyncByteSource;
+
+    /// Write an instance to an async sink with given endianess.
+    ///
+    /// # Errors
+    /// If Sink or implementation errors.
+    fn write_with_async<E, B>(
+        &self,
+        to: &mut B,
+        with: Self::WriteWith,
+    ) -> impl Future<Output = Result<(), WrappedErr<B::Err>>>
+    where
+        E: Endian,
+        B: AsyncByteSink;
+}
+
+integer_impl!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+impl<T, ReadWith, WriteWith, const N: usize> AsyncBarse for [T; N]
+where
+    T: AsyncBarse<ReadWith = ReadWith, WriteWith = WriteWith>,
+    ReadWith: Clone,
+    WriteWith: Clone,
+{
+    async fn read_with_async<E, B>(
+        from: &mut B,
+        with: Self::ReadWith,
+    ) -> Result<Self, WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: AsyncByteSource,
+    {
+        let mut values = [const { None }; N];
+        for (i, value) in values.iter_mut().enumerate() {
+            *value = Some(
+                T::read_with_async::<E, B>(from, with.clone())
+                    .await
+                    .map_err(|err| err.at_index(i))?,
+            );
+        }
+        Ok(values.map(|value| value.expect("all values should be some")))
+    }
+
+    async fn write_with_async<E, B>(
+        &self,
+        to: &mut B,
+        with: Self::WriteWith,
+    ) -> Result<(), WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: AsyncByteSink,
+    {
+        for (i, value) in self.iter().enumerate() {
+            T::write_with_async::<E, B>(value, to, with.clone())
+                .await
+                .map_err(|err| err.at_index(i))?;
+        }
+        Ok(())
+    }
+}
+
+impl AsyncBarse for () {
+    async fn read_with_async<E, B>(
+        _from: &mut B,
+        _with: Self::ReadWith,
+    ) -> Result<Self, WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: AsyncByteSource,
+    {
+        Ok(())
+    }
+
+    async fn write_with_async<E, B>(
+        &self,
+        _to: &mut B,
+        _with: Self::WriteWith,
+    ) -> Result<(), WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: AsyncByteSink,
+    {
+        Ok(())
+    }
+}
+
+impl<T> AsyncBarse for PhantomData<T> {
+    async fn read_with_async<E, B>(
+        _from: &mut B,
+        _with: Self::ReadWith,
+    ) -> Result<Self, WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: AsyncByteSource,
+    {
+        Ok(PhantomData)
+    }
+
+    async fn write_with_async<E, B>(
+        &self,
+        _to: &mut B,
+        _with: Self::WriteWith,
+    ) -> Result<(), WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: AsyncByteSink,
+    {
+        Ok(())
+    }
+}
+
+/// Implement AsyncBarse trait for integers.
+macro_rules! integer_impl {
+    ($($ty:ty),*) => {
+        $(
+        impl AsyncBarse for $ty {
+            #[inline]
+            async fn read_with_async<E, B>(from: &mut B, _with: ()) -> Result<Self, WrappedErr<B::Err>>
+            where
+                E: Endian,
+                B: AsyncByteSource,
+            {
+                Ok(E::read::<Self>(from.read_array().await?))
+            }
+
+            #[inline]
+            async fn write_with_async<E, B>(&self, to: &mut B, _with: ()) -> Result<(), WrappedErr<B::Err>>
+            where
+                E: Endian,
+                B: AsyncByteSink
+            {
+                Ok(to.write_array(E::write::<Self>(*self)).await?)
+            }
+        }
+        )*
+    };
+}
+use integer_impl;
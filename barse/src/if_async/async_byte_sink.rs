@@ -0,0 +1,59 @@
+//! [AsyncByteSink] trait.
+
+use ::core::future::Future;
+
+/// Sink for asynchronous writing of bytes.
+pub trait AsyncByteSink: Sized {
+    /// Error reported by sink.
+    type Err;
+
+    /// Try to write buf to sink.
+    ///
+    /// # Errors
+    /// If bytes cannot be written or sink otherwise fails.
+    fn write_slice(&mut self, buf: &[u8]) -> impl Future<Output = Result<(), Self::Err>>;
+
+    /// Write an array of bytes.
+    ///
+    /// # Errors
+    /// If bytes cannot be written or sink otherwise fails.
+    #[inline]
+    fn write_array<const N: usize>(
+        &mut self,
+        bytes: [u8; N],
+    ) -> impl Future<Output = Result<(), Self::Err>> {
+        async move { self.write_slice(&bytes).await }
+    }
+
+    /// Get offset of head from start of sink, if known. Used to give errors context, as such it
+    /// should be cheap to call.
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        None
+    }
+}
+
+impl<Sink> AsyncByteSink for &mut Sink
+where
+    Sink: AsyncByteSink,
+{
+    type Err = Sink::Err;
+
+    #[inline]
+    fn write_slice(&mut self, buf: &[u8]) -> impl Future<Output = Result<(), Self::Err>> {
+        Sink::write_slice(self, buf)
+    }
+
+    #[inline]
+    fn write_array<const N: usize>(
+        &mut self,
+        bytes: [u8; N],
+    ) -> impl Future<Output = Result<(), Self::Err>> {
+        Sink::write_array(self, bytes)
+    }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        Sink::offset(self)
+    }
+}
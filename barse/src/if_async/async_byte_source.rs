@@ -0,0 +1,118 @@
+//! [AsyncByteSource] trait.
+
+use ::core::future::Future;
+
+/// Source of bytes for asynchronous reading.
+pub trait AsyncByteSource: Sized {
+    /// Error reported by source.
+    type Err;
+
+    /// Try to fill buf with bytes.
+    ///
+    /// # Errors
+    /// If source cannot fill buffer, or otherwise fails.
+    fn read_slice(&mut self, buf: &mut [u8]) -> impl Future<Output = Result<(), Self::Err>>;
+
+    /// Read an array of bytes.
+    ///
+    /// # Errors
+    /// If N bytes cannot be read from source.
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> impl Future<Output = Result<[u8; N], Self::Err>> {
+        async move {
+            let mut bytes = [0u8; N];
+            self.read_slice(&mut bytes).await?;
+            Ok(bytes)
+        }
+    }
+
+    /// Read a single byte, or [None] if the end of source has been reached.
+    ///
+    /// By default [AsyncByteSource::remaining] is used to detect the end, sources not knowing it
+    /// should override this.
+    ///
+    /// # Errors
+    /// If the byte cannot be read from source.
+    #[inline]
+    fn read_byte_or_eof(&mut self) -> impl Future<Output = Result<Option<u8>, Self::Err>> {
+        async move {
+            if self.remaining() == Some(0) {
+                Ok(None)
+            } else {
+                let [byte] = self.read_array().await?;
+                Ok(Some(byte))
+            }
+        }
+    }
+
+    /// Skip bytes, as if they have been read.
+    ///
+    /// # Errors
+    /// If bytes cannot be skipped/read.
+    #[inline]
+    fn skip(&mut self, count: usize) -> impl Future<Output = Result<(), Self::Err>> {
+        async move {
+            let mut buf = [0u8; 64];
+            let mut count = count;
+            while count != 0 {
+                let len = count.min(buf.len());
+                self.read_slice(&mut buf[..len]).await?;
+                count -= len;
+            }
+            Ok(())
+        }
+    }
+
+    /// Get remaining bytes that may be read, if known.
+    ///
+    /// It may be possible to read more or fewer bytes than returned, but it should still be
+    /// treated as valid to error if too small.
+    #[inline]
+    fn remaining(&self) -> Option<usize> {
+        None
+    }
+
+    /// Get offset of head from start of source, if known. Used to give errors context, as such it
+    /// should be cheap to call.
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        None
+    }
+}
+
+impl<Src> AsyncByteSource for &mut Src
+where
+    Src: AsyncByteSource,
+{
+    type Err = Src::Err;
+
+    #[inline]
+    fn read_slice(&mut self, buf: &mut [u8]) -> impl Future<Output = Result<(), Self::Err>> {
+        Src::read_slice(self, buf)
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> impl Future<Output = Result<[u8; N], Self::Err>> {
+        Src::read_array(self)
+    }
+
+    #[inline]
+    fn read_byte_or_eof(&mut self) -> impl Future<Output = Result<Option<u8>, Self::Err>> {
+        Src::read_byte_or_eof(self)
+    }
+
+    #[inline]
+    fn skip(&mut self, count: usize) -> impl Future<Output = Result<(), Self::Err>> {
+        Src::skip(self, count)
+    }
+
+    #[inline]
+    fn remaining(&self) -> Option<usize> {
+        Src::remaining(self)
+    }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        Src::offset(self)
+    }
+}
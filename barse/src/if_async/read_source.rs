@@ -0,0 +1,83 @@
+//! [AsyncReadSource] impl.
+
+use ::core::{
+    future::poll_fn,
+    ops::{Deref, DerefMut},
+    pin::Pin,
+};
+use ::futures_io::AsyncRead;
+use ::std::io::ErrorKind;
+
+use crate::AsyncByteSource;
+
+/// [AsyncByteSource] implementor wrapping [AsyncRead] implementations.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsyncReadSource<R>(R);
+
+impl<R> AsyncReadSource<R> {
+    /// Construct a new instance from a value implementing [AsyncRead].
+    #[inline]
+    pub const fn new(value: R) -> Self
+    where
+        R: AsyncRead + Unpin,
+    {
+        Self(value)
+    }
+
+    /// Get wrapped value.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.0
+    }
+}
+
+impl<R> AsyncByteSource for AsyncReadSource<R>
+where
+    R: AsyncRead + Unpin,
+{
+    type Err = ::std::io::Error;
+
+    async fn read_slice(&mut self, mut buf: &mut [u8]) -> Result<(), Self::Err> {
+        while !buf.is_empty() {
+            match poll_fn(|cx| Pin::new(&mut self.0).poll_read(cx, buf)).await {
+                Ok(0) => return Err(ErrorKind::UnexpectedEof.into()),
+                Ok(count) => buf = &mut buf[count..],
+                Err(err) if err.kind() == ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(())
+    }
+
+    async fn read_byte_or_eof(&mut self) -> Result<Option<u8>, Self::Err> {
+        let mut byte = 0u8;
+        loop {
+            match poll_fn(|cx| {
+                Pin::new(&mut self.0).poll_read(cx, ::core::array::from_mut(&mut byte))
+            })
+            .await
+            {
+                Ok(0) => return Ok(None),
+                Ok(_) => return Ok(Some(byte)),
+                Err(err) if err.kind() == ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+impl<R> Deref for AsyncReadSource<R> {
+    type Target = R;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<R> DerefMut for AsyncReadSource<R> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
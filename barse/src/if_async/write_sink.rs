@@ -0,0 +1,81 @@
+//! [AsyncWriteSink] impl.
+
+use ::core::{
+    future::{poll_fn, Future},
+    ops::{Deref, DerefMut},
+    pin::Pin,
+};
+use ::futures_io::AsyncWrite;
+use ::std::io::ErrorKind;
+
+use crate::AsyncByteSink;
+
+/// [AsyncByteSink] implementor wrapping [AsyncWrite] implementations.
+///
+/// Written bytes are not flushed automatically, use [AsyncWriteSink::flush] when done.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsyncWriteSink<W>(W);
+
+impl<W> AsyncWriteSink<W> {
+    /// Construct a new instance from a value implementing [AsyncWrite].
+    #[inline]
+    pub const fn new(value: W) -> Self
+    where
+        W: AsyncWrite + Unpin,
+    {
+        Self(value)
+    }
+
+    /// Get wrapped value.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.0
+    }
+
+    /// Flush wrapped value.
+    ///
+    /// # Errors
+    /// If wrapped value cannot be flushed.
+    #[inline]
+    pub fn flush(&mut self) -> impl Future<Output = Result<(), ::std::io::Error>> + '_
+    where
+        W: AsyncWrite + Unpin,
+    {
+        poll_fn(|cx| Pin::new(&mut self.0).poll_flush(cx))
+    }
+}
+
+impl<W> AsyncByteSink for AsyncWriteSink<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    type Err = ::std::io::Error;
+
+    async fn write_slice(&mut self, mut buf: &[u8]) -> Result<(), Self::Err> {
+        while !buf.is_empty() {
+            match poll_fn(|cx| Pin::new(&mut self.0).poll_write(cx, buf)).await {
+                Ok(0) => return Err(ErrorKind::WriteZero.into()),
+                Ok(count) => buf = &buf[count..],
+                Err(err) if err.kind() == ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<W> Deref for AsyncWriteSink<W> {
+    type Target = W;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<W> DerefMut for AsyncWriteSink<W> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
@@ -2,17 +2,26 @@
 
 use ::std::io::{Cursor, Read, Write};
 
-use crate::{ByteSink, ByteSource, Empty, WrappedErr};
+use crate::{ByteSink, ByteSource, Empty, PeekSource, SeekSink, SeekSource, WrappedErr};
 
-pub use self::{read_source::ReadSource, write_sink::WriteSink};
+pub use self::{
+    buffered_sink::BufferedSink, buffered_source::BufferedSource, read_source::ReadSource,
+    write_sink::WriteSink,
+};
+
+pub mod skip;
 
 mod read_source;
 
 mod write_sink;
 
+mod buffered_source;
+
+mod buffered_sink;
+
 impl<A> ByteSource for Cursor<A>
 where
-    Cursor<A>: Read,
+    A: AsRef<[u8]>,
 {
     type Err = ::std::io::Error;
 
@@ -20,6 +29,64 @@ where
     fn read_slice(&mut self, buf: &mut [u8]) -> Result<(), Self::Err> {
         self.read_exact(buf)
     }
+
+    #[inline]
+    fn try_peek_slice(&mut self, buf: &mut [u8]) -> Result<bool, Self::Err> {
+        self.peek_slice(buf).map(|()| true)
+    }
+
+    #[inline]
+    fn skip(&mut self, count: usize) -> Result<(), Self::Err> {
+        if self.remaining().is_some_and(|remaining| count > remaining) {
+            return Err(::std::io::ErrorKind::UnexpectedEof.into());
+        }
+        self.set_position(Cursor::position(self) + count as u64);
+        Ok(())
+    }
+
+    #[inline]
+    fn remaining(&self) -> Option<usize> {
+        let len = self.get_ref().as_ref().len() as u64;
+        Some(len.saturating_sub(Cursor::position(self)) as usize)
+    }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        Some(Cursor::position(self))
+    }
+}
+
+impl<A> SeekSource for Cursor<A>
+where
+    A: AsRef<[u8]>,
+{
+    #[inline]
+    fn position(&mut self) -> Result<u64, Self::Err> {
+        Ok(Cursor::position(self))
+    }
+
+    #[inline]
+    fn seek_to(&mut self, pos: u64) -> Result<(), Self::Err> {
+        self.set_position(pos);
+        Ok(())
+    }
+}
+
+impl<A> PeekSource for Cursor<A>
+where
+    A: AsRef<[u8]>,
+{
+    #[inline]
+    fn peek_slice(&mut self, buf: &mut [u8]) -> Result<(), Self::Err> {
+        let bytes = self.get_ref().as_ref();
+        let start = usize::try_from(Cursor::position(self)).unwrap_or(usize::MAX);
+        let bytes = start
+            .checked_add(buf.len())
+            .and_then(|end| bytes.get(start..end))
+            .ok_or(::std::io::ErrorKind::UnexpectedEof)?;
+        buf.copy_from_slice(bytes);
+        Ok(())
+    }
 }
 
 impl<A> ByteSink for Cursor<A>
@@ -31,6 +98,27 @@ where
     fn write_slice(&mut self, buf: &[u8]) -> Result<(), Self::Err> {
         self.write_all(buf)
     }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        Some(Cursor::position(self))
+    }
+}
+
+impl<A> SeekSink for Cursor<A>
+where
+    Cursor<A>: Write,
+{
+    #[inline]
+    fn position(&mut self) -> Result<u64, Self::Err> {
+        Ok(Cursor::position(self))
+    }
+
+    #[inline]
+    fn seek_to(&mut self, pos: u64) -> Result<(), Self::Err> {
+        self.set_position(pos);
+        Ok(())
+    }
 }
 
 /// Write a value using native endian to a [Write] implementor.
@@ -58,3 +146,32 @@ where
     T::read_with::<crate::endian::Native, _>(&mut ReadSource::new(from), Empty::instance())
         .map_err(WrappedErr::merge_into)
 }
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    use super::*;
+
+    #[test]
+    fn cursor_seek() {
+        let mut source = Cursor::new(*b"0123456789");
+        ByteSource::skip(&mut source, 3).unwrap();
+        assert_eq!(SeekSource::position(&mut source).unwrap(), 3);
+        SeekSource::seek_to(&mut source, 7).unwrap();
+        assert_eq!(ByteSource::read_byte(&mut source).unwrap(), b'7');
+        assert!(ByteSource::skip(&mut source, 3).is_err());
+        SeekSource::seek_to(&mut source, 0).unwrap();
+        assert_eq!(ByteSource::read_array::<2>(&mut source).unwrap(), *b"01");
+
+        let mut sink = Cursor::new(Vec::new());
+        ByteSink::write_array(&mut sink, *b"abc").unwrap();
+        assert_eq!(SeekSink::position(&mut sink).unwrap(), 3);
+        SeekSink::seek_to(&mut sink, 5).unwrap();
+        ByteSink::write_byte(&mut sink, b'f').unwrap();
+        SeekSink::seek_to(&mut sink, 1).unwrap();
+        ByteSink::write_byte(&mut sink, b'B').unwrap();
+        assert_eq!(ByteSink::offset(&sink), Some(2));
+        assert_eq!(sink.into_inner(), b"aBc\0\0f");
+    }
+}
@@ -0,0 +1,209 @@
+//! [BufferedSink] impl.
+
+use ::std::io::{Seek, SeekFrom, Write};
+
+use crate::{ByteSink, SeekSink};
+
+/// Capacity used by [BufferedSink::new].
+const DEFAULT_CAPACITY: usize = 8 * 1024;
+
+/// [ByteSink] implementor wrapping [Write] implementations, writing through an internal buffer.
+///
+/// Bytes are only passed on to the wrapped value once the buffer is full or
+/// [BufferedSink::flush] is called, bytes not flushed are lost when dropped. If the wrapped value
+/// implements [Seek] so does this type, through [SeekSink], the buffer is flushed before seeking.
+pub struct BufferedSink<W> {
+    /// Wrapped value.
+    inner: W,
+
+    /// Internal buffer.
+    buf: Vec<u8>,
+
+    /// Capacity of buffer.
+    capacity: usize,
+
+    /// Offset of head from start of sink.
+    offset: u64,
+}
+
+impl<W> BufferedSink<W> {
+    /// Construct a new instance from a value implementing [Write], with a default capacity.
+    #[inline]
+    pub fn new(value: W) -> Self
+    where
+        W: Write,
+    {
+        Self::with_capacity(DEFAULT_CAPACITY, value)
+    }
+
+    /// Construct a new instance from a value implementing [Write], with given buffer capacity.
+    #[inline]
+    pub fn with_capacity(capacity: usize, value: W) -> Self
+    where
+        W: Write,
+    {
+        Self {
+            inner: value,
+            buf: Vec::with_capacity(capacity),
+            capacity,
+            offset: 0,
+        }
+    }
+
+    /// Get bytes that have been buffered but not yet flushed.
+    #[inline]
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf
+    }
+
+    /// Get capacity of internal buffer.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Get a reference to the wrapped value.
+    #[inline]
+    pub const fn get_ref(&self) -> &W {
+        &self.inner
+    }
+}
+
+impl<W> BufferedSink<W>
+where
+    W: Write,
+{
+    /// Write buffered bytes to wrapped value, then flush it.
+    ///
+    /// # Errors
+    /// If buffered bytes cannot be written or wrapped value cannot be flushed.
+    #[inline]
+    pub fn flush(&mut self) -> Result<(), ::std::io::Error> {
+        self.flush_buf()?;
+        self.inner.flush()
+    }
+
+    /// Flush buffer and get wrapped value.
+    ///
+    /// # Errors
+    /// If buffered bytes cannot be written.
+    #[inline]
+    pub fn into_inner(mut self) -> Result<W, ::std::io::Error> {
+        self.flush()?;
+        Ok(self.inner)
+    }
+
+    /// Write buffered bytes to wrapped value.
+    ///
+    /// # Errors
+    /// If buffered bytes cannot be written.
+    fn flush_buf(&mut self) -> Result<(), ::std::io::Error> {
+        self.inner.write_all(&self.buf)?;
+        self.buf.clear();
+        Ok(())
+    }
+}
+
+impl<W> ByteSink for BufferedSink<W>
+where
+    W: Write,
+{
+    type Err = ::std::io::Error;
+
+    #[inline]
+    fn write_slice(&mut self, buf: &[u8]) -> Result<(), Self::Err> {
+        if self.buf.len() + buf.len() > self.capacity {
+            self.flush_buf()?;
+        }
+        if buf.len() >= self.capacity {
+            // Large writes bypass the buffer.
+            self.inner.write_all(buf)?;
+        } else {
+            self.buf.extend_from_slice(buf);
+        }
+        self.offset += buf.len() as u64;
+        Ok(())
+    }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        Some(self.offset)
+    }
+}
+
+impl<W> SeekSink for BufferedSink<W>
+where
+    W: Write + Seek,
+{
+    #[inline]
+    fn position(&mut self) -> Result<u64, Self::Err> {
+        Ok(self.inner.stream_position()? + self.buf.len() as u64)
+    }
+
+    #[inline]
+    fn seek_to(&mut self, pos: u64) -> Result<(), Self::Err> {
+        self.flush_buf()?;
+        self.inner.seek(SeekFrom::Start(pos))?;
+        self.offset = pos;
+        Ok(())
+    }
+}
+
+impl<W> ::core::fmt::Debug for BufferedSink<W>
+where
+    W: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("BufferedSink")
+            .field("inner", &self.inner)
+            .field("buffered", &self.buf.len())
+            .field("capacity", &self.capacity)
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    use ::std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn buffered_writes() {
+        let mut sink = BufferedSink::with_capacity(8, Vec::new());
+
+        sink.write_array(*b"abcd").unwrap();
+        sink.write_byte(b'e').unwrap();
+        assert!(sink.get_ref().is_empty());
+        assert_eq!(sink.buffer(), b"abcde");
+
+        sink.write_array(*b"fgh").unwrap();
+        sink.write_byte(b'i').unwrap();
+        assert_eq!(sink.get_ref(), b"abcdefgh");
+
+        sink.write_slice(b"0123456789").unwrap();
+        assert_eq!(sink.get_ref(), b"abcdefghi0123456789");
+        assert_eq!(sink.offset(), Some(19));
+
+        sink.write_byte(b'!').unwrap();
+        sink.flush().unwrap();
+        assert_eq!(sink.into_inner().unwrap(), b"abcdefghi0123456789!");
+    }
+
+    #[test]
+    fn seek() {
+        let mut sink = BufferedSink::new(Cursor::new(vec![0u8; 8]));
+
+        sink.write_array(*b"abcd").unwrap();
+        assert_eq!(sink.position().unwrap(), 4);
+        sink.seek_to(2).unwrap();
+        sink.write_array(*b"XY").unwrap();
+        assert_eq!(sink.offset(), Some(4));
+
+        let cursor = sink.into_inner().unwrap();
+        assert_eq!(cursor.into_inner(), b"abXY\0\0\0\0");
+    }
+}
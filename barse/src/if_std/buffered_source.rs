@@ -0,0 +1,386 @@
+//! [BufferedSource] impl.
+
+use ::core::marker::PhantomData;
+use ::std::io::{ErrorKind, Read, Seek, SeekFrom};
+
+use crate::{
+    if_std::skip::{Drain, SeekForward, Skip},
+    ByteSource, PeekSource, SeekSource,
+};
+
+/// Capacity used by [BufferedSource::new].
+const DEFAULT_CAPACITY: usize = 8 * 1024;
+
+/// [ByteSource] implementor wrapping [Read] implementations, reading through an internal buffer.
+///
+/// Small reads, such as those of integers, are served from the buffer without calling the
+/// wrapped value. If the wrapped value implements [Seek] so does this type, through
+/// [SeekSource], any buffered bytes are discarded when seeking. Bytes not buffered are skipped
+/// using S, by default they are read and discarded.
+///
+/// Skipping does not seek automatically for [Seek] implementors, call
+/// [BufferedSource::with_seek_skip] to skip bytes not buffered by seeking instead.
+pub struct BufferedSource<R, S = Drain> {
+    /// Wrapped value.
+    inner: R,
+
+    /// Internal buffer.
+    buf: Box<[u8]>,
+
+    /// Position of next unread byte in buffer.
+    pos: usize,
+
+    /// Amount of bytes of buffer that have been filled.
+    filled: usize,
+
+    /// Offset of head from start of source.
+    offset: u64,
+
+    /// Strategy used to skip bytes.
+    skip: PhantomData<S>,
+}
+
+impl<R> BufferedSource<R> {
+    /// Construct a new instance from a value implementing [Read], with a default capacity.
+    #[inline]
+    pub fn new(value: R) -> Self
+    where
+        R: Read,
+    {
+        Self::with_capacity(DEFAULT_CAPACITY, value)
+    }
+
+    /// Construct a new instance from a value implementing [Read], with given buffer capacity.
+    #[inline]
+    pub fn with_capacity(capacity: usize, value: R) -> Self
+    where
+        R: Read,
+    {
+        Self {
+            inner: value,
+            buf: vec![0u8; capacity.max(1)].into_boxed_slice(),
+            pos: 0,
+            filled: 0,
+            offset: 0,
+            skip: PhantomData,
+        }
+    }
+}
+
+impl<R, S> BufferedSource<R, S> {
+    /// Skip bytes not buffered by seeking forward instead of reading them.
+    #[inline]
+    pub fn with_seek_skip(self) -> BufferedSource<R, SeekForward>
+    where
+        R: Read + Seek,
+    {
+        let Self {
+            inner,
+            buf,
+            pos,
+            filled,
+            offset,
+            skip: _,
+        } = self;
+        BufferedSource {
+            inner,
+            buf,
+            pos,
+            filled,
+            offset,
+            skip: PhantomData,
+        }
+    }
+
+    /// Get bytes that have been buffered but not yet read.
+    #[inline]
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf[self.pos..self.filled]
+    }
+
+    /// Get capacity of internal buffer.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Get a reference to the wrapped value.
+    #[inline]
+    pub const fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Get wrapped value, any buffered bytes are lost.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Discard buffered bytes.
+    #[inline]
+    const fn discard(&mut self) {
+        self.pos = 0;
+        self.filled = 0;
+    }
+}
+
+impl<R, S> BufferedSource<R, S>
+where
+    R: Read,
+{
+    /// Look at the next n bytes without consuming them.
+    ///
+    /// The internal buffer grows should n be larger than its capacity.
+    ///
+    /// # Errors
+    /// If n bytes cannot be read from wrapped value.
+    #[inline]
+    pub fn peek(&mut self, n: usize) -> Result<&[u8], ::std::io::Error> {
+        self.fill_to(n)?;
+        Ok(&self.buf[self.pos..self.pos + n])
+    }
+
+    /// Make sure at least n bytes are buffered.
+    ///
+    /// # Errors
+    /// If the bytes cannot be read from wrapped value.
+    fn fill_to(&mut self, n: usize) -> Result<(), ::std::io::Error> {
+        if self.filled - self.pos >= n {
+            return Ok(());
+        }
+
+        if n > self.buf.len() {
+            let mut buf = ::core::mem::take(&mut self.buf).into_vec();
+            buf.resize(n, 0);
+            self.buf = buf.into_boxed_slice();
+        }
+
+        if self.pos + n > self.buf.len() {
+            self.buf.copy_within(self.pos..self.filled, 0);
+            self.filled -= self.pos;
+            self.pos = 0;
+        }
+
+        while self.filled - self.pos < n {
+            match self.inner.read(&mut self.buf[self.filled..]) {
+                Ok(0) => return Err(ErrorKind::UnexpectedEof.into()),
+                Ok(count) => self.filled += count,
+                Err(err) if err.kind() == ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<R, S> ByteSource for BufferedSource<R, S>
+where
+    R: Read,
+    S: Skip<R>,
+{
+    type Err = ::std::io::Error;
+
+    #[inline]
+    fn read_slice(&mut self, buf: &mut [u8]) -> Result<(), Self::Err> {
+        let available = self.filled - self.pos;
+        if buf.len() > available && buf.len() - available >= self.buf.len() {
+            // Large reads bypass the buffer.
+            let (head, tail) = buf.split_at_mut(available);
+            head.copy_from_slice(self.buffer());
+            self.discard();
+            self.inner.read_exact(tail)?;
+        } else {
+            self.fill_to(buf.len())?;
+            buf.copy_from_slice(&self.buf[self.pos..self.pos + buf.len()]);
+            self.pos += buf.len();
+        }
+        self.offset += buf.len() as u64;
+        Ok(())
+    }
+
+    #[inline]
+    fn read_byte(&mut self) -> Result<u8, Self::Err> {
+        self.fill_to(1)?;
+        let byte = self.buf[self.pos];
+        self.pos += 1;
+        self.offset += 1;
+        Ok(byte)
+    }
+
+    #[inline]
+    fn read_byte_or_eof(&mut self) -> Result<Option<u8>, Self::Err> {
+        match self.read_byte() {
+            Ok(byte) => Ok(Some(byte)),
+            Err(err) if err.kind() == ErrorKind::UnexpectedEof && self.pos == self.filled => {
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    #[inline]
+    fn try_peek_slice(&mut self, buf: &mut [u8]) -> Result<bool, Self::Err> {
+        self.peek_slice(buf).map(|()| true)
+    }
+
+    #[inline]
+    fn skip(&mut self, count: usize) -> Result<(), Self::Err> {
+        let available = self.filled - self.pos;
+        if count <= available {
+            self.pos += count;
+        } else {
+            self.discard();
+            S::skip(&mut self.inner, (count - available) as u64)?;
+        }
+        self.offset += count as u64;
+        Ok(())
+    }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        Some(self.offset)
+    }
+}
+
+impl<R, S> PeekSource for BufferedSource<R, S>
+where
+    R: Read,
+    S: Skip<R>,
+{
+    #[inline]
+    fn peek_slice(&mut self, buf: &mut [u8]) -> Result<(), Self::Err> {
+        buf.copy_from_slice(self.peek(buf.len())?);
+        Ok(())
+    }
+}
+
+impl<R, S> SeekSource for BufferedSource<R, S>
+where
+    R: Read + Seek,
+    S: Skip<R>,
+{
+    #[inline]
+    fn position(&mut self) -> Result<u64, Self::Err> {
+        let buffered = (self.filled - self.pos) as u64;
+        Ok(self.inner.stream_position()? - buffered)
+    }
+
+    #[inline]
+    fn seek_to(&mut self, pos: u64) -> Result<(), Self::Err> {
+        self.discard();
+        self.inner.seek(SeekFrom::Start(pos))?;
+        self.offset = pos;
+        Ok(())
+    }
+}
+
+impl<R, S> ::core::fmt::Debug for BufferedSource<R, S>
+where
+    R: ::core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("BufferedSource")
+            .field("inner", &self.inner)
+            .field("buffered", &(self.filled - self.pos))
+            .field("capacity", &self.buf.len())
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    use ::std::io::{Cursor, Read};
+
+    use super::*;
+
+    /// Reader counting calls to read.
+    struct Counting<R> {
+        /// Wrapped reader.
+        inner: R,
+
+        /// Calls made to read.
+        calls: usize,
+    }
+
+    impl<R: Read> Read for Counting<R> {
+        fn read(&mut self, buf: &mut [u8]) -> ::std::io::Result<usize> {
+            self.calls += 1;
+            self.inner.read(buf)
+        }
+    }
+
+    #[test]
+    fn buffered_reads() {
+        let bytes = (0..=255u8).collect::<Vec<_>>();
+        let mut src = BufferedSource::with_capacity(
+            64,
+            Counting {
+                inner: Cursor::new(&bytes),
+                calls: 0,
+            },
+        );
+
+        for expected in bytes.chunks(4).take(16) {
+            assert_eq!(src.read_array::<4>().unwrap(), expected);
+        }
+        assert_eq!(src.get_ref().calls, 1);
+        assert_eq!(src.offset(), Some(64));
+
+        let mut large = [0u8; 128];
+        src.read_slice(&mut large).unwrap();
+        assert_eq!(large, bytes[64..192]);
+        assert_eq!(src.get_ref().calls, 2);
+
+        src.skip(60).unwrap();
+        assert_eq!(src.read_byte().unwrap(), 252);
+        assert_eq!(src.offset(), Some(253));
+        src.read_array::<3>().unwrap();
+        assert!(src.read_byte().is_err());
+    }
+
+    #[test]
+    fn peek() {
+        let bytes = (0..100u8).collect::<Vec<_>>();
+        let mut src = BufferedSource::with_capacity(8, Cursor::new(&bytes));
+
+        assert_eq!(src.peek(4).unwrap(), [0, 1, 2, 3]);
+        assert_eq!(src.read_byte().unwrap(), 0);
+        assert_eq!(src.peek(16).unwrap(), &bytes[1..17]);
+        assert_eq!(src.read_array::<2>().unwrap(), [1, 2]);
+        assert_eq!(src.offset(), Some(3));
+        assert!(src.peek(100).is_err());
+        assert_eq!(src.peek_array::<3>().unwrap(), [3, 4, 5]);
+
+        src.seek_to(90).unwrap();
+        assert_eq!(src.peek(10).unwrap(), &bytes[90..]);
+        assert_eq!(src.position().unwrap(), 90);
+    }
+
+    #[test]
+    fn seek() {
+        let bytes = (0..100u8).collect::<Vec<_>>();
+        let mut src = BufferedSource::with_capacity(8, Cursor::new(&bytes)).with_seek_skip();
+
+        assert_eq!(src.read_byte().unwrap(), 0);
+        assert_eq!(src.position().unwrap(), 1);
+        src.skip(4).unwrap();
+        assert_eq!(src.read_byte().unwrap(), 5);
+        src.skip(50).unwrap();
+        assert_eq!(src.get_ref().position(), 56);
+        assert_eq!(src.read_byte().unwrap(), 56);
+        assert_eq!(src.offset(), Some(57));
+        assert_eq!(src.position().unwrap(), 57);
+
+        src.seek_to(10).unwrap();
+        assert_eq!(src.read_array::<2>().unwrap(), [10, 11]);
+        assert_eq!(src.position().unwrap(), 12);
+        assert!(src.skip(100).is_err());
+        src.seek_to(99).unwrap();
+        assert_eq!(src.read_byte().unwrap(), 99);
+        assert!(src.read_byte().is_err());
+    }
+}
@@ -1,13 +1,25 @@
 //! [ReadSource] impl.
 
-use ::core::ops::{Deref, DerefMut};
-use ::std::io::Read;
+use ::core::{
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+use ::std::io::{ErrorKind, Read, Seek, SeekFrom};
 
-use crate::ByteSource;
+use crate::{
+    if_std::skip::{Drain, SeekForward, Skip},
+    ByteSource, SeekSource,
+};
 
 /// [ByteSource] implementor wrapping [Read] implementations.
+///
+/// If the wrapped value implements [Seek] so does this type, through [SeekSource]. Bytes are
+/// skipped using S, by default they are read and discarded.
+///
+/// Skipping does not seek automatically for [Seek] implementors, call
+/// [ReadSource::with_seek_skip] to skip by seeking instead.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct ReadSource<R>(R);
+pub struct ReadSource<R, S = Drain>(R, PhantomData<S>);
 
 impl<R> ReadSource<R> {
     /// Construct a new instance from a value implementing [Read].
@@ -16,7 +28,18 @@ impl<R> ReadSource<R> {
     where
         R: Read,
     {
-        Self(value)
+        Self(value, PhantomData)
+    }
+}
+
+impl<R, S> ReadSource<R, S> {
+    /// Skip bytes by seeking forward instead of reading them.
+    #[inline]
+    pub fn with_seek_skip(self) -> ReadSource<R, SeekForward>
+    where
+        R: Read + Seek,
+    {
+        ReadSource(self.0, PhantomData)
     }
 
     /// Get wrapped value.
@@ -26,9 +49,10 @@ impl<R> ReadSource<R> {
     }
 }
 
-impl<R> ByteSource for ReadSource<R>
+impl<R, S> ByteSource for ReadSource<R, S>
 where
     R: Read,
+    S: Skip<R>,
 {
     type Err = ::std::io::Error;
 
@@ -36,9 +60,43 @@ where
     fn read_slice(&mut self, buf: &mut [u8]) -> Result<(), Self::Err> {
         R::read_exact(self, buf)
     }
+
+    #[inline]
+    fn read_byte_or_eof(&mut self) -> Result<Option<u8>, Self::Err> {
+        let mut byte = 0u8;
+        loop {
+            match self.0.read(::core::array::from_mut(&mut byte)) {
+                Ok(0) => return Ok(None),
+                Ok(_) => return Ok(Some(byte)),
+                Err(err) if err.kind() == ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    #[inline]
+    fn skip(&mut self, count: usize) -> Result<(), Self::Err> {
+        S::skip(&mut self.0, count as u64)
+    }
 }
 
-impl<R> Deref for ReadSource<R> {
+impl<R, S> SeekSource for ReadSource<R, S>
+where
+    R: Read + Seek,
+    S: Skip<R>,
+{
+    #[inline]
+    fn position(&mut self) -> Result<u64, Self::Err> {
+        self.0.stream_position()
+    }
+
+    #[inline]
+    fn seek_to(&mut self, pos: u64) -> Result<(), Self::Err> {
+        self.0.seek(SeekFrom::Start(pos)).map(|_| ())
+    }
+}
+
+impl<R, S> Deref for ReadSource<R, S> {
     type Target = R;
 
     #[inline]
@@ -47,9 +105,48 @@ impl<R> Deref for ReadSource<R> {
     }
 }
 
-impl<R> DerefMut for ReadSource<R> {
+impl<R, S> DerefMut for ReadSource<R, S> {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    use ::std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn seek() {
+        let mut source = ReadSource::new(Cursor::new(*b"0123456789"));
+        assert_eq!(source.read_byte().unwrap(), b'0');
+        source.skip(2).unwrap();
+        assert_eq!(source.position().unwrap(), 3);
+        source.seek_to(8).unwrap();
+        assert_eq!(source.read_byte().unwrap(), b'8');
+        assert!(source.skip(2).is_err());
+
+        let mut source = ReadSource::new(Cursor::new(*b"0123456789")).with_seek_skip();
+        source.skip(4).unwrap();
+        assert_eq!(source.read_byte().unwrap(), b'4');
+        assert!(source.skip(6).is_err());
+        assert_eq!(source.position().unwrap(), 5);
+        source.skip(5).unwrap();
+        assert!(source.read_byte().is_err());
+        source.seek_to(1).unwrap();
+        assert_eq!(source.read_byte().unwrap(), b'1');
+    }
+    #[test]
+    fn eof() {
+        let mut source = ReadSource::new(Cursor::new(*b"01"));
+        assert!(!source.try_peek_slice(&mut [0u8]).unwrap());
+        assert_eq!(source.read_byte_or_eof().unwrap(), Some(b'0'));
+        assert_eq!(source.read_byte().unwrap(), b'1');
+        assert_eq!(source.read_byte_or_eof().unwrap(), None);
+        assert!(source.read_byte().is_err());
+    }
+}
@@ -0,0 +1,79 @@
+//! [Skip] strategies of [ReadSource][crate::ReadSource] and
+//! [BufferedSource][crate::BufferedSource].
+
+use ::std::io::{ErrorKind, Read, Seek, SeekFrom};
+
+/// Strategy used to skip bytes of a wrapped value R.
+pub trait Skip<R> {
+    /// Skip count bytes of inner.
+    ///
+    /// # Errors
+    /// If inner has less than count bytes left, or if it errors.
+    fn skip(inner: &mut R, count: u64) -> ::std::io::Result<()>;
+}
+
+/// [Skip] strategy reading and discarding bytes, works for any [Read] implementor.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Drain;
+
+impl<R> Skip<R> for Drain
+where
+    R: Read,
+{
+    #[inline]
+    fn skip(inner: &mut R, count: u64) -> ::std::io::Result<()> {
+        let skipped = ::std::io::copy(&mut inner.take(count), &mut ::std::io::sink())?;
+        if skipped == count {
+            Ok(())
+        } else {
+            Err(ErrorKind::UnexpectedEof.into())
+        }
+    }
+}
+
+/// [Skip] strategy seeking forward, for [Seek] implementors.
+///
+/// Skipping past the end is an error, in which case the position is left unchanged.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SeekForward;
+
+impl<R> Skip<R> for SeekForward
+where
+    R: Seek,
+{
+    #[inline]
+    fn skip(inner: &mut R, count: u64) -> ::std::io::Result<()> {
+        let pos = inner.stream_position()?;
+        let end = inner.seek(SeekFrom::End(0))?;
+        let target = pos.checked_add(count).filter(|target| *target <= end);
+        inner.seek(SeekFrom::Start(target.unwrap_or(pos)))?;
+        target
+            .map(|_| ())
+            .ok_or_else(|| ErrorKind::UnexpectedEof.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    use ::std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn seek_forward() {
+        let mut cursor = Cursor::new([0u8; 8]);
+        SeekForward::skip(&mut cursor, 5).unwrap();
+        assert_eq!(cursor.position(), 5);
+        assert!(SeekForward::skip(&mut cursor, 4).is_err());
+        assert_eq!(cursor.position(), 5);
+        SeekForward::skip(&mut cursor, 3).unwrap();
+        assert_eq!(cursor.position(), 8);
+
+        let mut cursor = Cursor::new([0u8; 8]);
+        Drain::skip(&mut cursor, 5).unwrap();
+        assert_eq!(cursor.position(), 5);
+        assert!(Drain::skip(&mut cursor, 4).is_err());
+    }
+}
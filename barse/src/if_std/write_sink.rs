@@ -1,11 +1,13 @@
 //! [WriteSink] impl.
 
 use ::core::ops::{Deref, DerefMut};
-use ::std::io::Write;
+use ::std::io::{Seek, SeekFrom, Write};
 
-use crate::ByteSink;
+use crate::{ByteSink, SeekSink};
 
 /// [ByteSink] implementor wrapping [Write] implementations.
+///
+/// If the wrapped value implements [Seek] so does this type, through [SeekSink].
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct WriteSink<W>(W);
 
@@ -38,6 +40,21 @@ where
     }
 }
 
+impl<W> SeekSink for WriteSink<W>
+where
+    W: Write + Seek,
+{
+    #[inline]
+    fn position(&mut self) -> Result<u64, Self::Err> {
+        self.0.stream_position()
+    }
+
+    #[inline]
+    fn seek_to(&mut self, pos: u64) -> Result<(), Self::Err> {
+        self.0.seek(SeekFrom::Start(pos)).map(|_| ())
+    }
+}
+
 impl<W> Deref for WriteSink<W> {
     type Target = W;
 
@@ -53,3 +70,25 @@ impl<W> DerefMut for WriteSink<W> {
         &mut self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    use ::std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn seek() {
+        let mut sink = WriteSink::new(Cursor::new(vec![0u8; 4]));
+        sink.write_array(*b"ab").unwrap();
+        assert_eq!(sink.position().unwrap(), 2);
+        sink.seek_to(6).unwrap();
+        sink.write_byte(b'g').unwrap();
+        sink.seek_to(0).unwrap();
+        sink.write_byte(b'A').unwrap();
+        assert_eq!(sink.position().unwrap(), 1);
+        assert_eq!(sink.into_inner().into_inner(), b"Ab\0\0\0\0g");
+    }
+}
@@ -8,16 +8,38 @@ pub mod error;
 
 mod barse;
 
+mod barse_borrow;
+
+mod barse_seek;
+
+mod fixed_barse;
+
 mod byte_source;
 
 mod byte_sink;
 
+mod borrow_source;
+
+mod seek_source;
+
+mod guarded_source;
+
+mod peek_source;
+
+mod seek_sink;
+
 mod empty_with;
 
 mod slice_source;
 
 mod slice_sink;
 
+mod counting_sink;
+
+mod collection;
+
+pub mod adapter;
+
 #[cfg_attr(docsrs, doc(cfg(feature = "ext")))]
 #[cfg(feature = "ext")]
 pub mod ext;
@@ -38,15 +60,29 @@ mod if_std;
 #[cfg(feature = "alloc")]
 mod if_alloc;
 
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+#[cfg(feature = "async")]
+mod if_async;
+
 mod sealed;
 
 #[doc(inline)]
 pub use self::{
     barse::Barse,
+    barse_borrow::BarseBorrow,
+    barse_seek::BarseSeek,
+    borrow_source::BorrowSource,
     byte_sink::ByteSink,
     byte_source::ByteSource,
+    collection::Collection,
+    counting_sink::CountingSink,
     empty_with::Empty,
     error::{Error, WrappedErr},
+    fixed_barse::{FixedBarse, FixedBytes},
+    guarded_source::GuardedSource,
+    peek_source::PeekSource,
+    seek_sink::SeekSink,
+    seek_source::SeekSource,
     slice_sink::SliceSink,
     slice_source::SliceSrc,
 };
@@ -68,4 +104,16 @@ pub use if_std::{read_value, write_value};
 
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-pub use if_std::{ReadSource, WriteSink};
+pub use if_std::{BufferedSink, BufferedSource, ReadSource, WriteSink};
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use if_std::skip;
+
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub use if_async::{read_value_async, write_value_async};
+
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub use if_async::{AsyncBarse, AsyncByteSink, AsyncByteSource, AsyncReadSource, AsyncWriteSink};
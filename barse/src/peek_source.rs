@@ -0,0 +1,61 @@
+//! [PeekSource] trait.
+
+use crate::ByteSource;
+
+/// [ByteSource] able to look at upcoming bytes without consuming them.
+///
+/// Implementors should also override [ByteSource::try_peek_slice], which is used by peeking enums
+/// of the derive as it is available for any source.
+pub trait PeekSource: ByteSource {
+    /// Fill buf with upcoming bytes, without moving head.
+    ///
+    /// # Errors
+    /// If source cannot fill buffer, or otherwise fails.
+    fn peek_slice(&mut self, buf: &mut [u8]) -> Result<(), Self::Err>;
+
+    /// Peek an array of bytes, without moving head.
+    ///
+    /// # Errors
+    /// If N bytes cannot be peeked from source.
+    #[inline]
+    fn peek_array<const N: usize>(&mut self) -> Result<[u8; N], Self::Err> {
+        let mut bytes = [0u8; N];
+        self.peek_slice(&mut bytes)?;
+        Ok(bytes)
+    }
+}
+
+impl<Src> PeekSource for &mut Src
+where
+    Src: PeekSource,
+{
+    #[inline]
+    fn peek_slice(&mut self, buf: &mut [u8]) -> Result<(), Self::Err> {
+        Src::peek_slice(self, buf)
+    }
+
+    #[inline]
+    fn peek_array<const N: usize>(&mut self) -> Result<[u8; N], Self::Err> {
+        Src::peek_array(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+impl<Src> PeekSource for alloc::boxed::Box<Src>
+where
+    Src: PeekSource,
+{
+    #[inline]
+    fn peek_slice(&mut self, buf: &mut [u8]) -> Result<(), Self::Err> {
+        Src::peek_slice(self, buf)
+    }
+
+    #[inline]
+    fn peek_array<const N: usize>(&mut self) -> Result<[u8; N], Self::Err> {
+        Src::peek_array(self)
+    }
+}
@@ -3,6 +3,15 @@
 /// Trait used to prevent implementations.
 pub trait Sealed {}
 
+/// Implement [Sealed] for primitive types.
+macro_rules! sealed_impl {
+    ($($ty:ty),*) => {$(
+        impl Sealed for $ty {}
+    )*};
+}
+
+sealed_impl!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
 /// Trait used to convert values to/from bytes.
 pub trait ToFromEndian: Sized {
     /// Bytes used by trait.
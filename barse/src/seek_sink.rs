@@ -0,0 +1,53 @@
+//! [SeekSink] trait.
+
+use crate::ByteSink;
+
+/// [ByteSink] with a head that may be moved to absolute positions.
+pub trait SeekSink: ByteSink {
+    /// Get position of head, in bytes from the start of sink.
+    ///
+    /// # Errors
+    /// If the position cannot be determined.
+    fn position(&mut self) -> Result<u64, Self::Err>;
+
+    /// Move head to an absolute position, in bytes from the start of sink.
+    ///
+    /// # Errors
+    /// If the head cannot be moved to given position.
+    fn seek_to(&mut self, pos: u64) -> Result<(), Self::Err>;
+}
+
+impl<Sink> SeekSink for &mut Sink
+where
+    Sink: SeekSink,
+{
+    #[inline]
+    fn position(&mut self) -> Result<u64, Self::Err> {
+        Sink::position(self)
+    }
+
+    #[inline]
+    fn seek_to(&mut self, pos: u64) -> Result<(), Self::Err> {
+        Sink::seek_to(self, pos)
+    }
+}
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+impl<Sink> SeekSink for alloc::boxed::Box<Sink>
+where
+    Sink: SeekSink,
+{
+    #[inline]
+    fn position(&mut self) -> Result<u64, Self::Err> {
+        Sink::position(self)
+    }
+
+    #[inline]
+    fn seek_to(&mut self, pos: u64) -> Result<(), Self::Err> {
+        Sink::seek_to(self, pos)
+    }
+}
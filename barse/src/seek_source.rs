@@ -0,0 +1,53 @@
+//! [SeekSource] trait.
+
+use crate::ByteSource;
+
+/// [ByteSource] with a head that may be moved to absolute positions.
+pub trait SeekSource: ByteSource {
+    /// Get position of head, in bytes from the start of source.
+    ///
+    /// # Errors
+    /// If the position cannot be determined.
+    fn position(&mut self) -> Result<u64, Self::Err>;
+
+    /// Move head to an absolute position, in bytes from the start of source.
+    ///
+    /// # Errors
+    /// If the head cannot be moved to given position.
+    fn seek_to(&mut self, pos: u64) -> Result<(), Self::Err>;
+}
+
+impl<Src> SeekSource for &mut Src
+where
+    Src: SeekSource,
+{
+    #[inline]
+    fn position(&mut self) -> Result<u64, Self::Err> {
+        Src::position(self)
+    }
+
+    #[inline]
+    fn seek_to(&mut self, pos: u64) -> Result<(), Self::Err> {
+        Src::seek_to(self, pos)
+    }
+}
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+impl<Src> SeekSource for alloc::boxed::Box<Src>
+where
+    Src: SeekSource,
+{
+    #[inline]
+    fn position(&mut self) -> Result<u64, Self::Err> {
+        Src::position(self)
+    }
+
+    #[inline]
+    fn seek_to(&mut self, pos: u64) -> Result<(), Self::Err> {
+        Src::seek_to(self, pos)
+    }
+}
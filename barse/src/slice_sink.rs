@@ -2,70 +2,90 @@
 
 use ::core::hash::Hash;
 
-use crate::{error::SliceSinkFull, ByteSink};
+use crate::{error::SliceSinkFull, ByteSink, SeekSink};
 
 /// [ByteSink] implementor wrapping a slice.
+///
+/// The head is never higher than the slice length, and may be moved using [SeekSink].
 #[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[repr(transparent)]
 pub struct SliceSink<'src> {
     /// Wrapped slice.
     slice: &'src mut [u8],
+
+    /// Position of head in slice.
+    head: usize,
 }
 
 impl<'src> SliceSink<'src> {
     /// Create a new [SliceSink] backed by given slice.
     #[inline]
     pub const fn new(slice: &'src mut [u8]) -> Self {
-        Self { slice }
+        Self { slice, head: 0 }
     }
 
     /// Get remaining bytes as a slice.
     #[inline]
     pub const fn as_bytes(&self) -> &[u8] {
-        self.slice
+        self.slice.split_at(self.head).1
     }
 
     /// Get how many more bytes may be written.
     #[inline]
     pub const fn len(&self) -> usize {
-        self.slice.len()
+        self.slice.len() - self.head
     }
 
     /// Returns `true` if no more bytes may be written.
     #[inline]
     pub const fn is_empty(&self) -> bool {
-        self.slice.is_empty()
+        self.len() == 0
     }
 
     /// Move past current ptr by size if possible and return old ptr.
     ///
     /// # Safety
-    /// If Some, the internal pointer has moved by size bytes.
+    /// If not null, the head has been moved by size bytes and the returned pointer is valid for
+    /// writes of size bytes.
     /// The returned pointer will be null on failure.
     #[inline]
     const fn next_ptr(&mut self, size: usize) -> *mut u8 {
-        // Get length of replacement slice. If size is larger than current length fail.
-        // Ensures size <= self.len.
-        let Some(len) = self.slice.len().checked_sub(size) else {
-            return ::core::ptr::null_mut();
+        // Get end of returned slice. If it is past the length of the slice fail.
+        // Ensures head + size <= len.
+        let end = match self.head.checked_add(size) {
+            Some(end) if end <= self.slice.len() => end,
+            _ => return ::core::ptr::null_mut(),
         };
 
-        // Get start of returned slice.
-        let start = self.slice.as_mut_ptr();
-
-        // Since len exists and is smaller than old len by size we know slice is valid.
-        self.slice = unsafe { ::core::slice::from_raw_parts_mut(start.add(size), len) };
+        // Since head <= len, the start pointer is at most one past the end of the slice.
+        let start = unsafe { self.slice.as_mut_ptr().add(self.head) };
+        self.head = end;
 
         start
     }
 
+    /// Move head to pos if possible.
+    ///
+    /// # Returns
+    /// True if head was moved and false otherwise.
+    #[inline]
+    #[must_use]
+    const fn set_head(&mut self, pos: u64) -> bool {
+        if pos > self.slice.len() as u64 {
+            return false;
+        }
+        self.head = pos as usize;
+        true
+    }
+
     /// Get next slice of specified size if possible.
     /// Head will be moved past it.
     ///
+    /// The slice borrows the sink, since the head may be moved back over it.
+    ///
     /// # Safety
     /// If a slice is returned it is guaranteed to have a length of size.
     #[inline]
-    pub const fn next_slice(&mut self, size: usize) -> Option<&'src mut [u8]> {
+    pub const fn next_slice(&mut self, size: usize) -> Option<&mut [u8]> {
         let start = self.next_ptr(size);
         if start.is_null() {
             None
@@ -76,8 +96,10 @@ impl<'src> SliceSink<'src> {
 
     /// Get next array ref of specified size.
     /// Head will be moved past it.
+    ///
+    /// The array borrows the sink, since the head may be moved back over it.
     #[inline]
-    pub const fn next_array_mut<const SIZE: usize>(&mut self) -> Option<&'src mut [u8; SIZE]> {
+    pub const fn next_array_mut<const SIZE: usize>(&mut self) -> Option<&mut [u8; SIZE]> {
         let start = self.next_ptr(SIZE);
         if start.is_null() {
             None
@@ -116,6 +138,23 @@ impl ByteSink for SliceSink<'_> {
     fn remaining(&self) -> Option<usize> {
         Some(self.len())
     }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        Some(self.head as u64)
+    }
+}
+
+impl SeekSink for SliceSink<'_> {
+    #[inline]
+    fn position(&mut self) -> Result<u64, Self::Err> {
+        Ok(self.head as u64)
+    }
+
+    #[inline]
+    fn seek_to(&mut self, pos: u64) -> Result<(), Self::Err> {
+        self.set_head(pos).then_some(()).ok_or(SliceSinkFull)
+    }
 }
 
 #[cfg(test)]
@@ -175,4 +214,23 @@ mod tests {
             start = end;
         }
     }
+
+    #[test]
+    fn seek() {
+        let mut buf = [0u8; 8];
+        let mut sink = SliceSink::new(&mut buf);
+
+        sink.write_slice(b"abcd").expect("write should succeed");
+        assert_eq!(sink.position(), Ok(4));
+        assert_eq!(sink.seek_to(1), Ok(()));
+        assert_eq!(sink.len(), 7);
+        sink.write_array(*b"XY").expect("write should succeed");
+        assert_eq!(sink.seek_to(9), Err(SliceSinkFull));
+        assert_eq!(sink.position(), Ok(3));
+        assert_eq!(sink.seek_to(6), Ok(()));
+        sink.write_slice(b"ef").expect("write should succeed");
+        assert!(sink.is_empty());
+
+        assert_eq!(&buf, b"aXYd\0\0ef");
+    }
 }
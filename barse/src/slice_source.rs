@@ -2,65 +2,80 @@
 
 use ::core::hash::Hash;
 
-use crate::{error::SliceSrcEmpty, ByteSource};
+use crate::{error::SliceSrcEmpty, BorrowSource, ByteSource, PeekSource, SeekSource};
 
 /// [ByteSource] implementor wrapping a slice.
 ///
-/// The head may be higher than the slice length but not higher than isize::MAX (functions using
-/// the head may panic).
+/// The head is never higher than the slice length, and may be moved using [SeekSource].
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[repr(transparent)]
 pub struct SliceSrc<'src> {
     /// Wrapped slice.
     slice: &'src [u8],
+
+    /// Position of head in slice.
+    head: usize,
 }
 
 impl<'src> SliceSrc<'src> {
     /// Create a new [SliceSrc] backed by given slice.
     #[inline]
     pub const fn new(slice: &'src [u8]) -> Self {
-        Self { slice }
+        Self { slice, head: 0 }
     }
 
     /// Get remaining bytes as a slice.
     #[inline]
     pub const fn as_bytes(&self) -> &[u8] {
-        self.slice
+        self.slice.split_at(self.head).1
     }
 
     /// Get how many more bytes may be read.
     #[inline]
     pub const fn len(&self) -> usize {
-        self.slice.len()
+        self.slice.len() - self.head
     }
 
     /// Returns `true` if no more bytes may be read.
     #[inline]
     pub const fn is_empty(&self) -> bool {
-        self.slice.is_empty()
+        self.len() == 0
     }
 
     /// Move past current ptr by size if possible and return old ptr.
     ///
     /// # Safety
-    /// If Some, the internal pointer has been moved by size bytes.
+    /// If not null, the head has been moved by size bytes and the returned pointer is valid for
+    /// reads of size bytes.
     #[inline]
     const fn next_ptr(&mut self, size: usize) -> *const u8 {
-        // Get length of replacement slice. If size is larger than current length fail.
-        // Ensures size <= self.len.
-        let Some(len) = self.slice.len().checked_sub(size) else {
-            return ::core::ptr::null();
+        // Get end of returned slice. If it is past the length of the slice fail.
+        // Ensures head + size <= len.
+        let end = match self.head.checked_add(size) {
+            Some(end) if end <= self.slice.len() => end,
+            _ => return ::core::ptr::null(),
         };
 
-        // Get start of returned slice.
-        let start = self.slice.as_ptr();
-
-        // Since len exists and is smaller than old len by size we know slice is valid.
-        self.slice = unsafe { ::core::slice::from_raw_parts(start.add(size), len) };
+        // Since head <= len, the start pointer is at most one past the end of the slice.
+        let start = unsafe { self.slice.as_ptr().add(self.head) };
+        self.head = end;
 
         start
     }
 
+    /// Move head to pos if possible.
+    ///
+    /// # Returns
+    /// True if head was moved and false otherwise.
+    #[inline]
+    #[must_use]
+    const fn set_head(&mut self, pos: u64) -> bool {
+        if pos > self.slice.len() as u64 {
+            return false;
+        }
+        self.head = pos as usize;
+        true
+    }
+
     /// Skip count bytes if possible.
     ///
     /// # Returns
@@ -120,6 +135,11 @@ impl ByteSource for SliceSrc<'_> {
         Ok(*byte)
     }
 
+    #[inline]
+    fn try_peek_slice(&mut self, buf: &mut [u8]) -> Result<bool, Self::Err> {
+        self.peek_slice(buf).map(|()| true)
+    }
+
     #[inline]
     fn skip(&mut self, count: usize) -> Result<(), Self::Err> {
         self.skip_bytes(count).then_some(()).ok_or(SliceSrcEmpty)
@@ -134,6 +154,44 @@ impl ByteSource for SliceSrc<'_> {
     fn remaining(&self) -> Option<usize> {
         Some(self.len())
     }
+
+    #[inline]
+    fn offset(&self) -> Option<u64> {
+        Some(self.head as u64)
+    }
+}
+
+impl<'src> BorrowSource<'src> for SliceSrc<'src> {
+    #[inline]
+    fn borrow_slice(&mut self, len: usize) -> Result<&'src [u8], Self::Err> {
+        self.next_slice(len).ok_or(SliceSrcEmpty)
+    }
+
+    #[inline]
+    fn borrow_array<const N: usize>(&mut self) -> Result<&'src [u8; N], Self::Err> {
+        self.next_array().ok_or(SliceSrcEmpty)
+    }
+}
+
+impl SeekSource for SliceSrc<'_> {
+    #[inline]
+    fn position(&mut self) -> Result<u64, Self::Err> {
+        Ok(self.head as u64)
+    }
+
+    #[inline]
+    fn seek_to(&mut self, pos: u64) -> Result<(), Self::Err> {
+        self.set_head(pos).then_some(()).ok_or(SliceSrcEmpty)
+    }
+}
+
+impl PeekSource for SliceSrc<'_> {
+    #[inline]
+    fn peek_slice(&mut self, buf: &mut [u8]) -> Result<(), Self::Err> {
+        let bytes = self.as_bytes().get(..buf.len()).ok_or(SliceSrcEmpty)?;
+        buf.copy_from_slice(bytes);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -178,4 +236,37 @@ mod tests {
         assert_eq!(source.skip(0), Ok(()));
         assert!(source.is_empty());
     }
+
+    #[test]
+    fn seek() {
+        let buf = b"0123456789";
+        let mut source = SliceSrc::new(buf);
+
+        assert_eq!(source.read_array::<3>(), Ok(*b"012"));
+        assert_eq!(source.position(), Ok(3));
+        assert_eq!(source.seek_to(7), Ok(()));
+        assert_eq!(source.as_bytes(), b"789");
+        assert_eq!(source.remaining(), Some(3));
+        assert_eq!(source.seek_to(1), Ok(()));
+        assert_eq!(source.read_byte(), Ok(b'1'));
+        assert_eq!(source.seek_to(11), Err(SliceSrcEmpty));
+        assert_eq!(source.position(), Ok(2));
+        assert_eq!(source.seek_to(10), Ok(()));
+        assert!(source.is_empty());
+        assert_eq!(source.read_byte(), Err(SliceSrcEmpty));
+    }
+
+    #[test]
+    fn peek() {
+        let buf = b"RIFF1234";
+        let mut source = SliceSrc::new(buf);
+
+        assert_eq!(source.peek_array::<4>(), Ok(*b"RIFF"));
+        assert_eq!(source.read_array::<2>(), Ok(*b"RI"));
+        let mut peeked = [0u8; 6];
+        assert_eq!(source.peek_slice(&mut peeked), Ok(()));
+        assert_eq!(&peeked, b"FF1234");
+        assert_eq!(source.peek_array::<7>(), Err(SliceSrcEmpty));
+        assert_eq!(source.remaining(), Some(6));
+    }
 }
@@ -1,11 +1,19 @@
 //! Helper types implementing [Barse][crate::Barse] for common usages.
 
 pub use self::{
-    byte_array::ByteArray, fixed_size::FixedSize, padding::Padding, use_endian::UseEndian,
+    byte_array::ByteArray,
+    c_str::FixedCStr,
+    file_ptr::FilePtr,
+    fixed_size::FixedSize,
+    fixed_str::{Ascii, FixedStr, Latin1, TextEncoding, Utf8, Utf8Compatible},
+    length_prefixed::{Counted, LengthPrefixed},
+    padding::{DynPadding, Padding},
+    use_endian::UseEndian,
+    var_int::{Sleb128, Uleb128, VarSigned, VarUnsigned, Vlq, ZigZag},
 };
 
 #[cfg(feature = "alloc")]
-pub use self::byte_array::boxed_byte_array::ByteBox;
+pub use self::{byte_array::boxed_byte_array::ByteBox, c_str::c_str_bytes::CStrBytes};
 
 mod byte_array;
 
@@ -14,3 +22,13 @@ mod use_endian;
 mod padding;
 
 mod fixed_size;
+
+mod length_prefixed;
+
+mod c_str;
+
+mod fixed_str;
+
+mod var_int;
+
+mod file_ptr;
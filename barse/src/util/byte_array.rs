@@ -50,6 +50,51 @@ impl<const N: usize> Barse for ByteArray<N> {
     {
         Ok(to.write_array(self.0)?)
     }
+
+    #[inline]
+    fn encoded_len(
+        &self,
+        _with: (),
+    ) -> Result<usize, crate::WrappedErr<::core::convert::Infallible>> {
+        Ok(N)
+    }
+}
+
+impl<const N: usize> crate::FixedBarse for ByteArray<N> {
+    const SIZE: usize = N;
+    type Bytes = [u8; N];
+}
+
+crate::barse_seek::forward_seek! {
+    impl[const N: usize] ByteArray<N>;
+}
+
+#[cfg(feature = "async")]
+impl<const N: usize> crate::AsyncBarse for ByteArray<N> {
+    #[inline]
+    async fn read_with_async<E, B>(
+        from: &mut B,
+        _with: (),
+    ) -> Result<Self, crate::WrappedErr<B::Err>>
+    where
+        E: crate::Endian,
+        B: crate::AsyncByteSource,
+    {
+        Ok(ByteArray(from.read_array().await?))
+    }
+
+    #[inline]
+    async fn write_with_async<E, B>(
+        &self,
+        to: &mut B,
+        _with: (),
+    ) -> Result<(), crate::WrappedErr<B::Err>>
+    where
+        E: crate::Endian,
+        B: crate::AsyncByteSink,
+    {
+        Ok(to.write_array(self.0).await?)
+    }
 }
 
 impl<const N: usize> Deref for ByteArray<N> {
@@ -230,5 +275,17 @@ pub(crate) mod boxed_byte_array {
             to.write_slice(self.as_ref())?;
             Ok(())
         }
+
+        #[inline]
+        fn encoded_len(
+            &self,
+            _with: Self::WriteWith,
+        ) -> Result<usize, crate::WrappedErr<::core::convert::Infallible>> {
+            Ok(N)
+        }
+    }
+
+    crate::barse_seek::forward_seek! {
+        impl[const N: usize] ByteBox<N>;
     }
 }
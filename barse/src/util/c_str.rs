@@ -0,0 +1,380 @@
+//! [FixedCStr] implementation.
+
+use ::core::{convert::Infallible, ffi::CStr};
+
+use crate::{Barse, Error, WrappedErr};
+
+/// NUL-terminated string stored in an N byte field.
+///
+/// Bytes following the terminator are zeroed, such that they are padded when written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct FixedCStr<const N: usize>([u8; N]);
+
+impl<const N: usize> FixedCStr<N> {
+    /// Construct a new [FixedCStr] from a [CStr].
+    ///
+    /// # Errors
+    /// If the string including its terminator does not fit in N bytes.
+    pub fn new(value: &CStr) -> Result<Self, Error> {
+        let bytes = value.to_bytes_with_nul();
+        let mut field = [0u8; N];
+        field
+            .get_mut(..bytes.len())
+            .ok_or(Error::Msg("C string did not fit in fixed field"))?
+            .copy_from_slice(bytes);
+        Ok(Self(field))
+    }
+
+    /// Construct a [FixedCStr] from raw field bytes, zeroing bytes after the terminator.
+    ///
+    /// # Errors
+    /// If bytes contain no NUL terminator.
+    pub fn from_field(mut field: [u8; N]) -> Result<Self, Error> {
+        let nul = field
+            .iter()
+            .position(|byte| *byte == 0)
+            .ok_or(Error::Msg("fixed C string was not NUL-terminated"))?;
+        field[nul..].fill(0);
+        Ok(Self(field))
+    }
+
+    /// Get string as a [CStr].
+    #[inline]
+    pub fn as_c_str(&self) -> &CStr {
+        CStr::from_bytes_until_nul(&self.0).unwrap_or_default()
+    }
+
+    /// Get bytes of string, without terminator.
+    #[inline]
+    pub fn to_bytes(&self) -> &[u8] {
+        self.as_c_str().to_bytes()
+    }
+
+    /// Unwrap [FixedCStr] to field bytes.
+    #[inline]
+    pub const fn into_inner(self) -> [u8; N] {
+        self.0
+    }
+}
+
+impl<const N: usize> Default for FixedCStr<N> {
+    fn default() -> Self {
+        Self([0u8; N])
+    }
+}
+
+impl<const N: usize> AsRef<CStr> for FixedCStr<N> {
+    #[inline]
+    fn as_ref(&self) -> &CStr {
+        self.as_c_str()
+    }
+}
+
+impl<const N: usize> TryFrom<&CStr> for FixedCStr<N> {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &CStr) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl<const N: usize> Barse for FixedCStr<N> {
+    type ReadWith = ();
+    type WriteWith = ();
+
+    #[inline]
+    fn read_with<E, B>(from: &mut B, _with: ()) -> Result<Self, WrappedErr<B::Err>>
+    where
+        E: crate::Endian,
+        B: crate::ByteSource,
+    {
+        Self::from_field(from.read_array()?).map_err(WrappedErr::from_err)
+    }
+
+    #[inline]
+    fn write_with<E, B>(&self, to: &mut B, _with: ()) -> Result<(), WrappedErr<B::Err>>
+    where
+        E: crate::Endian,
+        B: crate::ByteSink,
+    {
+        Ok(to.write_array(self.0)?)
+    }
+
+    #[inline]
+    fn encoded_len(&self, _with: ()) -> Result<usize, WrappedErr<Infallible>> {
+        Ok(N)
+    }
+}
+
+impl<const N: usize> crate::FixedBarse for FixedCStr<N> {
+    const SIZE: usize = N;
+    type Bytes = [u8; N];
+}
+
+crate::barse_seek::forward_seek! {
+    impl[const N: usize] FixedCStr<N>;
+}
+
+#[cfg(feature = "async")]
+impl<const N: usize> crate::AsyncBarse for FixedCStr<N> {
+    #[inline]
+    async fn read_with_async<E, B>(from: &mut B, _with: ()) -> Result<Self, WrappedErr<B::Err>>
+    where
+        E: crate::Endian,
+        B: crate::AsyncByteSource,
+    {
+        Self::from_field(from.read_array().await?).map_err(WrappedErr::from_err)
+    }
+
+    #[inline]
+    async fn write_with_async<E, B>(&self, to: &mut B, _with: ()) -> Result<(), WrappedErr<B::Err>>
+    where
+        E: crate::Endian,
+        B: crate::AsyncByteSink,
+    {
+        Ok(to.write_array(self.0).await?)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub(crate) mod c_str_bytes {
+    //! [CStrBytes] impl.
+
+    extern crate alloc;
+
+    use ::core::{convert::Infallible, ffi::CStr, ops::Deref};
+
+    use alloc::{ffi::CString, vec::Vec};
+
+    use crate::{Barse, Error, WrappedErr};
+
+    use super::FixedCStr;
+
+    /// NUL-terminated string read until a `0x00` byte.
+    ///
+    /// At most MAX bytes, excluding terminator, are read or written, such that a missing
+    /// terminator cannot cause unbounded reads. MAX defaults to 4096.
+    #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[repr(transparent)]
+    pub struct CStrBytes<const MAX: usize = 4096>(CString);
+
+    impl<const MAX: usize> CStrBytes<MAX> {
+        /// Construct a new [CStrBytes] from a [CString].
+        #[inline]
+        pub const fn new(value: CString) -> Self {
+            Self(value)
+        }
+
+        /// Unwrap [CStrBytes] to wrapped string.
+        #[inline]
+        pub fn into_inner(self) -> CString {
+            self.0
+        }
+
+        /// Get length of string, excluding terminator.
+        ///
+        /// # Errors
+        /// If string is longer than MAX.
+        #[inline]
+        fn checked_len(&self) -> Result<usize, Error> {
+            let len = self.0.as_bytes().len();
+            if len > MAX {
+                Err(Error::Msg("C string exceeded maximum length"))
+            } else {
+                Ok(len)
+            }
+        }
+
+        /// Push a read byte to bytes, returning the finished value if the byte was a terminator.
+        ///
+        /// # Errors
+        /// If bytes would exceed MAX.
+        fn push_byte(bytes: &mut Vec<u8>, byte: u8) -> Result<Option<Self>, Error> {
+            if byte == 0 {
+                let bytes = ::core::mem::take(bytes);
+                // SAFETY: Only non-NUL bytes are pushed.
+                return Ok(Some(Self(unsafe { CString::from_vec_unchecked(bytes) })));
+            }
+            if bytes.len() >= MAX {
+                return Err(Error::Msg("C string exceeded maximum length"));
+            }
+            bytes.push(byte);
+            Ok(None)
+        }
+    }
+
+    impl<const MAX: usize> Deref for CStrBytes<MAX> {
+        type Target = CStr;
+
+        #[inline]
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl<const MAX: usize> AsRef<CStr> for CStrBytes<MAX> {
+        #[inline]
+        fn as_ref(&self) -> &CStr {
+            &self.0
+        }
+    }
+
+    impl<const MAX: usize> From<CString> for CStrBytes<MAX> {
+        #[inline]
+        fn from(value: CString) -> Self {
+            Self(value)
+        }
+    }
+
+    impl<const MAX: usize> From<&CStr> for CStrBytes<MAX> {
+        #[inline]
+        fn from(value: &CStr) -> Self {
+            Self(value.into())
+        }
+    }
+
+    impl<const MAX: usize> From<CStrBytes<MAX>> for CString {
+        #[inline]
+        fn from(value: CStrBytes<MAX>) -> Self {
+            value.0
+        }
+    }
+
+    impl<const N: usize> From<FixedCStr<N>> for CString {
+        #[inline]
+        fn from(value: FixedCStr<N>) -> Self {
+            value.as_c_str().into()
+        }
+    }
+
+    impl<const MAX: usize> Barse for CStrBytes<MAX> {
+        type ReadWith = ();
+
+        type WriteWith = ();
+
+        #[inline]
+        fn read_with<E, B>(from: &mut B, _with: Self::ReadWith) -> Result<Self, WrappedErr<B::Err>>
+        where
+            E: crate::Endian,
+            B: crate::ByteSource,
+        {
+            let mut bytes = Vec::new();
+            loop {
+                if let Some(value) =
+                    Self::push_byte(&mut bytes, from.read_byte()?).map_err(WrappedErr::from_err)?
+                {
+                    return Ok(value);
+                }
+            }
+        }
+
+        #[inline]
+        fn write_with<E, B>(
+            &self,
+            to: &mut B,
+            _with: Self::WriteWith,
+        ) -> Result<(), WrappedErr<B::Err>>
+        where
+            E: crate::Endian,
+            B: crate::ByteSink,
+        {
+            self.checked_len().map_err(WrappedErr::from_err)?;
+            to.write_slice(self.0.as_bytes_with_nul())?;
+            Ok(())
+        }
+
+        #[inline]
+        fn encoded_len(&self, _with: Self::WriteWith) -> Result<usize, WrappedErr<Infallible>> {
+            Ok(self.checked_len().map_err(WrappedErr::from_err)? + 1)
+        }
+    }
+
+    crate::barse_seek::forward_seek! {
+        impl[const MAX: usize] CStrBytes<MAX>;
+    }
+
+    #[cfg(feature = "async")]
+    impl<const MAX: usize> crate::AsyncBarse for CStrBytes<MAX> {
+        #[inline]
+        async fn read_with_async<E, B>(
+            from: &mut B,
+            _with: Self::ReadWith,
+        ) -> Result<Self, WrappedErr<B::Err>>
+        where
+            E: crate::Endian,
+            B: crate::AsyncByteSource,
+        {
+            let mut bytes = Vec::new();
+            loop {
+                let [byte] = from.read_array().await?;
+                if let Some(value) =
+                    Self::push_byte(&mut bytes, byte).map_err(WrappedErr::from_err)?
+                {
+                    return Ok(value);
+                }
+            }
+        }
+
+        #[inline]
+        async fn write_with_async<E, B>(
+            &self,
+            to: &mut B,
+            _with: Self::WriteWith,
+        ) -> Result<(), WrappedErr<B::Err>>
+        where
+            E: crate::Endian,
+            B: crate::AsyncByteSink,
+        {
+            self.checked_len().map_err(WrappedErr::from_err)?;
+            to.write_slice(self.0.as_bytes_with_nul()).await?;
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    use crate::{endian::Little, SliceSink, SliceSrc};
+
+    use super::*;
+
+    #[test]
+    fn fixed_c_str() {
+        let mut buf = [0xffu8; 6];
+        let value = FixedCStr::<6>::new(c"abc").unwrap();
+        value
+            .write_with::<Little, _>(&mut SliceSink::new(&mut buf), ())
+            .unwrap();
+        assert_eq!(&buf, b"abc\0\0\0");
+
+        buf[5] = 0xff;
+        let read = FixedCStr::<6>::read_with::<Little, _>(&mut SliceSrc::new(&buf), ()).unwrap();
+        assert_eq!(read, value);
+        assert_eq!(read.as_c_str(), c"abc");
+
+        assert!(FixedCStr::<3>::new(c"abc").is_err());
+        assert!(FixedCStr::<3>::read_with::<Little, _>(&mut SliceSrc::new(b"abc"), ()).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn c_str_bytes() {
+        use super::c_str_bytes::CStrBytes;
+
+        let mut source = SliceSrc::new(b"ab\0cde\0");
+        let first = CStrBytes::<4>::read_with::<Little, _>(&mut source, ()).unwrap();
+        let second = CStrBytes::<4>::read_with::<Little, _>(&mut source, ()).unwrap();
+        assert_eq!(&*first, c"ab");
+        assert_eq!(&*second, c"cde");
+        assert_eq!(second.encoded_len(()).unwrap(), 4);
+
+        let mut source = SliceSrc::new(b"abcdefgh\0");
+        assert!(CStrBytes::<4>::read_with::<Little, _>(&mut source, ()).is_err());
+        assert!(CStrBytes::<2>::from(c"abc").encoded_len(()).is_err());
+    }
+}
@@ -0,0 +1,176 @@
+//! [FilePtr] implementation.
+
+use ::core::ops::{Deref, DerefMut};
+
+use crate::{Barse, BarseSeek, Empty, Endian, Error, GuardedSource, SeekSink, WrappedErr};
+
+/// Value stored elsewhere in source, pointed to by an offset stored as O.
+///
+/// The with value is the base offsets are relative to, zero for absolute offsets, followed by the
+/// with value of T. Position of source/sink is restored once T has been read/written.
+///
+/// Values are read from a [GuardedSource], such that pointer cycles are detected. Recursive
+/// structures point to boxed values, such as `Option<Box<FilePtr<u32, Node>>>`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FilePtr<O, T> {
+    /// Offset of value.
+    offset: O,
+
+    /// Value pointed to.
+    value: T,
+}
+
+impl<O, T> FilePtr<O, T> {
+    /// Construct a new [FilePtr] pointing to value at offset.
+    #[inline]
+    pub const fn new(offset: O, value: T) -> Self {
+        Self { offset, value }
+    }
+
+    /// Get offset of value.
+    #[inline]
+    pub const fn offset(&self) -> &O {
+        &self.offset
+    }
+
+    /// Unwrap [FilePtr] to value pointed to.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Unwrap [FilePtr] to offset and value pointed to.
+    #[inline]
+    pub fn into_parts(self) -> (O, T) {
+        (self.offset, self.value)
+    }
+}
+
+impl<O, T> Deref for FilePtr<O, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<O, T> DerefMut for FilePtr<O, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+/// Get absolute position of offset relative to base.
+///
+/// # Errors
+/// If offset is negative or position overflows.
+#[inline]
+fn position<O>(base: u64, offset: O) -> Result<u64, Error>
+where
+    O: TryInto<u64>,
+{
+    offset
+        .try_into()
+        .ok()
+        .and_then(|offset| base.checked_add(offset))
+        .ok_or(Error::Msg("pointer offset is not a valid position"))
+}
+
+impl<O, T> BarseSeek for FilePtr<O, T>
+where
+    O: Barse + Copy + TryInto<u64>,
+    O::ReadWith: Empty,
+    O::WriteWith: Empty,
+    T: BarseSeek,
+{
+    type ReadWith = (u64, T::ReadWith);
+
+    type WriteWith = (u64, T::WriteWith);
+
+    #[inline]
+    fn read_seek<E, B>(
+        from: &mut B,
+        (base, with): Self::ReadWith,
+    ) -> Result<Self, WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: GuardedSource,
+    {
+        let offset = O::read_with::<E, B>(from, O::ReadWith::instance())?;
+        let pos = position(base, offset).map_err(WrappedErr::from_err)?;
+
+        from.enter_ptr(pos).map_err(WrappedErr::from_err)?;
+        let value = from.position().map_err(WrappedErr::from).and_then(|back| {
+            from.seek_to(pos)?;
+            let value = T::read_seek::<E, B>(from, with);
+            let restored = from.seek_to(back);
+            let value = value?;
+            restored?;
+            Ok(value)
+        });
+        from.leave_ptr(pos);
+
+        Ok(Self::new(offset, value?))
+    }
+
+    #[inline]
+    fn write_seek<E, B>(
+        &self,
+        to: &mut B,
+        (base, with): Self::WriteWith,
+    ) -> Result<(), WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: SeekSink,
+    {
+        self.offset
+            .write_with::<E, B>(to, O::WriteWith::instance())?;
+        let pos = position(base, self.offset).map_err(WrappedErr::from_err)?;
+
+        let back = to.position()?;
+        to.seek_to(pos)?;
+        let written = self.value.write_seek::<E, B>(to, with);
+        let restored = to.seek_to(back);
+        written?;
+        Ok(restored?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    use crate::{adapter::PtrGuard, endian::Little, ByteSource, SeekSource, SliceSink, SliceSrc};
+
+    use super::*;
+
+    #[test]
+    fn file_ptr() {
+        let mut buf = [0u8; 6];
+        let mut sink = SliceSink::new(&mut buf);
+        FilePtr::<u8, u16>::new(2, 0x0201)
+            .write_seek::<Little, _>(&mut sink, (2, ()))
+            .unwrap();
+        assert_eq!(sink.position(), Ok(1));
+        assert_eq!(&buf, b"\x02\0\0\0\x01\x02");
+
+        let mut source = PtrGuard::<_>::new(SliceSrc::new(&buf));
+        let ptr = FilePtr::<u8, u16>::read_seek::<Little, _>(&mut source, (2, ())).unwrap();
+        assert_eq!(*ptr, 0x0201);
+        assert_eq!(*ptr.offset(), 2);
+        assert_eq!(source.read_byte(), Ok(0));
+
+        // Pointer to itself.
+        let mut source = PtrGuard::<_>::new(SliceSrc::new(b"\0"));
+        let err = FilePtr::<u8, FilePtr<u8, u8>>::read_seek::<Little, _>(&mut source, (0, (0, ())))
+            .unwrap_err();
+        assert!(matches!(
+            err.kind(),
+            crate::error::ErrKind::Other(Error::PtrCycle(_))
+        ));
+        assert_eq!(source.depth(), 0);
+        assert_eq!(source.position(), Ok(1));
+    }
+}
@@ -23,11 +23,12 @@ where
         E: crate::Endian,
         B: crate::ByteSource,
     {
+        let base = from.offset();
         let bytes = from.read_array::<SIZE>()?;
         let mut from = SliceSrc::new(&bytes);
 
         T::read_with::<E, _>(&mut from, with)
-            .map_err(|err| err.merge_into::<crate::Error>().into_wrapped::<B::Err>())
+            .map_err(|err| err.rewrap().shift_offset(base))
             .map(Self)
     }
 
@@ -40,14 +41,81 @@ where
         E: crate::Endian,
         B: crate::ByteSink,
     {
+        let base = to.offset();
         let mut bytes = [PAD; SIZE];
         {
             let mut to = SliceSink::new(&mut bytes);
             T::write_with::<E, _>(&self.0, &mut to, with)
-                .map_err(|err| err.merge_into::<crate::Error>().into_wrapped::<B::Err>())?;
+                .map_err(|err| err.rewrap().shift_offset(base))?;
         }
         to.write_array(bytes).map_err(From::from)
     }
+
+    #[inline]
+    fn encoded_len(
+        &self,
+        _with: Self::WriteWith,
+    ) -> Result<usize, crate::WrappedErr<::core::convert::Infallible>> {
+        Ok(SIZE)
+    }
+}
+
+impl<T, const SIZE: usize, const PAD: u8> crate::FixedBarse for FixedSize<T, SIZE, PAD>
+where
+    T: Barse,
+{
+    const SIZE: usize = SIZE;
+    type Bytes = [u8; SIZE];
+}
+
+crate::barse_seek::forward_seek! {
+    impl[T, const SIZE: usize, const PAD: u8] FixedSize<T, SIZE, PAD>;
+}
+
+#[cfg(feature = "async")]
+#[expect(
+    clippy::future_not_send,
+    reason = "futures hold the value and source/sink, Send whenever both are"
+)]
+impl<T, const SIZE: usize, const PAD: u8> crate::AsyncBarse for FixedSize<T, SIZE, PAD>
+where
+    T: Barse,
+{
+    async fn read_with_async<E, B>(
+        from: &mut B,
+        with: Self::ReadWith,
+    ) -> Result<Self, crate::WrappedErr<B::Err>>
+    where
+        E: crate::Endian,
+        B: crate::AsyncByteSource,
+    {
+        let base = from.offset();
+        let bytes = from.read_array::<SIZE>().await?;
+        let mut from = SliceSrc::new(&bytes);
+
+        T::read_with::<E, _>(&mut from, with)
+            .map_err(|err| err.rewrap().shift_offset(base))
+            .map(Self)
+    }
+
+    async fn write_with_async<E, B>(
+        &self,
+        to: &mut B,
+        with: Self::WriteWith,
+    ) -> Result<(), crate::WrappedErr<B::Err>>
+    where
+        E: crate::Endian,
+        B: crate::AsyncByteSink,
+    {
+        let base = to.offset();
+        let mut bytes = [PAD; SIZE];
+        {
+            let mut to = SliceSink::new(&mut bytes);
+            T::write_with::<E, _>(&self.0, &mut to, with)
+                .map_err(|err| err.rewrap().shift_offset(base))?;
+        }
+        to.write_array(bytes).await.map_err(From::from)
+    }
 }
 
 impl<T, const SIZE: usize, const PAD: u8> FixedSize<T, SIZE, PAD> {
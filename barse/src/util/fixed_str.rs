@@ -0,0 +1,363 @@
+//! [FixedStr] implementation.
+
+use ::core::{convert::Infallible, fmt, marker::PhantomData};
+
+use crate::{sealed::Sealed, Barse, Error, WrappedErr};
+
+/// Text encoding used by [FixedStr].
+pub trait TextEncoding: Sealed {
+    /// Validate encoded bytes.
+    ///
+    /// # Errors
+    /// If bytes are not valid for encoding.
+    fn validate(bytes: &[u8]) -> Result<(), Error>;
+
+    /// Encode text to buf, returning amount of bytes used.
+    ///
+    /// # Errors
+    /// If text cannot be encoded, or does not fit in buf.
+    fn encode(text: &str, buf: &mut [u8]) -> Result<usize, Error>;
+
+    /// Decode a char from start of valid bytes, returning it and amount of bytes used.
+    fn decode(bytes: &[u8]) -> Option<(char, usize)>;
+}
+
+/// Text encodings where encoded bytes are valid UTF-8.
+pub trait Utf8Compatible: TextEncoding {}
+
+/// Error returned when text does not fit in field.
+const TOO_LONG: Error = Error::Msg("text did not fit in fixed field");
+
+/// UTF-8 text encoding.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Utf8;
+
+impl Sealed for Utf8 {}
+impl Utf8Compatible for Utf8 {}
+impl TextEncoding for Utf8 {
+    #[inline]
+    fn validate(bytes: &[u8]) -> Result<(), Error> {
+        ::core::str::from_utf8(bytes)
+            .map(|_| ())
+            .map_err(|_| Error::Msg("text was not valid UTF-8"))
+    }
+
+    #[inline]
+    fn encode(text: &str, buf: &mut [u8]) -> Result<usize, Error> {
+        buf.get_mut(..text.len())
+            .ok_or(TOO_LONG)?
+            .copy_from_slice(text.as_bytes());
+        Ok(text.len())
+    }
+
+    #[inline]
+    fn decode(bytes: &[u8]) -> Option<(char, usize)> {
+        let len = match bytes.first()? {
+            0x00..=0x7f => 1,
+            0xc0..=0xdf => 2,
+            0xe0..=0xef => 3,
+            _ => 4,
+        };
+        let c = ::core::str::from_utf8(bytes.get(..len)?)
+            .ok()?
+            .chars()
+            .next()?;
+        Some((c, len))
+    }
+}
+
+/// ASCII text encoding.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ascii;
+
+impl Sealed for Ascii {}
+impl Utf8Compatible for Ascii {}
+impl TextEncoding for Ascii {
+    #[inline]
+    fn validate(bytes: &[u8]) -> Result<(), Error> {
+        if bytes.is_ascii() {
+            Ok(())
+        } else {
+            Err(Error::Msg("text was not valid ASCII"))
+        }
+    }
+
+    #[inline]
+    fn encode(text: &str, buf: &mut [u8]) -> Result<usize, Error> {
+        Self::validate(text.as_bytes())?;
+        Utf8::encode(text, buf)
+    }
+
+    #[inline]
+    fn decode(bytes: &[u8]) -> Option<(char, usize)> {
+        bytes.first().map(|byte| (char::from(*byte), 1))
+    }
+}
+
+/// Latin-1 (ISO 8859-1) text encoding.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Latin1;
+
+impl Sealed for Latin1 {}
+impl TextEncoding for Latin1 {
+    #[inline]
+    fn validate(_bytes: &[u8]) -> Result<(), Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn encode(text: &str, buf: &mut [u8]) -> Result<usize, Error> {
+        let mut len = 0;
+        for c in text.chars() {
+            let byte =
+                u8::try_from(c).map_err(|_| Error::Msg("text could not be encoded as Latin-1"))?;
+            *buf.get_mut(len).ok_or(TOO_LONG)? = byte;
+            len += 1;
+        }
+        Ok(len)
+    }
+
+    #[inline]
+    fn decode(bytes: &[u8]) -> Option<(char, usize)> {
+        bytes.first().map(|byte| (char::from(*byte), 1))
+    }
+}
+
+/// Text stored inline in an N byte field, padded by PAD and encoded by Enc.
+///
+/// Trailing PAD bytes are trimmed on read, and text longer than N bytes errors on write.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixedStr<const N: usize, const PAD: u8 = 0, Enc = Utf8> {
+    /// Field bytes, bytes past len are PAD.
+    bytes: [u8; N],
+
+    /// Length of text in bytes.
+    len: usize,
+
+    /// Encoding of text.
+    _p: PhantomData<fn() -> Enc>,
+}
+
+impl<const N: usize, const PAD: u8, Enc> FixedStr<N, PAD, Enc>
+where
+    Enc: TextEncoding,
+{
+    /// Construct a new [FixedStr] by encoding text.
+    ///
+    /// # Errors
+    /// If text cannot be encoded, or does not fit in N bytes.
+    pub fn new(text: &str) -> Result<Self, Error> {
+        let mut bytes = [PAD; N];
+        let len = Enc::encode(text, &mut bytes)?;
+        Ok(Self {
+            bytes,
+            len,
+            _p: PhantomData,
+        })
+    }
+
+    /// Construct a [FixedStr] from raw field bytes, trimming padding.
+    ///
+    /// # Errors
+    /// If trimmed bytes are not valid for encoding.
+    pub fn from_field(bytes: [u8; N]) -> Result<Self, Error> {
+        let len = bytes
+            .iter()
+            .rposition(|byte| *byte != PAD)
+            .map_or(0, |last| last + 1);
+        Enc::validate(&bytes[..len])?;
+        Ok(Self {
+            bytes,
+            len,
+            _p: PhantomData,
+        })
+    }
+
+    /// Get encoded text, without padding.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// Get length of encoded text in bytes.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if text is empty.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Iterate decoded chars of text.
+    #[inline]
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        let mut bytes = self.as_bytes();
+        ::core::iter::from_fn(move || {
+            let (c, len) = Enc::decode(bytes)?;
+            bytes = &bytes[len..];
+            Some(c)
+        })
+    }
+
+    /// Unwrap [FixedStr] to field bytes, including padding.
+    #[inline]
+    pub const fn into_inner(self) -> [u8; N] {
+        self.bytes
+    }
+}
+
+impl<const N: usize, const PAD: u8, Enc> FixedStr<N, PAD, Enc>
+where
+    Enc: Utf8Compatible,
+{
+    /// Get text as a [str].
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        ::core::str::from_utf8(self.as_bytes()).unwrap_or_default()
+    }
+}
+
+impl<const N: usize, const PAD: u8, Enc> Default for FixedStr<N, PAD, Enc> {
+    fn default() -> Self {
+        Self {
+            bytes: [PAD; N],
+            len: 0,
+            _p: PhantomData,
+        }
+    }
+}
+
+impl<const N: usize, const PAD: u8, Enc> fmt::Debug for FixedStr<N, PAD, Enc>
+where
+    Enc: TextEncoding,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("\"")?;
+        for c in self.chars() {
+            fmt::Display::fmt(&c.escape_debug(), f)?;
+        }
+        f.write_str("\"")
+    }
+}
+
+impl<const N: usize, const PAD: u8, Enc> fmt::Display for FixedStr<N, PAD, Enc>
+where
+    Enc: TextEncoding,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chars().try_for_each(|c| fmt::Write::write_char(f, c))
+    }
+}
+
+impl<const N: usize, const PAD: u8, Enc> TryFrom<&str> for FixedStr<N, PAD, Enc>
+where
+    Enc: TextEncoding,
+{
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl<const N: usize, const PAD: u8, Enc> Barse for FixedStr<N, PAD, Enc>
+where
+    Enc: TextEncoding,
+{
+    type ReadWith = ();
+    type WriteWith = ();
+
+    #[inline]
+    fn read_with<E, B>(from: &mut B, _with: ()) -> Result<Self, WrappedErr<B::Err>>
+    where
+        E: crate::Endian,
+        B: crate::ByteSource,
+    {
+        Self::from_field(from.read_array()?).map_err(WrappedErr::from_err)
+    }
+
+    #[inline]
+    fn write_with<E, B>(&self, to: &mut B, _with: ()) -> Result<(), WrappedErr<B::Err>>
+    where
+        E: crate::Endian,
+        B: crate::ByteSink,
+    {
+        Ok(to.write_array(self.bytes)?)
+    }
+
+    #[inline]
+    fn encoded_len(&self, _with: ()) -> Result<usize, WrappedErr<Infallible>> {
+        Ok(N)
+    }
+}
+
+impl<const N: usize, const PAD: u8, Enc> crate::FixedBarse for FixedStr<N, PAD, Enc>
+where
+    Enc: TextEncoding,
+{
+    const SIZE: usize = N;
+    type Bytes = [u8; N];
+}
+
+crate::barse_seek::forward_seek! {
+    impl[const N: usize, const PAD: u8, Enc] FixedStr<N, PAD, Enc>;
+}
+
+#[cfg(feature = "async")]
+impl<const N: usize, const PAD: u8, Enc> crate::AsyncBarse for FixedStr<N, PAD, Enc>
+where
+    Enc: TextEncoding,
+{
+    #[inline]
+    async fn read_with_async<E, B>(from: &mut B, _with: ()) -> Result<Self, WrappedErr<B::Err>>
+    where
+        E: crate::Endian,
+        B: crate::AsyncByteSource,
+    {
+        Self::from_field(from.read_array().await?).map_err(WrappedErr::from_err)
+    }
+
+    #[inline]
+    async fn write_with_async<E, B>(&self, to: &mut B, _with: ()) -> Result<(), WrappedErr<B::Err>>
+    where
+        E: crate::Endian,
+        B: crate::AsyncByteSink,
+    {
+        Ok(to.write_array(self.bytes).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    use crate::{endian::Little, SliceSink, SliceSrc};
+
+    use super::*;
+
+    #[test]
+    fn fixed_str() {
+        let mut buf = [0u8; 8];
+        let name = FixedStr::<8, b' '>::new("héllo").unwrap();
+        name.write_with::<Little, _>(&mut SliceSink::new(&mut buf), ())
+            .unwrap();
+        assert_eq!(&buf, "héllo  ".as_bytes());
+
+        let read =
+            FixedStr::<8, b' '>::read_with::<Little, _>(&mut SliceSrc::new(&buf), ()).unwrap();
+        assert_eq!(read.as_str(), "héllo");
+        assert_eq!(read, name);
+
+        assert!(FixedStr::<4>::new("héllo").is_err());
+        assert!(FixedStr::<8, 0, Ascii>::new("héllo").is_err());
+        assert!(FixedStr::<2, 0, Ascii>::from_field([b'a', 0xe9]).is_err());
+
+        let latin = FixedStr::<8, 0, Latin1>::from_field(*b"h\xe9llo\0\0\0").unwrap();
+        assert!(latin.chars().eq("héllo".chars()));
+        assert_eq!(FixedStr::<8, 0, Latin1>::new("héllo").unwrap(), latin);
+    }
+}
@@ -0,0 +1,193 @@
+//! [LengthPrefixed] implementation.
+
+use ::core::{
+    convert::Infallible,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{Barse, Empty, Endian, Error, WrappedErr};
+
+/// Collections which know the count their [Barse] impl is read with.
+pub trait Counted {
+    /// Get count of collection, as given when reading it.
+    fn count(&self) -> usize;
+}
+
+/// Collection prefixed by its count, stored as L.
+///
+/// The prefix is read and passed as the count of T, when writing it is taken from T.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct LengthPrefixed<L, T>(T, PhantomData<fn() -> L>);
+
+impl<L, T> LengthPrefixed<L, T> {
+    /// Construct a new [LengthPrefixed] from value.
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self(value, PhantomData)
+    }
+
+    /// Unwrap [LengthPrefixed] to wrapped value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        let Self(value, _) = self;
+        value
+    }
+}
+
+impl<L, T> Deref for LengthPrefixed<L, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<L, T> DerefMut for LengthPrefixed<L, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<L, T> From<T> for LengthPrefixed<L, T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<L, T> LengthPrefixed<L, T>
+where
+    L: TryFrom<usize>,
+    T: Counted,
+{
+    /// Get prefix of value.
+    ///
+    /// # Errors
+    /// If the count of value does not fit in L.
+    #[inline]
+    fn prefix(&self) -> Result<L, Error> {
+        L::try_from(self.0.count()).map_err(|_| Error::Msg("count did not fit in length prefix"))
+    }
+}
+
+impl<L, T> Barse for LengthPrefixed<L, T>
+where
+    L: Barse + TryFrom<usize>,
+    L::ReadWith: Empty,
+    L::WriteWith: Empty,
+    usize: TryFrom<L>,
+    T: Barse<ReadWith = usize> + Counted,
+{
+    type ReadWith = ();
+
+    type WriteWith = T::WriteWith;
+
+    #[inline]
+    fn read_with<E, B>(from: &mut B, _with: Self::ReadWith) -> Result<Self, WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: crate::ByteSource,
+    {
+        let prefix = L::read_with::<E, B>(from, L::ReadWith::instance())?;
+        let count = usize::try_from(prefix)
+            .map_err(|_| WrappedErr::from_err(Error::Msg("length prefix did not fit in usize")))?;
+        T::read_with::<E, B>(from, count).map(Self::new)
+    }
+
+    #[inline]
+    fn write_with<E, B>(&self, to: &mut B, with: Self::WriteWith) -> Result<(), WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: crate::ByteSink,
+    {
+        self.prefix()
+            .map_err(WrappedErr::from_err)?
+            .write_with::<E, B>(to, L::WriteWith::instance())?;
+        self.0.write_with::<E, B>(to, with)
+    }
+
+    #[inline]
+    fn encoded_len(&self, with: Self::WriteWith) -> Result<usize, WrappedErr<Infallible>> {
+        let prefix_len = self
+            .prefix()
+            .map_err(WrappedErr::from_err)?
+            .encoded_len(L::WriteWith::instance())?;
+        Ok(prefix_len.saturating_add(self.0.encoded_len(with)?))
+    }
+}
+
+crate::barse_seek::forward_seek! {
+    impl[L, T] LengthPrefixed<L, T>;
+}
+
+#[cfg(feature = "alloc")]
+mod if_alloc {
+    //! [Counted] implementations for alloc types.
+
+    extern crate alloc;
+
+    use alloc::{boxed::Box, string::String, vec::Vec};
+
+    use super::Counted;
+
+    impl<T> Counted for Vec<T> {
+        #[inline]
+        fn count(&self) -> usize {
+            self.len()
+        }
+    }
+
+    impl<T> Counted for Box<[T]> {
+        #[inline]
+        fn count(&self) -> usize {
+            self.len()
+        }
+    }
+
+    impl Counted for String {
+        #[inline]
+        fn count(&self) -> usize {
+            self.len()
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    extern crate alloc;
+
+    use alloc::{string::String, vec, vec::Vec};
+
+    use crate::{endian::Big, SliceSink, SliceSrc};
+
+    use super::*;
+
+    #[test]
+    fn length_prefixed() {
+        let mut buf = [0u8; 8];
+        let mut sink = SliceSink::new(&mut buf);
+        LengthPrefixed::<u8, _>::new(String::from("abc"))
+            .write_with::<Big, _>(&mut sink, ())
+            .unwrap();
+        LengthPrefixed::<u16, _>::new(vec![7u8, 8])
+            .write_with::<Big, _>(&mut sink, ())
+            .unwrap();
+        assert_eq!(&buf, b"\x03abc\x00\x02\x07\x08");
+
+        let mut source = SliceSrc::new(&buf);
+        let string = LengthPrefixed::<u8, String>::read_with::<Big, _>(&mut source, ()).unwrap();
+        let bytes = LengthPrefixed::<u16, Vec<u8>>::read_with::<Big, _>(&mut source, ()).unwrap();
+        assert_eq!(*string, "abc");
+        assert_eq!(*bytes, [7, 8]);
+        assert_eq!(bytes.encoded_len(()).unwrap(), 4);
+
+        let too_long = LengthPrefixed::<u8, _>::new(vec![0u8; 256]);
+        assert!(too_long.encoded_len(()).is_err());
+    }
+}
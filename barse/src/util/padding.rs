@@ -1,4 +1,4 @@
-//! [Padding] implementation.
+//! [Padding] and [DynPadding] implementation.
 
 use crate::{Barse, Endian};
 
@@ -31,4 +31,163 @@ impl<const N: usize, const BYTE: u8> Barse for Padding<N, BYTE> {
         to.write_array([BYTE; N])?;
         Ok(())
     }
+
+    #[inline]
+    fn encoded_len(
+        &self,
+        _with: (),
+    ) -> Result<usize, crate::WrappedErr<::core::convert::Infallible>> {
+        Ok(N)
+    }
+}
+
+impl<const N: usize, const BYTE: u8> crate::FixedBarse for Padding<N, BYTE> {
+    const SIZE: usize = N;
+    type Bytes = [u8; N];
+}
+
+crate::barse_seek::forward_seek! {
+    impl[const N: usize, const BYTE: u8] Padding<N, BYTE>;
+}
+
+#[cfg(feature = "async")]
+impl<const N: usize, const BYTE: u8> crate::AsyncBarse for Padding<N, BYTE> {
+    #[inline]
+    async fn read_with_async<E, B>(
+        from: &mut B,
+        _with: (),
+    ) -> Result<Self, crate::WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: crate::AsyncByteSource,
+    {
+        from.skip(N).await?;
+        Ok(Self)
+    }
+
+    #[inline]
+    async fn write_with_async<E, B>(
+        &self,
+        to: &mut B,
+        _with: (),
+    ) -> Result<(), crate::WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: crate::AsyncByteSink,
+    {
+        to.write_array([BYTE; N]).await?;
+        Ok(())
+    }
+}
+
+/// Padding of a count given at runtime, read bytes are discarded and count bytes of BYTE are
+/// written, count is given as the with value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DynPadding<const BYTE: u8 = 0u8>;
+
+/// Size of chunks padding is written in.
+const CHUNK: usize = 64;
+
+impl<const BYTE: u8> Barse for DynPadding<BYTE> {
+    type ReadWith = usize;
+    type WriteWith = usize;
+
+    #[inline]
+    fn read_with<E, B>(from: &mut B, count: usize) -> Result<Self, crate::WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: crate::ByteSource,
+    {
+        from.skip(count)?;
+        Ok(Self)
+    }
+
+    #[inline]
+    fn write_with<E, B>(
+        &self,
+        to: &mut B,
+        mut count: usize,
+    ) -> Result<(), crate::WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: crate::ByteSink,
+    {
+        while count != 0 {
+            let len = count.min(CHUNK);
+            to.write_slice(&[BYTE; CHUNK][..len])?;
+            count -= len;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn encoded_len(
+        &self,
+        count: usize,
+    ) -> Result<usize, crate::WrappedErr<::core::convert::Infallible>> {
+        Ok(count)
+    }
+}
+
+crate::barse_seek::forward_seek! {
+    impl[const BYTE: u8] DynPadding<BYTE>;
+}
+
+#[cfg(feature = "async")]
+impl<const BYTE: u8> crate::AsyncBarse for DynPadding<BYTE> {
+    #[inline]
+    async fn read_with_async<E, B>(
+        from: &mut B,
+        count: usize,
+    ) -> Result<Self, crate::WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: crate::AsyncByteSource,
+    {
+        from.skip(count).await?;
+        Ok(Self)
+    }
+
+    #[inline]
+    async fn write_with_async<E, B>(
+        &self,
+        to: &mut B,
+        mut count: usize,
+    ) -> Result<(), crate::WrappedErr<B::Err>>
+    where
+        E: Endian,
+        B: crate::AsyncByteSink,
+    {
+        while count != 0 {
+            let len = count.min(CHUNK);
+            to.write_slice(&[BYTE; CHUNK][..len]).await?;
+            count -= len;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    use crate::{endian::Little, ByteSource, SliceSink, SliceSrc};
+
+    use super::*;
+
+    #[test]
+    fn dyn_padding() {
+        let mut buf = [0u8; 100];
+        let mut sink = SliceSink::new(&mut buf);
+        DynPadding::<0xAA>
+            .write_with::<Little, _>(&mut sink, 70)
+            .unwrap();
+        assert_eq!(DynPadding::<0xAA>.encoded_len(70).unwrap(), 70);
+        assert!(buf[..70].iter().all(|b| *b == 0xAA));
+        assert_eq!(buf[70], 0);
+
+        let mut source = SliceSrc::new(b"abcd");
+        DynPadding::<0>::read_with::<Little, _>(&mut source, 3).unwrap();
+        assert_eq!(source.read_byte(), Ok(b'd'));
+    }
 }
@@ -60,4 +60,61 @@ where
     {
         T::write_with::<E, B>(&self.0, to, with)
     }
+
+    #[inline]
+    fn encoded_len(
+        &self,
+        with: Self::WriteWith,
+    ) -> Result<usize, crate::WrappedErr<::core::convert::Infallible>> {
+        T::encoded_len(&self.0, with)
+    }
+}
+
+impl<T, E> crate::FixedBarse for UseEndian<T, E>
+where
+    T: crate::FixedBarse,
+    E: Endian,
+{
+    const SIZE: usize = T::SIZE;
+    type Bytes = T::Bytes;
+}
+
+crate::barse_seek::forward_seek! {
+    impl[T, End] UseEndian<T, End>;
+}
+
+#[cfg(feature = "async")]
+#[expect(
+    clippy::future_not_send,
+    reason = "only forwards to the futures of T, which are Send when T's are"
+)]
+impl<T, E> crate::AsyncBarse for UseEndian<T, E>
+where
+    T: crate::AsyncBarse,
+    E: Endian,
+{
+    #[inline]
+    async fn read_with_async<_E, B>(
+        from: &mut B,
+        with: Self::ReadWith,
+    ) -> Result<Self, crate::WrappedErr<B::Err>>
+    where
+        _E: Endian,
+        B: crate::AsyncByteSource,
+    {
+        T::read_with_async::<E, B>(from, with).await.map(Self::new)
+    }
+
+    #[inline]
+    async fn write_with_async<_E, B>(
+        &self,
+        to: &mut B,
+        with: Self::WriteWith,
+    ) -> Result<(), crate::WrappedErr<B::Err>>
+    where
+        _E: Endian,
+        B: crate::AsyncByteSink,
+    {
+        T::write_with_async::<E, B>(&self.0, to, with).await
+    }
 }
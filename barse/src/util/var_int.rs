@@ -0,0 +1,508 @@
+//! [Uleb128], [Sleb128], [Vlq] and [ZigZag] implementations.
+
+use ::core::{
+    convert::Infallible,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{sealed::Sealed, Barse, ByteSink, ByteSource, Endian, Error, WrappedErr};
+
+/// Longest encoding of any supported integer, 128 bits in groups of 7.
+const MAX_LEN: usize = 19;
+
+/// Error returned when a value does not fit in target type.
+const OVERFLOW: Error = Error::Msg("variable length integer overflowed");
+
+/// Error returned when too many continuation bytes are read.
+const TOO_LONG: Error = Error::Msg("variable length integer exceeded maximum length");
+
+/// Unsigned integers which may be read/written with a variable length.
+pub trait VarUnsigned: Sealed + Copy {
+    /// Bit width of type.
+    const BITS: u32;
+
+    /// Widen to u128.
+    fn to_u128(self) -> u128;
+
+    /// Narrow from u128, if value fits.
+    fn from_u128(value: u128) -> Option<Self>;
+}
+
+/// Signed integers which may be read/written with a variable length.
+pub trait VarSigned: Sealed + Copy {
+    /// Bit width of type.
+    const BITS: u32;
+
+    /// Widen to i128.
+    fn to_i128(self) -> i128;
+
+    /// Narrow from i128, if value fits.
+    fn from_i128(value: i128) -> Option<Self>;
+}
+
+/// Implement [VarUnsigned] and [VarSigned] for integer types.
+macro_rules! var_int_traits {
+    ($trait:ident, $wide:ty, $to:ident, $from:ident, $($ty:ty),*) => {$(
+        impl $trait for $ty {
+            const BITS: u32 = <$ty>::BITS;
+
+            #[inline]
+            fn $to(self) -> $wide {
+                self as $wide
+            }
+
+            #[inline]
+            fn $from(value: $wide) -> Option<Self> {
+                Self::try_from(value).ok()
+            }
+        }
+    )*};
+}
+
+var_int_traits!(
+    VarUnsigned,
+    u128,
+    to_u128,
+    from_u128,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize
+);
+var_int_traits!(VarSigned, i128, to_i128, from_i128, i8, i16, i32, i64, i128, isize);
+
+/// Layout of encoded groups.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    /// Little endian groups, zero extended.
+    Uleb,
+
+    /// Little endian groups, sign extended.
+    Sleb,
+
+    /// Big endian groups, zero extended.
+    Vlq,
+}
+
+/// Incremental decoder of a variable length integer.
+#[derive(Debug, Clone, Copy)]
+struct Decoder {
+    /// Layout of groups.
+    kind: Kind,
+
+    /// Decoded bits so far.
+    value: u128,
+
+    /// Bits decoded so far.
+    shift: u32,
+
+    /// Bytes left before the encoding is too long.
+    left: u32,
+}
+
+impl Decoder {
+    /// Create a decoder for an integer with given bit width.
+    const fn new(kind: Kind, bits: u32) -> Self {
+        Self {
+            kind,
+            value: 0,
+            shift: 0,
+            left: bits.div_ceil(7),
+        }
+    }
+
+    /// Push a read byte, returning the decoded bits on the last byte.
+    ///
+    /// Signed values are returned as their two's complement bits.
+    ///
+    /// # Errors
+    /// If the value overflows 128 bits or too many bytes are pushed.
+    fn push(&mut self, byte: u8) -> Result<Option<u128>, Error> {
+        self.left = self.left.checked_sub(1).ok_or(TOO_LONG)?;
+        let group = u128::from(byte & 0x7f);
+        let last = byte & 0x80 == 0;
+        match self.kind {
+            Kind::Vlq => {
+                if self.value >> (u128::BITS - 7) != 0 {
+                    return Err(OVERFLOW);
+                }
+                self.value = self.value << 7 | group;
+            }
+            Kind::Uleb => {
+                if (group << self.shift) >> self.shift != group {
+                    return Err(OVERFLOW);
+                }
+                self.value |= group << self.shift;
+            }
+            Kind::Sleb => {
+                let extended = i128::from((byte << 1).cast_signed() >> 1);
+                if (last && (extended << self.shift) >> self.shift != extended)
+                    || (!last && (group << self.shift) >> self.shift != group)
+                {
+                    return Err(OVERFLOW);
+                }
+                self.value |= group << self.shift;
+                if last && byte & 0x40 != 0 && self.shift + 7 < u128::BITS {
+                    self.value |= u128::MAX << (self.shift + 7);
+                }
+            }
+        }
+        self.shift += 7;
+        Ok(last.then_some(self.value))
+    }
+
+    /// Decode from a source.
+    ///
+    /// # Errors
+    /// If source errors or decoded value is invalid.
+    fn read<B>(mut self, from: &mut B) -> Result<u128, WrappedErr<B::Err>>
+    where
+        B: ByteSource,
+    {
+        loop {
+            if let Some(value) = self.push(from.read_byte()?).map_err(WrappedErr::from_err)? {
+                return Ok(value);
+            }
+        }
+    }
+
+    /// Decode from an async source.
+    ///
+    /// # Errors
+    /// If source errors or decoded value is invalid.
+    #[cfg(feature = "async")]
+    async fn read_async<B>(mut self, from: &mut B) -> Result<u128, WrappedErr<B::Err>>
+    where
+        B: crate::AsyncByteSource,
+    {
+        loop {
+            let [byte] = from.read_array().await?;
+            if let Some(value) = self.push(byte).map_err(WrappedErr::from_err)? {
+                return Ok(value);
+            }
+        }
+    }
+}
+
+/// Encoded bytes of a variable length integer.
+#[derive(Debug, Clone, Copy)]
+struct Encoded {
+    /// Encoded bytes, only len are used.
+    bytes: [u8; MAX_LEN],
+
+    /// Amount of bytes used.
+    len: usize,
+}
+
+impl Encoded {
+    /// Get an empty encoding.
+    const fn new() -> Self {
+        Self {
+            bytes: [0u8; MAX_LEN],
+            len: 0,
+        }
+    }
+
+    /// Push a byte to encoding.
+    const fn push(&mut self, byte: u8) {
+        self.bytes[self.len] = byte;
+        self.len += 1;
+    }
+
+    /// Get used bytes.
+    fn as_slice(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// Encode as little endian zero extended groups.
+    const fn uleb(mut value: u128) -> Self {
+        let mut encoded = Self::new();
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                encoded.push(byte);
+                return encoded;
+            }
+            encoded.push(byte | 0x80);
+        }
+    }
+
+    /// Encode as little endian sign extended groups.
+    const fn sleb(mut value: i128) -> Self {
+        let mut encoded = Self::new();
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0) {
+                encoded.push(byte);
+                return encoded;
+            }
+            encoded.push(byte | 0x80);
+        }
+    }
+
+    /// Encode as big endian zero extended groups.
+    const fn vlq(value: u128) -> Self {
+        let mut encoded = Self::new();
+        let mut groups = (u128::BITS - value.leading_zeros()).div_ceil(7);
+        if groups == 0 {
+            groups = 1;
+        }
+        while groups > 1 {
+            groups -= 1;
+            encoded.push(((value >> (groups * 7)) & 0x7f) as u8 | 0x80);
+        }
+        encoded.push((value & 0x7f) as u8);
+        encoded
+    }
+}
+
+/// Map a signed value to an unsigned one, such that small magnitudes stay small.
+const fn zigzag(value: i128) -> u128 {
+    ((value << 1) ^ (value >> (i128::BITS - 1))).cast_unsigned()
+}
+
+/// Reverse [zigzag].
+const fn unzigzag(value: u128) -> i128 {
+    (value >> 1).cast_signed() ^ -((value & 1).cast_signed())
+}
+
+/// Implement wrapper type for a variable length encoding.
+macro_rules! var_int_wrapper {
+    (
+        $(#[$attr:meta])*
+        $name:ident<T: $bound:ident>,
+        $kind:expr,
+        |$raw:ident| $from_raw:expr,
+        |$value:ident| $encode:expr $(,)?
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(transparent)]
+        pub struct $name<T>(T);
+
+        impl<T> $name<T> {
+            #[doc = concat!("Construct a new [", stringify!($name), "] from value.")]
+            #[inline]
+            pub const fn new(value: T) -> Self {
+                Self(value)
+            }
+
+            #[doc = concat!("Unwrap [", stringify!($name), "] to wrapped value.")]
+            #[inline]
+            pub fn into_inner(self) -> T {
+                self.0
+            }
+        }
+
+        impl<T> $name<T>
+        where
+            T: $bound,
+        {
+            /// Decode value from raw bits.
+            ///
+            /// # Errors
+            /// If the value does not fit in T.
+            #[inline]
+            fn from_raw($raw: u128) -> Result<Self, Error> {
+                $from_raw.map(Self).ok_or(OVERFLOW)
+            }
+
+            /// Encode value.
+            #[inline]
+            fn encode(&self) -> Encoded {
+                let $value = self.0;
+                $encode
+            }
+        }
+
+        impl<T> Deref for $name<T> {
+            type Target = T;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl<T> DerefMut for $name<T> {
+            #[inline]
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        impl<T> From<T> for $name<T> {
+            #[inline]
+            fn from(value: T) -> Self {
+                Self(value)
+            }
+        }
+
+        impl<T> Barse for $name<T>
+        where
+            T: $bound,
+        {
+            type ReadWith = ();
+            type WriteWith = ();
+
+            #[inline]
+            fn read_with<E, B>(from: &mut B, _with: ()) -> Result<Self, WrappedErr<B::Err>>
+            where
+                E: Endian,
+                B: ByteSource,
+            {
+                let raw = Decoder::new($kind, T::BITS).read(from)?;
+                Self::from_raw(raw).map_err(WrappedErr::from_err)
+            }
+
+            #[inline]
+            fn write_with<E, B>(&self, to: &mut B, _with: ()) -> Result<(), WrappedErr<B::Err>>
+            where
+                E: Endian,
+                B: ByteSink,
+            {
+                Ok(to.write_slice(self.encode().as_slice())?)
+            }
+
+            #[inline]
+            fn encoded_len(&self, _with: ()) -> Result<usize, WrappedErr<Infallible>> {
+                Ok(self.encode().len)
+            }
+        }
+
+        crate::barse_seek::forward_seek! {
+            impl[T] $name<T>;
+        }
+
+        #[cfg(feature = "async")]
+        #[expect(
+            clippy::future_not_send,
+            reason = "encoded bytes are kept in a local buffer, futures are Send when the source/sink is"
+        )]
+        impl<T> crate::AsyncBarse for $name<T>
+        where
+            T: $bound,
+        {
+            #[inline]
+            async fn read_with_async<E, B>(
+                from: &mut B,
+                _with: (),
+            ) -> Result<Self, WrappedErr<B::Err>>
+            where
+                E: Endian,
+                B: crate::AsyncByteSource,
+            {
+                let raw = Decoder::new($kind, T::BITS).read_async(from).await?;
+                Self::from_raw(raw).map_err(WrappedErr::from_err)
+            }
+
+            #[inline]
+            async fn write_with_async<E, B>(
+                &self,
+                to: &mut B,
+                _with: (),
+            ) -> Result<(), WrappedErr<B::Err>>
+            where
+                E: Endian,
+                B: crate::AsyncByteSink,
+            {
+                Ok(to.write_slice(self.encode().as_slice()).await?)
+            }
+        }
+    };
+}
+
+var_int_wrapper!(
+    /// Unsigned LEB128 encoded integer, as used by WebAssembly and DWARF.
+    Uleb128<T: VarUnsigned>,
+    Kind::Uleb,
+    |raw| T::from_u128(raw),
+    |value| Encoded::uleb(value.to_u128()),
+);
+
+var_int_wrapper!(
+    /// Signed LEB128 encoded integer, as used by WebAssembly and DWARF.
+    Sleb128<T: VarSigned>,
+    Kind::Sleb,
+    |raw| T::from_i128(raw.cast_signed()),
+    |value| Encoded::sleb(value.to_i128()),
+);
+
+var_int_wrapper!(
+    /// Big endian variable length quantity, as used by MIDI.
+    Vlq<T: VarUnsigned>,
+    Kind::Vlq,
+    |raw| T::from_u128(raw),
+    |value| Encoded::vlq(value.to_u128()),
+);
+
+var_int_wrapper!(
+    /// Signed integer zigzag mapped and encoded as unsigned LEB128, as used by protobuf.
+    ZigZag<T: VarSigned>,
+    Kind::Uleb,
+    |raw| T::from_i128(unzigzag(raw)),
+    |value| Encoded::uleb(zigzag(value.to_i128())),
+);
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::missing_panics_doc)]
+
+    use crate::{endian::Little, SliceSink, SliceSrc};
+
+    use super::*;
+
+    /// Write value and compare to expected bytes, then read it back.
+    fn round_trip<T>(value: T, expected: &[u8])
+    where
+        T: Barse<ReadWith = (), WriteWith = ()> + PartialEq + ::core::fmt::Debug,
+    {
+        let mut buf = [0u8; MAX_LEN];
+        let mut sink = SliceSink::new(&mut buf);
+        value.write_with::<Little, _>(&mut sink, ()).unwrap();
+        assert_eq!(&buf[..expected.len()], expected);
+        assert_eq!(value.encoded_len(()).unwrap(), expected.len());
+
+        let read = T::read_with::<Little, _>(&mut SliceSrc::new(expected), ()).unwrap();
+        assert_eq!(read, value);
+    }
+
+    #[test]
+    fn encodings() {
+        round_trip(Uleb128(0u32), &[0x00]);
+        round_trip(Uleb128(624_485u32), &[0xe5, 0x8e, 0x26]);
+        let mut max = [0xff; MAX_LEN];
+        max[MAX_LEN - 1] = 0x03;
+        round_trip(Uleb128(u128::MAX), &max);
+        round_trip(Sleb128(-123_456i32), &[0xc0, 0xbb, 0x78]);
+        round_trip(Sleb128(63i8), &[0x3f]);
+        round_trip(Sleb128(-64i8), &[0x40]);
+        let mut min = [0x80; MAX_LEN];
+        min[MAX_LEN - 1] = 0x7e;
+        round_trip(Sleb128(i128::MIN), &min);
+        round_trip(Vlq(0x3fffu16), &[0xff, 0x7f]);
+        round_trip(Vlq(0x0fff_ffffu32), &[0xff, 0xff, 0xff, 0x7f]);
+        round_trip(ZigZag(-1i64), &[0x01]);
+        round_trip(ZigZag(-2i64), &[0x03]);
+        round_trip(ZigZag(i32::MIN), &[0xff, 0xff, 0xff, 0xff, 0x0f]);
+    }
+
+    #[test]
+    fn invalid() {
+        let read =
+            |bytes: &[u8]| Uleb128::<u8>::read_with::<Little, _>(&mut SliceSrc::new(bytes), ());
+        assert!(read(&[0x80, 0x02]).is_err());
+        assert!(read(&[0x80, 0x80, 0x00]).is_err());
+        assert!(read(&[0xff, 0x01]).is_ok());
+
+        let read =
+            |bytes: &[u8]| Sleb128::<i8>::read_with::<Little, _>(&mut SliceSrc::new(bytes), ());
+        assert!(read(&[0x80, 0x01]).is_err());
+        assert_eq!(*read(&[0xc0, 0x00]).unwrap(), 64);
+        assert_eq!(*read(&[0x80, 0x7f]).unwrap(), -128);
+    }
+}
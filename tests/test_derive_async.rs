@@ -0,0 +1,278 @@
+//! Async derive tests.
+
+use ::barse::{
+    endian::{Big, Little},
+    ext::{ByteSinkExt, ByteSourceExt},
+    util::ByteArray,
+    AsyncBarse, AsyncReadSource, AsyncWriteSink, Barse, SliceSrc,
+};
+use ::futures::{executor::block_on, io::Cursor};
+
+#[derive(Barse, Debug, PartialEq)]
+#[barse(async)]
+struct Record {
+    kind: u8,
+    id: u32,
+    #[barse(endian = Big)]
+    len: u16,
+    tag: ByteArray<2>,
+    #[barse(bytes = 3)]
+    raw: [u8; 3],
+    #[barse(ignore)]
+    cached: u64,
+}
+
+#[derive(Barse, Debug, PartialEq)]
+#[barse(async, discriminant = u8)]
+enum Frame {
+    #[barse(discriminant = 0)]
+    Empty,
+    #[barse(discriminant = 1)]
+    Single(Record),
+    #[barse(discriminant = 2)]
+    Pair { first: Record, second: [u16; 2] },
+}
+
+#[derive(Barse, Debug, PartialEq)]
+#[barse(async, peek = 2)]
+enum Tagged {
+    #[barse(discriminant = *b"id")]
+    Id { tag: [u8; 2], id: u32 },
+    #[barse(discriminant = *b"ln")]
+    Len { tag: [u8; 2], len: u16 },
+}
+
+fn record() -> Record {
+    Record {
+        kind: 7,
+        id: 0x0102_0304,
+        len: 0x0506,
+        tag: ByteArray::new(*b"ok"),
+        raw: *b"abc",
+        cached: 0,
+    }
+}
+
+/// Test of async reads and writes matching sync ones.
+#[test]
+fn round_trip() {
+    let frame = Frame::Pair {
+        first: record(),
+        second: [1, 2],
+    };
+
+    let mut sink = AsyncWriteSink::new(Cursor::new(Vec::new()));
+    block_on(frame.write_with_async::<Little, _>(&mut sink, ())).unwrap();
+    block_on(sink.flush()).unwrap();
+    let bytes = sink.into_inner().into_inner();
+
+    let mut sync_bytes = Vec::new();
+    sync_bytes.write_le(&frame).unwrap();
+    assert_eq!(bytes, sync_bytes);
+    assert_eq!(SliceSrc::new(&bytes).read_le::<Frame>().unwrap(), frame);
+
+    let mut source = AsyncReadSource::new(Cursor::new(bytes));
+    let read = block_on(Frame::read_with_async::<Little, _>(&mut source, ())).unwrap();
+    assert_eq!(read, frame);
+}
+
+/// Test of context added to errors by async impls.
+#[test]
+fn error_context() {
+    let bytes = [1u8, 7, 4, 3, 2, 1, 5];
+    let mut source = AsyncReadSource::new(Cursor::new(bytes));
+    let err = block_on(Frame::read_with_async::<Little, _>(&mut source, ())).unwrap_err();
+
+    let context = err.context().expect("error should have context");
+    assert_eq!(context.to_string(), "Frame::Single.0.len");
+}
+
+/// Test of enum variants selected by peeked bytes in async impls.
+#[test]
+fn peek_variant() {
+    let bytes = b"ln\x05\x00id\x01\x00\x00\x00".to_vec();
+    let mut source = AsyncReadSource::new(Cursor::new(bytes));
+
+    let len = block_on(Tagged::read_with_async::<Little, _>(&mut source, ())).unwrap();
+    let id = block_on(Tagged::read_with_async::<Little, _>(&mut source, ())).unwrap();
+    assert_eq!(
+        len,
+        Tagged::Len {
+            tag: *b"ln",
+            len: 5
+        }
+    );
+    assert_eq!(id, Tagged::Id { tag: *b"id", id: 1 });
+}
+
+/// Test of sized fields in async impls.
+#[test]
+fn sized_field() {
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(async)]
+    struct Chunk {
+        len: u8,
+        #[barse(size = len, leftover = skip)]
+        body: [u8; 2],
+        tail: u8,
+    }
+
+    let chunk = Chunk {
+        len: 4,
+        body: *b"ab",
+        tail: 9,
+    };
+
+    let mut sink = AsyncWriteSink::new(Cursor::new(Vec::new()));
+    block_on(chunk.write_with_async::<Little, _>(&mut sink, ())).unwrap();
+    block_on(sink.flush()).unwrap();
+    let bytes = sink.into_inner().into_inner();
+    assert_eq!(bytes, b"\x04ab\x00\x00\x09");
+
+    let mut source = AsyncReadSource::new(Cursor::new(bytes));
+    let read = block_on(Chunk::read_with_async::<Little, _>(&mut source, ())).unwrap();
+    assert_eq!(read, chunk);
+}
+
+/// Test of checksums in async impls.
+#[test]
+fn checksum_field() {
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(async)]
+    struct Block {
+        data: [u8; 4],
+        #[barse(checksum(adler32))]
+        adler: u32,
+    }
+
+    let block = Block {
+        data: *b"abcd",
+        adler: 0,
+    };
+
+    let mut sink = AsyncWriteSink::new(Cursor::new(Vec::new()));
+    block_on(block.write_with_async::<Big, _>(&mut sink, ())).unwrap();
+    block_on(sink.flush()).unwrap();
+    let bytes = sink.into_inner().into_inner();
+    assert_eq!(bytes, b"abcd\x03\xd8\x01\x8b");
+
+    let mut source = AsyncReadSource::new(Cursor::new(bytes));
+    let read = block_on(Block::read_with_async::<Big, _>(&mut source, ())).unwrap();
+    assert_eq!(read.adler, 0x03d8_018b);
+}
+
+/// Test of bit fields in async impls.
+#[test]
+fn bit_fields() {
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(async)]
+    struct Flags {
+        #[barse(bits = 1)]
+        a: bool,
+        #[barse(bits = 3)]
+        b: u8,
+        #[barse(bits = 12)]
+        c: u16,
+    }
+
+    let flags = Flags {
+        a: true,
+        b: 2,
+        c: 0x123,
+    };
+
+    let mut sink = AsyncWriteSink::new(Cursor::new(Vec::new()));
+    block_on(flags.write_with_async::<Little, _>(&mut sink, ())).unwrap();
+    block_on(sink.flush()).unwrap();
+    let bytes = sink.into_inner().into_inner();
+    assert_eq!(bytes, b"\xa1\x23");
+
+    let mut source = AsyncReadSource::new(Cursor::new(bytes));
+    let read = block_on(Flags::read_with_async::<Little, _>(&mut source, ())).unwrap();
+    assert_eq!(read, flags);
+}
+
+/// Test of assertions in async impls.
+#[test]
+fn assertions() {
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(async)]
+    struct Version {
+        #[barse(assert(*major > 0, "major version should not be zero", write))]
+        major: u8,
+        minor: u8,
+    }
+
+    let mut sink = AsyncWriteSink::new(Cursor::new(Vec::new()));
+    let err = block_on(Version { major: 0, minor: 1 }.write_with_async::<Little, _>(&mut sink, ()))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "assertion failed, major version should not be zero (in Version.major)"
+    );
+
+    let mut source = AsyncReadSource::new(Cursor::new(vec![0u8, 1]));
+    assert!(block_on(Version::read_with_async::<Little, _>(&mut source, ())).is_err());
+}
+
+/// Test of collection fields in async impls.
+#[test]
+fn collection_fields() {
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(async)]
+    struct Lists {
+        len: u8,
+        #[barse(count = len)]
+        values: Vec<u16>,
+        #[barse(until = |byte: &u8| *byte == 0)]
+        name: Vec<u8>,
+        #[barse(until_eof)]
+        rest: Vec<u16>,
+    }
+
+    let lists = Lists {
+        len: 2,
+        values: vec![1, 2],
+        name: b"hi\0".to_vec(),
+        rest: vec![3, 4],
+    };
+
+    let mut sink = AsyncWriteSink::new(Cursor::new(Vec::new()));
+    block_on(lists.write_with_async::<Little, _>(&mut sink, ())).unwrap();
+    block_on(sink.flush()).unwrap();
+    let bytes = sink.into_inner().into_inner();
+    assert_eq!(bytes, b"\x02\x01\x00\x02\x00hi\0\x03\x00\x04\x00");
+
+    let mut source = AsyncReadSource::new(Cursor::new(bytes));
+    let read = block_on(Lists::read_with_async::<Little, _>(&mut source, ())).unwrap();
+    assert_eq!(read, lists);
+}
+
+/// Test of field alignment in async impls.
+#[test]
+fn aligned_fields() {
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(async, pad_to_multiple = 4)]
+    struct Record {
+        tag: u8,
+        #[barse(align = 2)]
+        value: u16,
+        last: u8,
+    }
+
+    let record = Record {
+        tag: 1,
+        value: 2,
+        last: 3,
+    };
+
+    let mut sink = AsyncWriteSink::new(Cursor::new(Vec::new()));
+    block_on(record.write_with_async::<Little, _>(&mut sink, ())).unwrap();
+    block_on(sink.flush()).unwrap();
+    let bytes = sink.into_inner().into_inner();
+    assert_eq!(bytes, b"\x01\0\x02\0\x03\0\0\0");
+
+    let mut source = AsyncReadSource::new(Cursor::new(bytes));
+    let read = block_on(Record::read_with_async::<Little, _>(&mut source, ())).unwrap();
+    assert_eq!(read, record);
+}
@@ -1,7 +1,7 @@
 //! Struct Derive tests.
 #![allow(dead_code)]
 
-use ::barse::{Barse, ReadAs, WriteAs};
+use ::barse::{Barse, FixedBarse, ReadAs, WriteAs};
 
 #[derive(Barse)]
 struct Simple {
@@ -13,7 +13,7 @@ struct Simple {
 use ::std::marker::PhantomData;
 
 use ::barse::{
-    endian::Little,
+    endian::{Big, Little},
     ext::{ByteSinkExt, ByteSourceExt},
     util::UseEndian,
     SliceSink, SliceSrc,
@@ -53,6 +53,14 @@ struct AlwaysLittle<T>(#[barse(with)] T);
 )]
 struct WithEndian<T, E>(#[barse(with)] T, #[barse(ignore)] PhantomData<fn() -> E>);
 
+#[derive(Debug, Clone, Barse)]
+struct PrefixedVec {
+    #[barse(calc = self.vec.len() as u16, verify)]
+    size: u16,
+    #[barse(count = size)]
+    vec: Vec<i32>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 struct SizedVec;
 
@@ -86,8 +94,9 @@ impl WriteAs<Vec<i32>, ()> for SizedVec {
     }
 }
 
-#[derive(Debug, Clone, Barse)]
-struct PrefixedVec {
+#[derive(Debug, Clone, PartialEq, Barse)]
+struct AdaptedVec {
+    #[barse(calc = self.vec.len() as u16, verify)]
     size: u16,
     #[barse(as SizedVec)]
     #[barse(read_with = size)]
@@ -106,7 +115,7 @@ enum EitherInt {
 #[test]
 fn basic() {
     #[derive(Barse, PartialEq, Debug)]
-    #[barse(field_prefix = field_)]
+    #[barse(field_prefix = field_, fixed)]
     struct TestStruct {
         #[barse(endian = barse::endian::Little)]
         a: f32,
@@ -124,11 +133,11 @@ fn basic() {
         d: *b"hello",
     };
 
-    let mut buf = [0u8; size_of::<TestStruct>()];
+    assert_eq!(TestStruct::SIZE, 4 + 8 + 16 + 5);
 
-    SliceSink::new(&mut buf).write_be(&test_struct).unwrap();
+    let buf = test_struct.to_bytes::<Big>().unwrap();
 
-    let barsed_test_struct = SliceSrc::new(&buf).read_be::<TestStruct>().unwrap();
+    let barsed_test_struct = TestStruct::from_bytes::<Big>(&buf).unwrap();
 
     assert_eq!(test_struct, barsed_test_struct);
 }
@@ -137,12 +146,14 @@ fn basic() {
 #[test]
 fn deterministic() {
     #[derive(Debug, PartialEq, Eq, Barse, Clone, Copy)]
+    #[barse(fixed)]
     struct A {
         a: i16,
         b: u16,
         c: i32,
     }
     #[derive(Debug, PartialEq, Eq, Barse, Clone, Copy)]
+    #[barse(fixed)]
     struct B(i16, u16, i32);
 
     let (a, b, c) = (-5, 67, -35_000);
@@ -150,13 +161,1145 @@ fn deterministic() {
     let struct_a = A { a, b, c };
     let struct_b = B(a, b, c);
 
-    let mut buf = [0u8; size_of::<A>()];
-
-    SliceSink::new(&mut buf)
-        .write_be(&UseEndian::<A, Little>::new(struct_a))
+    let buf = UseEndian::<A, Little>::new(struct_a)
+        .to_bytes::<Big>()
         .unwrap();
 
-    let barsed_b = SliceSrc::new(&buf).read_le::<B>().unwrap();
+    let barsed_b = B::from_bytes::<Little>(&buf).unwrap();
 
     assert_eq!(struct_b, barsed_b);
 }
+
+/// Test of context added to errors by derived impls.
+#[test]
+fn error_context() {
+    #[derive(Barse, Debug)]
+    struct Entry {
+        tag: u8,
+        kind: u32,
+    }
+
+    #[derive(Barse, Debug)]
+    struct Header {
+        magic: u16,
+        entries: [Entry; 4],
+    }
+
+    let buf = [0u8; 2 + 5 * 3 + 2];
+    let err = SliceSrc::new(&buf).read_le::<Header>().unwrap_err();
+    let context = err.context().expect("error should have context");
+
+    assert_eq!(context.offset(), Some(18));
+    assert_eq!(context.to_string(), "Header.entries[3].kind");
+}
+
+/// Test of reads borrowing from source.
+#[test]
+fn borrowed() {
+    use ::barse::{ext::BorrowSourceExt, BarseBorrow};
+
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(borrow = 'a)]
+    struct Packet<'a> {
+        len: u16,
+        #[barse(count = len)]
+        payload: &'a [u8],
+        kind: &'a [u8; 2],
+        #[barse(count = 5)]
+        name: &'a str,
+    }
+
+    let buf = [3, 0, 1, 2, 3, b'a', b'b', b'h', b'e', b'l', b'l', b'o'];
+    let packet = SliceSrc::new(&buf).read_borrow_le::<Packet>().unwrap();
+
+    assert_eq!(
+        packet,
+        Packet {
+            len: 3,
+            payload: &buf[2..5],
+            kind: b"ab",
+            name: "hello",
+        }
+    );
+    assert!(::core::ptr::eq(packet.payload, &buf[2..5]));
+
+    let mut out = [0u8; 12];
+    packet
+        .write_borrow::<Little, _>(&mut SliceSink::new(&mut out), ())
+        .unwrap();
+    assert_eq!(out, buf);
+}
+
+/// Test of borrowing inferred from fields borrowing bytes.
+#[test]
+fn borrowed_inferred() {
+    use ::barse::{ext::BorrowSourceExt, BarseBorrow};
+
+    #[derive(Barse, Debug, PartialEq)]
+    struct Packet<'a> {
+        len: u16,
+        #[barse(count = len)]
+        payload: &'a [u8],
+    }
+
+    let buf = [2, 0, 7, 9];
+    let packet = SliceSrc::new(&buf).read_borrow_le::<Packet>().unwrap();
+    assert_eq!(
+        packet,
+        Packet {
+            len: 2,
+            payload: &[7, 9],
+        }
+    );
+    assert!(::core::ptr::eq(packet.payload, &buf[2..]));
+
+    let mut out = [0u8; 4];
+    packet
+        .write_borrow::<Little, _>(&mut SliceSink::new(&mut out), ())
+        .unwrap();
+    assert_eq!(out, buf);
+}
+
+/// Types with lifetimes not borrowing from source.
+#[test]
+fn lifetime_owned() {
+    use ::std::borrow::Cow;
+
+    #[derive(Barse, Debug, PartialEq)]
+    struct Named<'a> {
+        len: u8,
+        tag: Cow<'a, [u8; 2]>,
+    }
+
+    let buf = [7, b'o', b'k'];
+    let named = SliceSrc::new(&buf).read_le::<Named>().unwrap();
+
+    assert_eq!(
+        named,
+        Named {
+            len: 7,
+            tag: Cow::Borrowed(b"ok"),
+        }
+    );
+
+    let mut out = [0u8; 3];
+    SliceSink::new(&mut out).write_le(&named).unwrap();
+    assert_eq!(out, buf);
+}
+
+/// Test of enum variants selected by peeked bytes.
+#[test]
+fn peek_variant() {
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(peek = 4)]
+    enum Chunk {
+        #[barse(discriminant = *b"RIFF")]
+        Riff { magic: [u8; 4], size: u32 },
+        #[barse(discriminant = *b"LIST")]
+        List { magic: [u8; 4], count: u16 },
+    }
+
+    let buf = *b"LIST\x02\x00RIFF\x08\x00\x00\x00";
+    let mut source = SliceSrc::new(&buf);
+
+    let list = source.read_le::<Chunk>().unwrap();
+    let riff = source.read_le::<Chunk>().unwrap();
+    assert_eq!(
+        list,
+        Chunk::List {
+            magic: *b"LIST",
+            count: 2
+        }
+    );
+    assert_eq!(
+        riff,
+        Chunk::Riff {
+            magic: *b"RIFF",
+            size: 8
+        }
+    );
+
+    let mut out = [0u8; 14];
+    let mut sink = SliceSink::new(&mut out);
+    sink.write_le(&list).unwrap();
+    sink.write_le(&riff).unwrap();
+    assert_eq!(out, buf);
+
+    // Sources unable to peek have the bytes replayed.
+    let mut source = ::barse::ReadSource::new(&buf[..]);
+    assert_eq!(source.read_le::<Chunk>().unwrap(), list);
+    assert_eq!(source.read_le::<Chunk>().unwrap(), riff);
+
+    // Peeked bytes are left in source if no variant matches.
+    let mut source = SliceSrc::new(b"JUNK\x00\x00");
+    let err = source.read_le::<Chunk>().unwrap_err();
+    assert_eq!(err.context().and_then(|ctx| ctx.offset()), Some(0));
+    assert_eq!(source.len(), 6);
+}
+
+/// Test of fields limited to a size given by earlier fields.
+#[test]
+fn sized_field() {
+    #[derive(Barse, Debug, PartialEq)]
+    struct Header {
+        version: u16,
+    }
+
+    #[derive(Barse, Debug, PartialEq)]
+    struct Chunk {
+        len: u32,
+        #[barse(size = len, leftover = skip)]
+        header: Header,
+        tail: u8,
+    }
+
+    #[derive(Barse, Debug, PartialEq)]
+    struct Strict {
+        len: u8,
+        #[barse(size = len)]
+        header: Header,
+    }
+
+    let buf = *b"\x04\x00\x00\x00\x01\x00\xff\xff\x07";
+    let chunk = SliceSrc::new(&buf).read_le::<Chunk>().unwrap();
+    assert_eq!(
+        chunk,
+        Chunk {
+            len: 4,
+            header: Header { version: 1 },
+            tail: 7,
+        }
+    );
+
+    let mut out = [0xeeu8; 9];
+    SliceSink::new(&mut out).write_le(&chunk).unwrap();
+    assert_eq!(out, *b"\x04\x00\x00\x00\x01\x00\x00\x00\x07");
+
+    let err = SliceSrc::new(b"\x03\x01\x00\x00")
+        .read_le::<Strict>()
+        .unwrap_err();
+    assert_eq!(err.context().and_then(|ctx| ctx.offset()), Some(3));
+    assert!(matches!(err.kind(), ::barse::error::ErrKind::Other(_)));
+
+    let err = SliceSrc::new(b"\x01\x01\x00")
+        .read_le::<Strict>()
+        .unwrap_err();
+    assert_eq!(err.context().and_then(|ctx| ctx.offset()), Some(1));
+
+    let strict = Strict {
+        len: 1,
+        header: Header { version: 1 },
+    };
+    let mut out = [0u8; 3];
+    assert!(SliceSink::new(&mut out).write_le(&strict).is_err());
+}
+
+/// Test of checksums over ranges of fields.
+#[test]
+fn checksum_field() {
+    #[derive(Barse, Debug, PartialEq)]
+    struct Packet {
+        kind: u8,
+        len: u8,
+        #[barse(size = len, leftover = skip)]
+        payload: [u8; 2],
+        #[barse(checksum(sum8, over = payload..here))]
+        payload_sum: u8,
+        #[barse(checksum(crc32))]
+        crc: u32,
+    }
+
+    let packet = Packet {
+        kind: 1,
+        len: 3,
+        payload: *b"ab",
+        payload_sum: 0,
+        crc: 0,
+    };
+
+    let mut out = [0u8; 10];
+    SliceSink::new(&mut out).write_le(&packet).unwrap();
+    assert_eq!(&out[..6], b"\x01\x03ab\x00\xc3");
+    let crc = u32::from_le_bytes(out[6..].try_into().unwrap());
+
+    let read = SliceSrc::new(&out).read_le::<Packet>().unwrap();
+    assert_eq!(
+        read,
+        Packet {
+            payload_sum: 0xc3,
+            crc,
+            ..packet
+        }
+    );
+
+    out[4] = 1;
+    let err = SliceSrc::new(&out).read_le::<Packet>().unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        ::barse::error::ErrKind::Other(::barse::Error::Checksum(
+            ::barse::error::ChecksumMismatch {
+                expected: 0xc4,
+                actual: 0xc3,
+            }
+        ))
+    ));
+    assert_eq!(err.context().and_then(|ctx| ctx.offset()), Some(6));
+
+    out[4] = 0;
+    out[0] = 2;
+    let err = SliceSrc::new(&out).read_le::<Packet>().unwrap_err();
+    assert_eq!(
+        err.context().map(ToString::to_string).as_deref(),
+        Some("Packet.crc")
+    );
+}
+
+/// Test of encoded length of derived types.
+#[test]
+fn encoded_len() {
+    #[derive(Barse)]
+    struct Mixed {
+        a: u16,
+        #[barse(bytes = 3)]
+        b: [u8; 3],
+        #[barse(ignore)]
+        c: u64,
+        d: [u32; 2],
+        e: PrefixedVec,
+        len: u8,
+        #[barse(size = len, leftover = skip)]
+        f: u8,
+    }
+
+    let mixed = Mixed {
+        a: 1,
+        b: *b"abc",
+        c: 5,
+        d: [1, 2],
+        e: PrefixedVec {
+            size: 3,
+            vec: vec![1, 2, 3],
+        },
+        len: 4,
+        f: 9,
+    };
+
+    let len = mixed.encoded_len(()).unwrap();
+    assert_eq!(len, 2 + 3 + 8 + 2 + 12 + 1 + 4);
+
+    let mut buf = Vec::new();
+    buf.write_le(&mixed).unwrap();
+    assert_eq!(buf.len(), len);
+
+    assert_eq!(EitherInt::Signed(-1).encoded_len(()).unwrap(), 16);
+    assert_eq!(AlwaysLittle(5u32).encoded_len(()).unwrap(), 4);
+}
+
+/// Test of fixed size of derived types.
+#[test]
+fn fixed_size() {
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(fixed)]
+    struct Header {
+        magic: ::barse::util::ByteArray<4>,
+        version: UseEndian<u16, Little>,
+        #[barse(ignore)]
+        cached: Vec<u8>,
+    }
+
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(fixed)]
+    struct File {
+        header: Header,
+        entries: [u32; 2],
+    }
+
+    assert_eq!(Header::SIZE, 6);
+    assert_eq!(File::SIZE, 14);
+
+    let file = File {
+        header: Header {
+            magic: ::barse::util::ByteArray::new(*b"FILE"),
+            version: UseEndian::new(1),
+            cached: Vec::new(),
+        },
+        entries: [2, 3],
+    };
+    let bytes: [u8; 14] = file.to_bytes::<Big>().unwrap();
+    assert_eq!(&bytes, b"FILE\x01\x00\x00\x00\x00\x02\x00\x00\x00\x03");
+    assert_eq!(File::from_bytes::<Big>(&bytes).unwrap(), file);
+}
+
+/// Test of null terminated strings.
+#[test]
+fn c_strings() {
+    use ::barse::util::{CStrBytes, FixedCStr};
+
+    #[derive(Barse, Debug, PartialEq)]
+    struct Entry {
+        name: FixedCStr<8>,
+        comment: CStrBytes<16>,
+        value: u8,
+    }
+
+    let buf = *b"name\0\xff\xff\xff\x68i\0\x05";
+    let entry = SliceSrc::new(&buf).read_le::<Entry>().unwrap();
+    assert_eq!(entry.name.as_c_str(), c"name");
+    assert_eq!(&*entry.comment, c"hi");
+    assert_eq!(entry.value, 5);
+
+    let mut out = [0u8; 12];
+    SliceSink::new(&mut out).write_le(&entry).unwrap();
+    assert_eq!(&out, b"name\0\0\0\0hi\0\x05");
+}
+
+/// Test of variable length integers.
+#[test]
+fn var_ints() {
+    use ::barse::{
+        barse_as::{Uleb128, ZigZag},
+        util::Vlq,
+    };
+
+    #[derive(Barse, Debug, PartialEq)]
+    struct Record {
+        #[barse(as Uleb128)]
+        id: u64,
+        #[barse(as ZigZag)]
+        delta: i32,
+        ticks: Vlq<u32>,
+    }
+
+    let buf = *b"\xe5\x8e\x26\x03\x81\x00";
+    let record = SliceSrc::new(&buf).read_le::<Record>().unwrap();
+    assert_eq!(
+        record,
+        Record {
+            id: 624_485,
+            delta: -2,
+            ticks: Vlq::new(128),
+        }
+    );
+    assert_eq!(record.encoded_len(()).unwrap(), buf.len());
+
+    let mut out = [0u8; 6];
+    SliceSink::new(&mut out).write_le(&record).unwrap();
+    assert_eq!(out, buf);
+}
+
+/// Test of fields packed into bits.
+#[test]
+fn bit_fields() {
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(fixed)]
+    struct Ipv4Start {
+        #[barse(bits = 4)]
+        version: u8,
+        #[barse(bits = 4)]
+        ihl: u8,
+        #[barse(bits = 6)]
+        dscp: u8,
+        #[barse(bits = 2)]
+        ecn: u8,
+        total_len: u16,
+        #[barse(bits = 1)]
+        flag: bool,
+        #[barse(bits = 7)]
+        offset: i8,
+    }
+
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(bit_order = lsb0)]
+    struct Lsb {
+        #[barse(bits = 3)]
+        low: u8,
+        #[barse(bits = 5)]
+        high: u8,
+    }
+
+    let buf = *b"\x45\x02\x00\x14\xff";
+    let header = SliceSrc::new(&buf).read_be::<Ipv4Start>().unwrap();
+    assert_eq!(
+        header,
+        Ipv4Start {
+            version: 4,
+            ihl: 5,
+            dscp: 0,
+            ecn: 2,
+            total_len: 20,
+            flag: true,
+            offset: -1,
+        }
+    );
+    assert_eq!(Ipv4Start::SIZE, 5);
+    assert_eq!(header.to_bytes::<Big>().unwrap(), buf);
+
+    let lsb = SliceSrc::new(&[0b1010_1101]).read_le::<Lsb>().unwrap();
+    assert_eq!(lsb, Lsb { low: 5, high: 21 });
+
+    let mut out = [0u8; 1];
+    let too_wide = Lsb { low: 8, high: 0 };
+    assert!(SliceSink::new(&mut out).write_le(&too_wide).is_err());
+}
+
+/// Test of magic values checked on read.
+#[test]
+fn magic() {
+    use ::barse::{
+        error::{ErrKind, MagicMismatch},
+        Error,
+    };
+
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(magic = b"\x7fELF", fixed)]
+    struct Header {
+        class: u8,
+        #[barse(magic = b"\x01")]
+        version: u8,
+    }
+
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(discriminant = u8)]
+    #[repr(u8)]
+    enum Chunk {
+        #[barse(magic = b"fmt ")]
+        Format(u16) = 1,
+        Data(u8) = 2,
+    }
+
+    let buf = *b"\x7fELF\x02\x01\x05";
+    let header = SliceSrc::new(&buf).read_le::<Header>().unwrap();
+    assert_eq!(
+        header,
+        Header {
+            class: 2,
+            version: 5
+        }
+    );
+    assert_eq!(Header::SIZE, 7);
+    assert_eq!(header.to_bytes::<Little>().unwrap(), buf);
+
+    let err = SliceSrc::new(b"\x7fELL\x02\x01\x05")
+        .read_le::<Header>()
+        .unwrap_err();
+    let ErrKind::Other(Error::Magic(mismatch)) = err.kind() else {
+        panic!("expected magic mismatch, got {err}");
+    };
+    const ELF: &[u8] = b"\x7fELF";
+    assert_eq!(mismatch, &MagicMismatch::new(&ELF, b"\x7fELL"));
+    assert_eq!(mismatch.actual(), b"\x7fELL");
+
+    let chunk = SliceSrc::new(b"\x01fmt \x10\x00")
+        .read_le::<Chunk>()
+        .unwrap();
+    assert_eq!(chunk, Chunk::Format(16));
+    assert!(SliceSrc::new(b"\x01fmt_\x10\x00")
+        .read_le::<Chunk>()
+        .is_err());
+
+    let mut out = [0u8; 7];
+    SliceSink::new(&mut out).write_le(&chunk).unwrap();
+    assert_eq!(&out, b"\x01fmt \x10\x00");
+}
+
+/// Test of assertions on read and write.
+#[test]
+fn assertions() {
+    use ::barse::{
+        error::{AssertionFailed, ErrKind},
+        Error,
+    };
+
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(assert(min <= max, "min should not exceed max", write))]
+    struct Range {
+        #[barse(assert(*version == 1, "unsupported version"))]
+        version: u8,
+        min: u8,
+        #[barse(assert(*max < 100, "max should be below 100", write))]
+        max: u8,
+    }
+
+    let range = SliceSrc::new(&[1, 2, 5]).read_le::<Range>().unwrap();
+    assert_eq!(
+        range,
+        Range {
+            version: 1,
+            min: 2,
+            max: 5
+        }
+    );
+
+    let failed = |buf: &[u8]| {
+        let err = SliceSrc::new(buf).read_le::<Range>().unwrap_err();
+        let ErrKind::Other(Error::Assertion(AssertionFailed { message })) = err.kind() else {
+            panic!("expected assertion failure, got {err}");
+        };
+        (*message, err.context().map(ToString::to_string))
+    };
+    assert_eq!(
+        failed(&[2, 2, 5]),
+        ("unsupported version", Some("Range.version".into()))
+    );
+    assert_eq!(
+        failed(&[1, 2, 100]),
+        ("max should be below 100", Some("Range.max".into()))
+    );
+    assert_eq!(
+        failed(&[1, 6, 5]),
+        ("min should not exceed max", Some("Range".into()))
+    );
+
+    let mut out = [0u8; 3];
+    let invalid = Range {
+        version: 2,
+        min: 6,
+        max: 5,
+    };
+    let err = SliceSink::new(&mut out).write_le(&invalid).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "assertion failed, min should not exceed max (in Range, at offset 0)"
+    );
+    SliceSink::new(&mut out)
+        .write_le(&Range { min: 1, ..invalid })
+        .unwrap();
+    assert_eq!(out, [2, 1, 5]);
+}
+
+/// Test of fields calculated from other fields on write.
+#[test]
+fn calculated_fields() {
+    use ::barse::{error::ErrKind, Error};
+
+    #[derive(Barse, Debug, PartialEq)]
+    struct Sum {
+        #[barse(calc = self.a.wrapping_add(self.b), verify)]
+        sum: u8,
+        a: u8,
+        b: u8,
+    }
+
+    let stale = PrefixedVec {
+        size: 0,
+        vec: vec![1, 2],
+    };
+    let mut buf = Vec::new();
+    buf.write_le(&stale).unwrap();
+    assert_eq!(buf, b"\x02\x00\x01\x00\x00\x00\x02\x00\x00\x00");
+    assert_eq!(stale.encoded_len(()).unwrap(), buf.len());
+    assert_eq!(
+        SliceSrc::new(&buf).read_le::<PrefixedVec>().unwrap().size,
+        2
+    );
+
+    let adapted = SliceSrc::new(&buf).read_le::<AdaptedVec>().unwrap();
+    assert_eq!(
+        adapted,
+        AdaptedVec {
+            size: 2,
+            vec: vec![1, 2]
+        }
+    );
+    let mut out = Vec::new();
+    out.write_le(&AdaptedVec { size: 0, ..adapted }).unwrap();
+    assert_eq!(out, buf);
+
+    let sum = Sum { sum: 0, a: 3, b: 4 };
+    let mut out = [0u8; 3];
+    SliceSink::new(&mut out).write_le(&sum).unwrap();
+    assert_eq!(out, [7, 3, 4]);
+    assert_eq!(
+        SliceSrc::new(&out).read_le::<Sum>().unwrap(),
+        Sum { sum: 7, ..sum }
+    );
+
+    let err = SliceSrc::new(&[8, 3, 4]).read_le::<Sum>().unwrap_err();
+    assert!(matches!(err.kind(), ErrKind::Other(Error::Assertion(_))));
+    assert_eq!(
+        err.context().map(ToString::to_string).as_deref(),
+        Some("Sum.sum")
+    );
+}
+
+/// Test of fields present depending on earlier fields.
+#[test]
+fn conditional_fields() {
+    use ::barse::{error::ErrKind, Error};
+
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(read_with = extended: bool, write_with = extended: bool)]
+    struct Record {
+        flags: u8,
+        #[barse(if = *flags & 1 != 0)]
+        id: Option<u16>,
+        #[barse(if = extended)]
+        extra: Option<u8>,
+        end: u8,
+    }
+
+    let record = SliceSrc::new(&[1, 2, 0, 3])
+        .read_with::<Record, Little>(false)
+        .unwrap();
+    assert_eq!(
+        record,
+        Record {
+            flags: 1,
+            id: Some(2),
+            extra: None,
+            end: 3
+        }
+    );
+    assert_eq!(record.encoded_len(false).unwrap(), 4);
+
+    let record = SliceSrc::new(&[0, 5, 3])
+        .read_with::<Record, Little>(true)
+        .unwrap();
+    assert_eq!(
+        record,
+        Record {
+            flags: 0,
+            id: None,
+            extra: Some(5),
+            end: 3
+        }
+    );
+
+    let mut out = [0u8; 3];
+    SliceSink::new(&mut out)
+        .write_with::<Record, Little>(&record, true)
+        .unwrap();
+    assert_eq!(out, [0, 5, 3]);
+
+    let err = SliceSink::new(&mut out)
+        .write_with::<Record, Little>(&record, false)
+        .unwrap_err();
+    assert!(matches!(err.kind(), ErrKind::Other(Error::Assertion(_))));
+    assert_eq!(
+        err.context().map(ToString::to_string).as_deref(),
+        Some("Record.extra")
+    );
+}
+
+/// Test of collections with counts, terminators and end of source.
+#[test]
+fn collection_fields() {
+    use ::barse::{barse_as::Uleb128, BufferedSource, ReadSource};
+
+    #[derive(Barse, Debug, PartialEq)]
+    struct Lists {
+        len: u8,
+        #[barse(count = len, as Uleb128)]
+        values: Vec<u32>,
+        #[barse(count = 2, endian = Big)]
+        pair: [u16; 2],
+        #[barse(until = |byte: &u8| *byte == 0)]
+        name: Box<[u8]>,
+        #[barse(until_eof, read_with = len != 0)]
+        rest: Vec<Option<u8>>,
+    }
+
+    let buf = [2, 0x7f, 0x80, 0x01, 0, 1, 0, 2, b'h', b'i', 0, 3, 4];
+    let lists = SliceSrc::new(&buf).read_le::<Lists>().unwrap();
+    assert_eq!(
+        lists,
+        Lists {
+            len: 2,
+            values: vec![0x7f, 0x80],
+            pair: [1, 2],
+            name: Box::new(*b"hi\0"),
+            rest: vec![Some(3), Some(4)],
+        }
+    );
+    assert_eq!(lists.encoded_len(()).unwrap(), buf.len());
+
+    let mut out = Vec::new();
+    out.write_le(&lists).unwrap();
+    assert_eq!(out, buf);
+
+    // Sources not knowing their length are read until they report the end.
+    let mut source = ReadSource::new(::std::io::Cursor::new(&buf));
+    assert_eq!(source.read_le::<Lists>().unwrap(), lists);
+    let mut source = BufferedSource::with_capacity(4, ::std::io::Cursor::new(&buf));
+    assert_eq!(source.read_le::<Lists>().unwrap(), lists);
+
+    let err = SliceSrc::new(&[1, 0x80]).read_le::<Lists>().unwrap_err();
+    assert_eq!(
+        err.context().map(ToString::to_string).as_deref(),
+        Some("Lists.values[0]")
+    );
+    assert!(SliceSrc::new(&[0, 0, 1, 0]).read_le::<Lists>().is_err());
+}
+
+/// Test of field alignment and record padding.
+#[test]
+fn aligned_fields() {
+    use ::barse::ByteSource;
+
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(magic = b"R", pad_to_multiple = 8)]
+    struct Record {
+        tag: u8,
+        #[barse(align = 4)]
+        value: u32,
+        len: u8,
+        #[barse(align = 2, count = len)]
+        items: Vec<u16>,
+    }
+
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(discriminant = u8)]
+    enum Chunk {
+        #[barse(discriminant = 1)]
+        Word(#[barse(align = 4)] u32),
+        #[barse(discriminant = 2, pad_to_multiple = 4)]
+        Byte(u8),
+    }
+
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(align_base = offset)]
+    struct Entry {
+        tag: u8,
+        #[barse(align = 4)]
+        value: u32,
+    }
+
+    let record = Record {
+        tag: 1,
+        value: 2,
+        len: 2,
+        items: vec![3, 4],
+    };
+    let buf = *b"R\x01\0\0\x02\0\0\0\x02\0\x03\0\x04\0\0\0";
+    assert_eq!(record.encoded_len(()).unwrap(), buf.len());
+
+    let mut out = Vec::new();
+    out.write_le(&record).unwrap();
+    assert_eq!(out, buf);
+    assert_eq!(SliceSrc::new(&buf).read_le::<Record>().unwrap(), record);
+
+    let err = SliceSrc::new(&buf[..15]).read_le::<Record>().unwrap_err();
+    assert_eq!(
+        err.context().map(ToString::to_string).as_deref(),
+        Some("Record")
+    );
+    assert_eq!(err.context().and_then(|context| context.offset()), Some(14));
+
+    for (chunk, bytes) in [
+        (Chunk::Word(5), &b"\x01\0\0\0\x05\0\0\0"[..]),
+        (Chunk::Byte(6), &b"\x02\x06\0\0"[..]),
+    ] {
+        assert_eq!(chunk.encoded_len(()).unwrap(), bytes.len());
+        let mut out = Vec::new();
+        out.write_le(&chunk).unwrap();
+        assert_eq!(out, bytes);
+        assert_eq!(SliceSrc::new(bytes).read_le::<Chunk>().unwrap(), chunk);
+    }
+
+    // Entries are aligned relative to offset in source/sink, not their start.
+    let entry = Entry { tag: 7, value: 8 };
+    let mut out = Vec::new();
+    out.write_le(&0xffu8).unwrap();
+    out.write_le(&entry).unwrap();
+    assert_eq!(out, b"\xff\x07\0\0\x08\0\0\0");
+
+    let mut source = SliceSrc::new(&out);
+    source.skip(1).unwrap();
+    assert_eq!(source.read_le::<Entry>().unwrap(), entry);
+}
+
+/// Test of fields at offsets and file pointers.
+#[test]
+fn offset_fields() {
+    use ::barse::{ext::SeekSourceExt, util::FilePtr, BarseSeek, ByteSource};
+
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(seek)]
+    struct Header {
+        name_at: u8,
+        #[barse(offset = u64::from(*name_at))]
+        name: [u8; 2],
+        #[barse(with = (0, ()))]
+        value: FilePtr<u8, u16>,
+        #[barse(size = 1usize)]
+        tail: u8,
+    }
+
+    #[derive(Barse, Debug)]
+    #[barse(seek)]
+    struct Looped {
+        #[barse(offset = 0, with = (0, ()))]
+        ptr: FilePtr<u8, u8>,
+    }
+
+    let header = Header {
+        name_at: 4,
+        name: *b"hi",
+        value: FilePtr::new(6, 0x1234),
+        tail: 9,
+    };
+    let buf = *b"\x04\x06\x09\0hi\x34\x12";
+
+    let mut out = ::std::io::Cursor::new(Vec::new());
+    header.write_seek::<Little, _>(&mut out, ()).unwrap();
+    assert_eq!(out.position(), 3);
+    assert_eq!(out.into_inner(), buf);
+
+    let mut source = SliceSrc::new(&buf);
+    assert_eq!(source.read_seek_le::<Header>().unwrap(), header);
+    assert_eq!(source.read_byte(), Ok(0));
+
+    let err = SliceSrc::new(b"\0").read_seek_le::<Looped>().unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        ::barse::error::ErrKind::Other(::barse::Error::PtrCycle(_))
+    ));
+
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(seek)]
+    struct Node {
+        value: u8,
+        has_next: u8,
+        #[barse(if = *has_next != 0, with = (0, ()))]
+        next: Option<Box<FilePtr<u8, Node>>>,
+    }
+
+    let list = Node {
+        value: 1,
+        has_next: 1,
+        next: Some(Box::new(FilePtr::new(
+            3,
+            Node {
+                value: 2,
+                has_next: 0,
+                next: None,
+            },
+        ))),
+    };
+    let buf = *b"\x01\x01\x03\x02\0";
+
+    let mut out = ::std::io::Cursor::new(Vec::new());
+    list.write_seek::<Little, _>(&mut out, ()).unwrap();
+    assert_eq!(out.into_inner(), buf);
+    assert_eq!(SliceSrc::new(&buf).read_seek_le::<Node>().unwrap(), list);
+
+    let err = SliceSrc::new(b"\x01\x01\0")
+        .read_seek_le::<Node>()
+        .unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        ::barse::error::ErrKind::Other(::barse::Error::PtrCycle(_))
+    ));
+}
+
+/// Test of variants catching unknown discriminants.
+#[test]
+fn catch_all_variant() {
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(discriminant = u16)]
+    enum Message {
+        #[barse(discriminant = 1)]
+        Ping(u8),
+        #[barse(catch_all)]
+        Unknown {
+            tag: u16,
+            #[barse(until_eof)]
+            payload: Vec<u8>,
+        },
+        #[barse(discriminant = 2)]
+        Empty,
+    }
+
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(peek = 1)]
+    enum Tagged {
+        #[barse(discriminant = [b'a'])]
+        A(u8, u8),
+        #[barse(catch_all)]
+        Other(u8),
+    }
+
+    for (message, bytes) in [
+        (Message::Ping(3), &b"\x01\0\x03"[..]),
+        (Message::Empty, &b"\x02\0"[..]),
+        (
+            Message::Unknown {
+                tag: 7,
+                payload: vec![4, 5],
+            },
+            &b"\x07\0\x04\x05"[..],
+        ),
+        (
+            Message::Unknown {
+                tag: 8,
+                payload: Vec::new(),
+            },
+            &b"\x08\0"[..],
+        ),
+    ] {
+        assert_eq!(message.encoded_len(()).unwrap(), bytes.len());
+        let mut out = Vec::new();
+        out.write_le(&message).unwrap();
+        assert_eq!(out, bytes);
+        assert_eq!(SliceSrc::new(bytes).read_le::<Message>().unwrap(), message);
+    }
+
+    for (tagged, bytes) in [
+        (Tagged::A(b'a', 1), &b"a\x01"[..]),
+        (Tagged::Other(b'b'), b"b"),
+    ] {
+        let mut out = Vec::new();
+        out.write_le(&tagged).unwrap();
+        assert_eq!(out, bytes);
+        assert_eq!(SliceSrc::new(bytes).read_le::<Tagged>().unwrap(), tagged);
+    }
+}
+
+/// Test of discriminants read and written as other types.
+#[test]
+fn discriminant_encodings() {
+    use ::barse::{barse_as::Uleb128, ByteSink, ByteSource, Endian, ReadAs, WrappedErr, WriteAs};
+
+    /// Bytes masked by a key.
+    struct Xor;
+
+    impl ReadAs<u8, u8> for Xor {
+        fn read_with<E, B>(self, from: &mut B, key: u8) -> Result<u8, WrappedErr<B::Err>>
+        where
+            E: Endian,
+            B: ByteSource,
+        {
+            Ok(from.read_byte()? ^ key)
+        }
+    }
+
+    impl WriteAs<u8, u8> for Xor {
+        fn write_with<E, B>(self, value: &u8, to: &mut B, key: u8) -> Result<(), WrappedErr<B::Err>>
+        where
+            E: Endian,
+            B: ByteSink,
+        {
+            Ok(to.write_byte(value ^ key)?)
+        }
+    }
+
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(discriminant = u32, discriminant_as = Uleb128)]
+    enum Var {
+        #[barse(discriminant = 300)]
+        Wide(u8),
+        #[barse(discriminant = 1)]
+        Narrow,
+    }
+
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(discriminant = [u8; 4])]
+    enum Chunk {
+        #[barse(discriminant = b"fmt ")]
+        Fmt(u16),
+        #[barse(discriminant = b"data")]
+        Data,
+    }
+
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(discriminant = u8, discriminant_as = Xor, discriminant_with = 0x55)]
+    enum Masked {
+        #[barse(discriminant = 1)]
+        One,
+        #[barse(catch_all)]
+        Other(u8),
+    }
+
+    fn round_trip<T>(value: &T, bytes: &[u8])
+    where
+        T: Barse<ReadWith = (), WriteWith = ()> + ::std::fmt::Debug + PartialEq,
+    {
+        assert_eq!(value.encoded_len(()).unwrap(), bytes.len());
+        let mut out = Vec::new();
+        out.write_le(value).unwrap();
+        assert_eq!(out, bytes);
+        assert_eq!(SliceSrc::new(bytes).read_le::<T>().unwrap(), *value);
+    }
+
+    round_trip(&Var::Wide(7), b"\xac\x02\x07");
+    round_trip(&Var::Narrow, b"\x01");
+    round_trip(&Chunk::Fmt(2), b"fmt \x02\0");
+    round_trip(&Chunk::Data, b"data");
+    round_trip(&Masked::One, b"\x54");
+    round_trip(&Masked::Other(2), b"\x57");
+}
+
+/// Test of discriminants taken from repr of enum.
+#[test]
+fn implicit_discriminants() {
+    #[derive(Barse, Debug, PartialEq, Clone, Copy)]
+    #[repr(u8)]
+    enum Kind {
+        A,
+        B,
+        C = 5,
+        D,
+        E = 1 + 9,
+        F,
+    }
+
+    #[derive(Barse, Debug, PartialEq)]
+    #[repr(C, i16)]
+    enum Shape {
+        Point(i16) = -1,
+        Line(i16, i16),
+    }
+
+    for kind in [Kind::A, Kind::B, Kind::C, Kind::D, Kind::E, Kind::F] {
+        let bytes = [kind as u8];
+        assert_eq!(kind.encoded_len(()).unwrap(), 1);
+        let mut out = Vec::new();
+        out.write_le(&kind).unwrap();
+        assert_eq!(out, bytes);
+        assert_eq!(SliceSrc::new(&bytes).read_le::<Kind>().unwrap(), kind);
+    }
+    assert!(SliceSrc::new(&[2]).read_le::<Kind>().is_err());
+
+    for (shape, bytes) in [
+        (Shape::Point(3), &b"\xff\xff\x03\0"[..]),
+        (Shape::Line(1, 2), &b"\0\0\x01\0\x02\0"[..]),
+    ] {
+        let mut out = Vec::new();
+        out.write_le(&shape).unwrap();
+        assert_eq!(out, bytes);
+        assert_eq!(SliceSrc::new(bytes).read_le::<Shape>().unwrap(), shape);
+    }
+}
+
+/// Test of discriminants depending on with value.
+#[test]
+fn with_discriminants() {
+    #[derive(Barse, Debug, PartialEq)]
+    #[barse(discriminant = u8, with = base: u8)]
+    enum Kind {
+        #[barse(discriminant = base)]
+        A,
+        #[barse(discriminant = (base + 1))]
+        B,
+    }
+
+    assert_eq!(
+        SliceSrc::new(&[4]).read_with::<Kind, Little>(4).unwrap(),
+        Kind::A
+    );
+    assert_eq!(
+        SliceSrc::new(&[5]).read_with::<Kind, Little>(4).unwrap(),
+        Kind::B
+    );
+    assert_eq!(
+        SliceSrc::new(&[1]).read_with::<Kind, Little>(0).unwrap(),
+        Kind::B
+    );
+    assert!(SliceSrc::new(&[0]).read_with::<Kind, Little>(4).is_err());
+
+    let mut out = [0u8];
+    SliceSink::new(&mut out)
+        .write_with::<Kind, Little>(&Kind::B, 7)
+        .unwrap();
+    assert_eq!(out, [8]);
+}